@@ -1,166 +1,364 @@
 #[cfg(feature = "fresh")]
 extern crate bindgen;
+extern crate pkg_config;
 
 use std::env;
 #[cfg(feature = "fresh")]
 use std::fs;
+#[cfg(any(feature = "fresh", feature = "dynamic_loading"))]
+use std::path::{Path};
 use std::path::{PathBuf};
 
-#[cfg(all(
-    not(feature = "fresh"),
-    not(any(
-        feature = "cuda_6_5",
-        feature = "cuda_7_0",
-        feature = "cuda_7_5",
-        feature = "cuda_8_0",
-        feature = "cuda_9_0",
-        feature = "cuda_9_1",
-        feature = "cuda_9_2",
-        feature = "cuda_10_0",
-    ))
-))]
+#[allow(dead_code)]
+#[path = "build/cuda_version.rs"]
+mod cuda_version;
+#[allow(dead_code)]
+#[path = "build/cuda_search.rs"]
+mod cuda_search;
+#[allow(dead_code)]
+#[path = "build/runtime_api.rs"]
+mod runtime_api;
+
+use crate::cuda_search::{Search};
+use crate::cuda_version::{CudaVersion, SUPPORTED};
+
 fn main() {
-  compile_error!("a cuda version feature must be enabled");
+  println!("cargo:rerun-if-changed=build.rs");
+  println!("cargo:rerun-if-changed=build/cuda_version.rs");
+  println!("cargo:rerun-if-changed=build/cuda_search.rs");
+  println!("cargo:rerun-if-changed=build/runtime_api.rs");
+  for var in &["CUDA_HOME", "CUDA_PATH", "CONDA_PREFIX", "CUDART_LIB_DIR", "CUDART_INCLUDE_DIR", "CUDART_STRICT_VERSION"] {
+    println!("cargo:rerun-if-env-changed={}", var);
+  }
+  let explicit: Vec<CudaVersion> = SUPPORTED.iter()
+    .filter(|v| env::var_os(format!("CARGO_FEATURE_{}", v.feature().to_uppercase())).is_some())
+    .cloned()
+    .collect();
+  let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+  let checked_in: Vec<CudaVersion> = SUPPORTED.iter()
+    .filter(|v| v.has_checked_in_bindings(&manifest_dir))
+    .cloned()
+    .collect();
+  let available = if cfg!(feature = "fresh") { SUPPORTED.to_vec() } else { checked_in.clone() };
+  let search = cuda_search(&explicit);
+  let include_dirs = search.include_dirs();
+  let include_dir = cuda_search::find(&include_dirs, &["cuda_runtime_api.h"]);
+  let version = select_cuda_version(&explicit, &include_dir, &search, &available);
+  for v in SUPPORTED.iter() {
+    println!("cargo:rustc-check-cfg=cfg({})", v.feature());
+    println!("cargo:rustc-check-cfg=cfg(cuda_gte_{}_{})", v.major, v.minor);
+  }
+  println!("cargo:rustc-check-cfg=cfg(cuda_generated_bindings)");
+  for cfg in version.cfgs() {
+    println!("cargo:rustc-cfg={}", cfg);
+  }
+  // Versions without checked-in bindings use those generated into
+  // `$OUT_DIR` below.
+  let generated = !checked_in.contains(&version);
+  if generated {
+    println!("cargo:rustc-cfg=cuda_generated_bindings");
+  }
+  #[cfg(not(feature = "dynamic_loading"))]
+  link_runtime(&search);
+  #[cfg(feature = "fresh")]
+  {
+    let include_dir = include_dir.unwrap_or_else(|tried| {
+      panic!("{}", cuda_search::not_found("the CUDA runtime headers (cuda_runtime_api.h)", &tried))
+    });
+    gen_fresh_bindings(&include_dir, version);
+  }
+  #[cfg(feature = "dynamic_loading")]
+  {
+    let src_path = if generated {
+      PathBuf::from(env::var("OUT_DIR").unwrap()).join("_cuda_runtime_api.rs")
+    } else {
+      manifest_dir.join("src").join("ffi").join(version.ffi_dir()).join("_cuda_runtime_api.rs")
+    };
+    gen_dynamic_bindings(&src_path);
+  }
 }
 
-#[cfg(all(
-    not(feature = "fresh"),
-    any(
-        feature = "cuda_6_5",
-        feature = "cuda_7_0",
-        feature = "cuda_7_5",
-        feature = "cuda_8_0",
-        feature = "cuda_9_0",
-        feature = "cuda_9_1",
-        feature = "cuda_9_2",
-        feature = "cuda_10_0",
-    )
-))]
-fn main() {
-  let cuda_dir = PathBuf::from(
-      env::var("CUDA_HOME")
-        .or_else(|_| env::var("CUDA_PATH"))
-        .unwrap_or_else(|_| "/usr/local/cuda".to_owned())
-  );
-  let cuda_lib_dir = cuda_dir.join("lib64");
-  println!("cargo:rustc-link-lib=cudart");
-  println!("cargo:rustc-link-search=native={}", cuda_lib_dir.display());
+/// Link `libcudart`, or with the `static` feature `libcudart_static` and
+/// the system libraries it depends on.
+#[cfg(not(feature = "dynamic_loading"))]
+fn link_runtime(search: &Search) {
+  #[cfg(not(feature = "static"))]
+  let (names, kind) = (["libcudart.so", "cudart.lib"], "cudart");
+  #[cfg(feature = "static")]
+  let (names, kind) = (["libcudart_static.a", "cudart_static.lib"], "static=cudart_static");
+  println!("cargo:rustc-link-lib={}", kind);
+  match cuda_search::find(&search.lib_dirs(), &names) {
+    Err(tried) => {
+      let msg = cuda_search::not_found(&format!("the CUDA runtime library ({})", names[0]), &tried);
+      // An explicit `CUDART_LIB_DIR` is a mistake; otherwise only linking
+      // needs the library, so `cargo check` and `cargo doc` still work.
+      if search.lib_dir.is_some() {
+        panic!("{}", msg);
+      }
+      for line in msg.lines() {
+        println!("cargo:warning={}", line);
+      }
+    }
+    Ok(lib_dir) => {
+      println!("cargo:rustc-link-search=native={}", lib_dir.display());
+      if let Some(stubs_dir) = cuda_search::stubs_dir(&lib_dir) {
+        println!("cargo:rustc-link-search=native={}", stubs_dir.display());
+      }
+    }
+  }
+  if cfg!(feature = "static") && search.target.contains("linux") {
+    for lib in &["rt", "pthread", "dl"] {
+      println!("cargo:rustc-link-lib=dylib={}", lib);
+    }
+  }
+}
+
+/// Where to look for the toolkit: the `CUDART_LIB_DIR` and
+/// `CUDART_INCLUDE_DIR` overrides, then `pkg-config`, then the usual
+/// install prefixes.
+fn cuda_search(explicit: &[CudaVersion]) -> Search {
+  let target = env::var("TARGET").unwrap();
+  let cross = env::var("HOST").map(|host| host != target).unwrap_or(false);
+  let mut search = Search{
+    lib_dir:      env::var_os("CUDART_LIB_DIR").map(PathBuf::from),
+    include_dir:  env::var_os("CUDART_INCLUDE_DIR").map(PathBuf::from),
+    target,
+    cross,
+    .. Search::default()
+  };
+  // NVIDIA's packages install `cudart-X.Y.pc`; probe the selected version,
+  // or else every supported version, newest first.
+  let versions: Vec<CudaVersion> = if explicit.is_empty() {
+    SUPPORTED.iter().rev().cloned().collect()
+  } else {
+    explicit.to_vec()
+  };
+  let names = versions.iter()
+    .map(|v| format!("cudart-{}", v))
+    .chain(Some("cudart".to_owned()));
+  for name in names {
+    if let Ok(lib) = pkg_config::Config::new().cargo_metadata(false).env_metadata(true).probe(&name) {
+      search.pkg_lib_dirs = lib.link_paths;
+      search.pkg_include_dirs = lib.include_paths;
+      break;
+    }
+  }
+  for var in &["CUDA_HOME", "CUDA_PATH", "CONDA_PREFIX"] {
+    if let Some(dir) = env::var_os(var) {
+      search.roots.push(PathBuf::from(dir));
+    }
+  }
+  for v in explicit.iter() {
+    search.roots.push(PathBuf::from(format!("/usr/local/cuda-{}", v)));
+  }
+  // The last three are the default install, Arch's package, and Debian's.
+  search.roots.push(PathBuf::from("/usr/local/cuda"));
+  search.roots.push(PathBuf::from("/opt/cuda"));
+  search.roots.push(PathBuf::from("/usr"));
+  search
+}
+
+/// The version selected by a `cuda_X_Y` feature (`explicit`), or else
+/// detected from the toolkit.
+fn select_cuda_version(explicit: &[CudaVersion], include_dir: &Result<PathBuf, Vec<PathBuf>>, search: &Search, available: &[CudaVersion]) -> CudaVersion {
+  // Prefer the headers that were found, so the version matches them.
+  let include_dirs = match *include_dir {
+    Ok(ref dir) => vec![dir.clone()],
+    Err(_) => search.include_dirs(),
+  };
+  let detected = cuda_version::detect(&include_dirs, &search.roots);
+  if let Ok(ref d) = detected {
+    println!("cargo:rerun-if-changed={}", d.source.display());
+  }
+  let strict = env::var_os("CUDART_STRICT_VERSION").is_some();
+  match cuda_version::select(explicit, &detected, strict, available) {
+    Err(msg) => panic!("{}", msg),
+    Ok(selection) => {
+      for warning in selection.warnings.iter() {
+        println!("cargo:warning={}", warning);
+      }
+      selection.version
+    }
+  }
 }
 
 #[cfg(feature = "fresh")]
-fn main() {
+fn gen_fresh_bindings(cuda_include_dir: &Path, version: CudaVersion) {
   let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
-  let cuda_dir = PathBuf::from(
-      env::var("CUDA_HOME")
-        .or_else(|_| env::var("CUDA_PATH"))
-        .unwrap_or_else(|_| "/usr/local/cuda".to_owned())
-  );
-  let cuda_include_dir = cuda_dir.join("include");
-  let cuda_lib_dir = cuda_dir.join("lib64");
-  println!("cargo:rustc-link-lib=cudart");
-  println!("cargo:rustc-link-search=native={}", cuda_lib_dir.display());
-
-  #[cfg(feature = "cuda_6_5")]
-  let a_cuda_version_feature_must_be_enabled = "v6_5";
-  #[cfg(feature = "cuda_7_0")]
-  let a_cuda_version_feature_must_be_enabled = "v7_0";
-  #[cfg(feature = "cuda_7_5")]
-  let a_cuda_version_feature_must_be_enabled = "v7_5";
-  #[cfg(feature = "cuda_8_0")]
-  let a_cuda_version_feature_must_be_enabled = "v8_0";
-  #[cfg(feature = "cuda_9_0")]
-  let a_cuda_version_feature_must_be_enabled = "v9_0";
-  #[cfg(feature = "cuda_9_1")]
-  let a_cuda_version_feature_must_be_enabled = "v9_1";
-  #[cfg(feature = "cuda_9_2")]
-  let a_cuda_version_feature_must_be_enabled = "v9_2";
-  #[cfg(feature = "cuda_10_0")]
-  let a_cuda_version_feature_must_be_enabled = "v10_0";
-  let v = a_cuda_version_feature_must_be_enabled;
-
-  let gensrc_dir = manifest_dir.join("gensrc").join("ffi").join(v);
+  let v = version.ffi_dir();
+
+  let api_path = manifest_dir.join("build").join("runtime_api.txt");
+  println!("cargo:rerun-if-changed={}", api_path.display());
+  let api_src = fs::read_to_string(&api_path)
+    .unwrap_or_else(|e| panic!("failed to read {}: {}", api_path.display(), e));
+  let fns = runtime_api::parse(&api_src).unwrap_or_else(|e| panic!("{}", e));
+
+  let gensrc_dir = manifest_dir.join("gensrc").join("ffi").join(&v);
   println!("cargo:rerun-if-changed={}", gensrc_dir.display());
   fs::create_dir_all(&gensrc_dir).ok();
 
   println!("cargo:rerun-if-changed={}", gensrc_dir.join("_cuda_runtime_api.rs").display());
   fs::remove_file(gensrc_dir.join("_cuda_runtime_api.rs")).ok();
-  bindgen::Builder::default()
+  let names = runtime_api::functions_for(&fns, version);
+  let mut builder = bindgen::Builder::default()
     .clang_arg(format!("-I{}", cuda_include_dir.as_os_str().to_str().unwrap()))
     .header("wrapped_cuda_runtime_api.h")
-    .whitelist_recursively(false)
-    // Device management.
-    .whitelist_function("cudaDeviceReset")
-    .whitelist_function("cudaDeviceSynchronize")
-    .whitelist_function("cudaGetDeviceCount")
-    .whitelist_function("cudaGetDevice")
-    .whitelist_function("cudaGetDeviceFlags")
-    .whitelist_function("cudaGetDeviceProperties")
-    .whitelist_function("cudaDeviceGetAttribute")
-    .whitelist_function("cudaSetDevice")
-    .whitelist_function("cudaSetDeviceFlags")
-    // Error handling.
-    .whitelist_function("cudaGetErrorString")
-    // Stream management.
-    .whitelist_function("cudaStreamCreate")
-    .whitelist_function("cudaStreamCreateWithFlags")
-    .whitelist_function("cudaStreamCreateWithPriority")
-    .whitelist_function("cudaStreamDestroy")
-    .whitelist_function("cudaStreamAddCallback")
-    .whitelist_function("cudaStreamAttachMemAsync")
-    .whitelist_function("cudaStreamQuery")
-    .whitelist_function("cudaStreamSynchronize")
-    .whitelist_function("cudaStreamWaitEvent")
-    // Event management.
-    .whitelist_function("cudaEventCreate")
-    .whitelist_function("cudaEventCreateWithFlags")
-    .whitelist_function("cudaEventDestroy")
-    .whitelist_function("cudaEventElapsedTime")
-    .whitelist_function("cudaEventQuery")
-    .whitelist_function("cudaEventRecord")
-    .whitelist_function("cudaEventSynchronize")
-    // Memory management.
-    .whitelist_function("cudaMalloc")
-    .whitelist_function("cudaFree")
-    .whitelist_function("cudaMallocHost")
-    .whitelist_function("cudaFreeHost")
-    .whitelist_function("cudaHostAlloc")
-    .whitelist_function("cudaHostGetDevicePointer")
-    .whitelist_function("cudaHostGetFlags")
-    .whitelist_function("cudaHostRegister")
-    .whitelist_function("cudaHostUnregister")
-    .whitelist_function("cudaMallocManaged")
-    .whitelist_function("cudaMemAdvise")
-    .whitelist_function("cudaMemPrefetchAsync")
-    .whitelist_function("cudaMemRangeGetAttribute")
-    .whitelist_function("cudaMemRangeGetAttributes")
-    .whitelist_function("cudaMemcpy")
-    .whitelist_function("cudaMemcpyAsync")
-    .whitelist_function("cudaMemcpy2D")
-    .whitelist_function("cudaMemcpy2DAsync")
-    .whitelist_function("cudaMemcpyPeer")
-    .whitelist_function("cudaMemcpyPeerAsync")
-    .whitelist_function("cudaMemset")
-    .whitelist_function("cudaMemsetAsync")
-    // Peer device memory access.
-    .whitelist_function("cudaDeviceCanAccessPeer")
-    .whitelist_function("cudaDeviceDisablePeerAccess")
-    .whitelist_function("cudaDeviceEnablePeerAccess")
-    // OpenGL interoperability.
-    .whitelist_function("cudaGLGetDevices")
-    .whitelist_function("cudaGraphicsGLRegisterBuffer")
-    .whitelist_function("cudaGraphicsGLRegisterImage")
-    // Graphics interoperability.
-    .whitelist_function("cudaGraphicsMapResources")
-    .whitelist_function("cudaGraphicsResourceGetMappedPointer")
-    .whitelist_function("cudaGraphicsResourceSetMapFlags")
-    .whitelist_function("cudaGraphicsUnmapResources")
-    .whitelist_function("cudaGraphicsUnregisterResource")
-    // Version management.
-    .whitelist_function("cudaDriverGetVersion")
-    .whitelist_function("cudaRuntimeGetVersion")
+    .whitelist_recursively(false);
+  for &name in names.iter() {
+    builder = builder.whitelist_function(name);
+  }
+  let bindings = builder
     .rustfmt_bindings(true)
     .generate()
-    .expect("bindgen failed to generate runtime bindings")
+    .expect("bindgen failed to generate runtime bindings");
+  bindings
     .write_to_file(gensrc_dir.join("_cuda_runtime_api.rs"))
     .expect("bindgen failed to write runtime bindings");
+  // Built against when `v` has no checked-in bindings.
+  let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+  bindings
+    .write_to_file(out_dir.join("_cuda_runtime_api.rs"))
+    .expect("bindgen failed to write runtime bindings");
+
+  // The types the functions take, for `ffi::header` to check the layout of
+  // their declarations against.
+  let mut builder = bindgen::Builder::default()
+    .clang_arg(format!("-I{}", cuda_include_dir.as_os_str().to_str().unwrap()))
+    .header("wrapped_cuda_runtime_api.h")
+    .layout_tests(false);
+  for &name in names.iter() {
+    builder = builder.whitelist_function(name);
+  }
+  builder
+    .generate()
+    .expect("bindgen failed to generate runtime types")
+    .write_to_file(out_dir.join("_cuda_runtime_api_types.rs"))
+    .expect("bindgen failed to write runtime types");
+}
+
+/// Rewrite the bindings at `src_path` as functions which resolve their
+/// symbol from the dynamically loaded runtime on first call (see
+/// `ffi::dynamic`), and write them to `$OUT_DIR`.
+#[cfg(feature = "dynamic_loading")]
+fn gen_dynamic_bindings(src_path: &Path) {
+  let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+  println!("cargo:rerun-if-changed={}", src_path.display());
+  let src = std::fs::read_to_string(&src_path)
+    .unwrap_or_else(|e| panic!("failed to read {}: {}", src_path.display(), e));
+  let mut out = String::new();
+  out.push_str(&format!("/* generated by build.rs from {} */\n", src_path.display()));
+  for f in parse_extern_fns(&src) {
+    let names: Vec<&str> = f.params.iter().map(|&(ref name, _)| name.as_str()).collect();
+    let tys: Vec<&str> = f.params.iter().map(|&(_, ref ty)| ty.as_str()).collect();
+    let params: Vec<String> = f.params.iter().map(|&(ref name, ref ty)| format!("{}: {}", name, ty)).collect();
+    out.push_str(&format!("pub unsafe fn {}({}) -> {} {{\n", f.name, params.join(", "), f.ret));
+    out.push_str(&format!("  static SYM: crate::ffi::dynamic::Symbol = crate::ffi::dynamic::Symbol::new(\"{}\\0\");\n", f.name));
+    out.push_str("  match SYM.get() {\n");
+    out.push_str("    None => crate::ffi::dynamic::Unavailable::unavailable(),\n");
+    out.push_str(&format!("    Some(sym) => ::std::mem::transmute::<*mut ::std::os::raw::c_void, unsafe extern \"C\" fn ({}) -> {}>(sym)({}),\n",
+        tys.join(", "), f.ret, names.join(", ")));
+    out.push_str("  }\n}\n");
+  }
+  let out_path = out_dir.join("_cuda_runtime_api_dynamic.rs");
+  std::fs::write(&out_path, out)
+    .unwrap_or_else(|e| panic!("failed to write {}: {}", out_path.display(), e));
+}
+
+#[cfg(feature = "dynamic_loading")]
+struct ExternFn {
+  name:   String,
+  params: Vec<(String, String)>,
+  ret:    String,
+}
+
+/// Parse the `pub fn` declarations of bindgen output, in either its compact
+/// or its rustfmt'd layout.
+#[cfg(feature = "dynamic_loading")]
+fn parse_extern_fns(src: &str) -> Vec<ExternFn> {
+  // Drop string literals (doc attributes) so that their contents cannot be
+  // mistaken for declarations.
+  let mut code = String::with_capacity(src.len());
+  let mut chars = src.chars();
+  while let Some(c) = chars.next() {
+    if c == '"' {
+      while let Some(c) = chars.next() {
+        match c {
+          '\\' => { chars.next(); }
+          '"' => break,
+          _ => {}
+        }
+      }
+      code.push_str("\"\"");
+    } else {
+      code.push(c);
+    }
+  }
+  let mut fns = Vec::new();
+  let mut rest = &code[..];
+  while let Some(i) = rest.find("pub fn ") {
+    rest = &rest[i + 7 ..];
+    let open = rest.find('(').expect("missing parameter list");
+    let name = rest[.. open].trim().to_owned();
+    let mut depth = 0;
+    let mut close = open;
+    for (j, c) in rest[open ..].char_indices() {
+      match c {
+        '(' => depth += 1,
+        ')' => {
+          depth -= 1;
+          if depth == 0 {
+            close = open + j;
+            break;
+          }
+        }
+        _ => {}
+      }
+    }
+    let params = split_params(&rest[open + 1 .. close]);
+    let semi = close + rest[close ..].find(';').expect("missing semicolon");
+    let tail = rest[close + 1 .. semi].trim();
+    let ret = if tail.starts_with("->") {
+      normalize(&tail[2 ..])
+    } else {
+      "()".to_owned()
+    };
+    fns.push(ExternFn{name, params, ret});
+    rest = &rest[semi ..];
+  }
+  fns
+}
+
+#[cfg(feature = "dynamic_loading")]
+fn split_params(list: &str) -> Vec<(String, String)> {
+  let mut params = Vec::new();
+  let mut depth = 0;
+  let mut start = 0;
+  let bytes = list.as_bytes();
+  for (j, &b) in bytes.iter().enumerate() {
+    match b {
+      b'(' | b'<' | b'[' => depth += 1,
+      b'>' if j > 0 && bytes[j - 1] == b'-' => {}
+      b')' | b'>' | b']' => depth -= 1,
+      b',' if depth == 0 => {
+        params.push(&list[start .. j]);
+        start = j + 1;
+      }
+      _ => {}
+    }
+  }
+  params.push(&list[start ..]);
+  params.into_iter()
+    .filter(|p| !p.trim().is_empty())
+    .map(|p| {
+      let p = p.trim();
+      let colon = p.find(':').expect("missing parameter type");
+      (p[.. colon].trim().to_owned(), normalize(&p[colon + 1 ..]))
+    })
+    .collect()
+}
+
+/// Collapse line breaks and indentation within a type.
+#[cfg(feature = "dynamic_loading")]
+fn normalize(ty: &str) -> String {
+  ty.split_whitespace().collect::<Vec<_>>().join(" ")
 }
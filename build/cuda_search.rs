@@ -0,0 +1,140 @@
+//! CUDA library and header discovery for `build.rs`.
+//!
+//! `build.rs` fills in a `Search` from the environment and `pkg-config`;
+//! from there, finding a directory is only a matter of which candidate
+//! paths exist, which `tests/build_cuda_search.rs` checks against the
+//! layouts of the installers, distro packages and cross targets.
+
+use std::path::{Path, PathBuf};
+
+/// Where to look for the runtime library and headers.
+#[derive(Clone, Default, Debug)]
+pub struct Search {
+  /// `CUDART_LIB_DIR`; if set, the only library directory searched.
+  pub lib_dir:          Option<PathBuf>,
+  /// `CUDART_INCLUDE_DIR`; if set, the only header directory searched.
+  pub include_dir:      Option<PathBuf>,
+  /// Library directories reported by `pkg-config`.
+  pub pkg_lib_dirs:     Vec<PathBuf>,
+  /// Header directories reported by `pkg-config`.
+  pub pkg_include_dirs: Vec<PathBuf>,
+  /// Toolkit install prefixes, in order of preference.
+  pub roots:            Vec<PathBuf>,
+  /// The target triple being built for.
+  pub target:           String,
+  /// Whether `target` differs from the host, in which case the host's own
+  /// library directories (`lib64`, `lib`) are not searched.
+  pub cross:            bool,
+}
+
+/// The toolkit's `targets/<dir>` names for `target`, e.g. `aarch64-linux`
+/// for Jetson and `sbsa-linux` for ARM servers.
+pub fn target_dirs(target: &str) -> Vec<&'static str> {
+  let arch = target.split('-').next().unwrap_or("");
+  if !target.contains("linux") {
+    return Vec::new();
+  }
+  match arch {
+    "x86_64"      => vec!["x86_64-linux"],
+    "aarch64"     => vec!["aarch64-linux", "sbsa-linux"],
+    "powerpc64le" => vec!["ppc64le-linux"],
+    _ => Vec::new(),
+  }
+}
+
+/// The Debian-style multiarch directory for `target`, e.g.
+/// `x86_64-linux-gnu`.
+pub fn multiarch_dir(target: &str) -> Option<&'static str> {
+  if !target.contains("linux") {
+    return None;
+  }
+  match target.split('-').next().unwrap_or("") {
+    "x86_64"      => Some("x86_64-linux-gnu"),
+    "aarch64"     => Some("aarch64-linux-gnu"),
+    "powerpc64le" => Some("powerpc64le-linux-gnu"),
+    _ => None,
+  }
+}
+
+impl Search {
+  /// The directories searched for the runtime library, in order.
+  pub fn lib_dirs(&self) -> Vec<PathBuf> {
+    if let Some(ref dir) = self.lib_dir {
+      return vec![dir.clone()];
+    }
+    let mut dirs = self.pkg_lib_dirs.clone();
+    for root in self.roots.iter() {
+      for t in target_dirs(&self.target) {
+        dirs.push(root.join("targets").join(t).join("lib"));
+      }
+      if let Some(m) = multiarch_dir(&self.target) {
+        dirs.push(root.join("lib").join(m));
+      }
+      if self.target.contains("windows") {
+        dirs.push(root.join("lib").join("x64"));
+      } else if !self.cross {
+        dirs.push(root.join("lib64"));
+        dirs.push(root.join("lib"));
+      }
+    }
+    dedup(dirs)
+  }
+
+  /// The directories searched for `cuda_runtime_api.h`, in order.
+  pub fn include_dirs(&self) -> Vec<PathBuf> {
+    if let Some(ref dir) = self.include_dir {
+      return vec![dir.clone()];
+    }
+    let mut dirs = self.pkg_include_dirs.clone();
+    for root in self.roots.iter() {
+      for t in target_dirs(&self.target) {
+        dirs.push(root.join("targets").join(t).join("include"));
+      }
+      dirs.push(root.join("include"));
+    }
+    dedup(dirs)
+  }
+}
+
+fn dedup(dirs: Vec<PathBuf>) -> Vec<PathBuf> {
+  let mut uniq: Vec<PathBuf> = Vec::with_capacity(dirs.len());
+  for dir in dirs {
+    if !uniq.contains(&dir) {
+      uniq.push(dir);
+    }
+  }
+  uniq
+}
+
+/// The first of `dirs` containing one of the files `names`. On failure,
+/// returns every path tried.
+pub fn find(dirs: &[PathBuf], names: &[&str]) -> Result<PathBuf, Vec<PathBuf>> {
+  let mut tried = Vec::new();
+  for dir in dirs.iter() {
+    for name in names.iter() {
+      let path = dir.join(name);
+      if path.is_file() {
+        return Ok(dir.clone());
+      }
+      tried.push(path);
+    }
+  }
+  Err(tried)
+}
+
+/// The `stubs` directory next to the library in `lib_dir`, which holds a
+/// link-only `libcuda` for building on machines without the driver.
+pub fn stubs_dir(lib_dir: &Path) -> Option<PathBuf> {
+  let dir = lib_dir.join("stubs");
+  if dir.is_dir() { Some(dir) } else { None }
+}
+
+/// The diagnostic printed when nothing is found.
+pub fn not_found(what: &str, tried: &[PathBuf]) -> String {
+  let mut msg = format!("could not find {}; tried:\n", what);
+  for path in tried.iter() {
+    msg.push_str(&format!("  {}\n", path.display()));
+  }
+  msg.push_str("set CUDA_HOME to the toolkit, or CUDART_LIB_DIR and CUDART_INCLUDE_DIR");
+  msg
+}
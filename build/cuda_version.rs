@@ -0,0 +1,223 @@
+//! CUDA toolkit version detection for `build.rs`.
+//!
+//! This file is free of build script state (environment variables, cargo
+//! directives) so that it can be tested against fake toolkit trees, see
+//! `tests/build_cuda_version.rs`.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct CudaVersion {
+  pub major:  u32,
+  pub minor:  u32,
+}
+
+/// The versions with a `cuda_X_Y` feature, oldest first.
+pub const SUPPORTED: &[CudaVersion] = &[
+  CudaVersion::new(6, 5),
+  CudaVersion::new(7, 0),
+  CudaVersion::new(7, 5),
+  CudaVersion::new(8, 0),
+  CudaVersion::new(9, 0),
+  CudaVersion::new(9, 1),
+  CudaVersion::new(9, 2),
+  CudaVersion::new(10, 0),
+  CudaVersion::new(10, 1),
+  CudaVersion::new(10, 2),
+  CudaVersion::new(11, 0),
+  CudaVersion::new(11, 1),
+  CudaVersion::new(11, 2),
+  CudaVersion::new(11, 3),
+  CudaVersion::new(11, 4),
+  CudaVersion::new(11, 5),
+  CudaVersion::new(11, 6),
+  CudaVersion::new(11, 7),
+  CudaVersion::new(11, 8),
+  CudaVersion::new(12, 0),
+  CudaVersion::new(12, 1),
+  CudaVersion::new(12, 2),
+  CudaVersion::new(12, 3),
+  CudaVersion::new(12, 4),
+  CudaVersion::new(12, 5),
+  CudaVersion::new(12, 6),
+];
+
+impl fmt::Display for CudaVersion {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}.{}", self.major, self.minor)
+  }
+}
+
+impl CudaVersion {
+  pub const fn new(major: u32, minor: u32) -> CudaVersion {
+    CudaVersion{major, minor}
+  }
+
+  /// Decode `CUDA_VERSION`/`CUDART_VERSION`, e.g. 9020 for 9.2.
+  pub fn from_encoded(v: u32) -> CudaVersion {
+    CudaVersion{major: v / 1000, minor: (v % 1000) / 10}
+  }
+
+  /// E.g. `cuda_10_0`.
+  pub fn feature(&self) -> String {
+    format!("cuda_{}_{}", self.major, self.minor)
+  }
+
+  /// The directory of the checked-in bindings under `src/ffi`, e.g. `v10_0`.
+  pub fn ffi_dir(&self) -> String {
+    format!("v{}_{}", self.major, self.minor)
+  }
+
+  /// Whether bindings for this version are checked in under
+  /// `manifest_dir/src/ffi`. Otherwise they are generated from the toolkit
+  /// headers with the `fresh` feature.
+  pub fn has_checked_in_bindings(&self, manifest_dir: &Path) -> bool {
+    manifest_dir.join("src").join("ffi").join(self.ffi_dir()).join("_cuda_runtime_api.rs").is_file()
+  }
+
+  /// The cfgs enabled when building for this version: its `cuda_X_Y`, and
+  /// `cuda_gte_X_Y` for every supported version since 7.0 up to it.
+  pub fn cfgs(&self) -> Vec<String> {
+    let mut cfgs = vec![self.feature()];
+    for v in SUPPORTED.iter().filter(|&&v| v >= CudaVersion::new(7, 0) && v <= *self) {
+      cfgs.push(format!("cuda_gte_{}_{}", v.major, v.minor));
+    }
+    cfgs
+  }
+
+  /// The version to build for with toolkit version `self`: itself if
+  /// supported, otherwise the newest supported version before it.
+  pub fn nearest_supported(&self) -> Option<CudaVersion> {
+    SUPPORTED.iter().rev().find(|&&v| v <= *self).cloned()
+  }
+}
+
+/// The value of `#define name <integer>` in `src`.
+pub fn parse_define(src: &str, name: &str) -> Option<u32> {
+  for line in src.lines() {
+    let mut toks = line.split_whitespace();
+    if toks.next() == Some("#define") && toks.next() == Some(name) {
+      return toks.next().and_then(|t| t.parse().ok());
+    }
+  }
+  None
+}
+
+/// Parse `version.txt`, e.g. `CUDA Version 10.0.130`.
+pub fn parse_version_txt(src: &str) -> Option<CudaVersion> {
+  let rest = &src[src.find("CUDA Version")? + "CUDA Version".len() ..];
+  parse_dotted(rest.trim_start())
+}
+
+/// Parse `version.json` (CUDA 11 and later), using the `version` of its
+/// `cuda` entry.
+pub fn parse_version_json(src: &str) -> Option<CudaVersion> {
+  let rest = &src[src.find("\"cuda\"")? ..];
+  let rest = &rest[rest.find("\"version\"")? + "\"version\"".len() ..];
+  let rest = rest.trim_start().strip_prefix(':')?.trim_start().strip_prefix('"')?;
+  parse_dotted(rest)
+}
+
+/// Parse the leading `major.minor` of `s`.
+pub fn parse_dotted(s: &str) -> Option<CudaVersion> {
+  let mut parts = s.split(|c: char| !c.is_ascii_digit());
+  let major = parts.next()?.parse().ok()?;
+  let minor = parts.next()?.parse().ok()?;
+  Some(CudaVersion{major, minor})
+}
+
+#[derive(Clone, Debug)]
+pub struct Detected {
+  pub version:  CudaVersion,
+  /// The file the version was read from.
+  pub source:   PathBuf,
+}
+
+/// Detect the version of the toolkit from the headers in `include_dirs`,
+/// or else the version files in `roots`. On failure, returns the paths
+/// that were tried.
+pub fn detect(include_dirs: &[PathBuf], roots: &[PathBuf]) -> Result<Detected, Vec<PathBuf>> {
+  let header = |name: &'static str| move |src: &str| parse_define(src, name).map(CudaVersion::from_encoded);
+  let mut sources: Vec<(PathBuf, Box<dyn Fn(&str) -> Option<CudaVersion>>)> = Vec::new();
+  for dir in include_dirs.iter() {
+    sources.push((dir.join("cuda_runtime_api.h"), Box::new(header("CUDART_VERSION"))));
+    sources.push((dir.join("cuda.h"), Box::new(header("CUDA_VERSION"))));
+  }
+  for root in roots.iter() {
+    sources.push((root.join("version.json"), Box::new(parse_version_json)));
+    sources.push((root.join("version.txt"), Box::new(parse_version_txt)));
+  }
+  let mut tried = Vec::new();
+  for (path, parse) in sources {
+    if let Some(version) = fs::read_to_string(&path).ok().and_then(|src| parse(&src)) {
+      return Ok(Detected{version, source: path});
+    }
+    tried.push(path);
+  }
+  Err(tried)
+}
+
+#[derive(Clone, Debug)]
+pub struct Selection {
+  pub version:  CudaVersion,
+  pub warnings: Vec<String>,
+}
+
+/// Choose the version to build for, given the enabled `cuda_X_Y` features
+/// (`explicit`) and the detected toolkit. An explicit feature wins over
+/// the toolkit; if they disagree, that is a warning, or an error if
+/// `strict`. `available` are the versions whose bindings can be used:
+/// those checked in, or every supported version with `fresh`.
+pub fn select(explicit: &[CudaVersion], detected: &Result<Detected, Vec<PathBuf>>, strict: bool, available: &[CudaVersion]) -> Result<Selection, String> {
+  let mut warnings = Vec::new();
+  match (explicit, detected) {
+    (&[], &Ok(ref d)) => {
+      let too_old = || {
+        format!("the CUDA toolkit at {} is version {}, older than the oldest supported version {}",
+            d.source.display(), d.version, SUPPORTED[0])
+      };
+      let nearest = d.version.nearest_supported().ok_or_else(too_old)?;
+      let version = SUPPORTED.iter().rev()
+        .find(|&&v| v <= nearest && available.contains(&v))
+        .cloned()
+        .ok_or_else(too_old)?;
+      if version != nearest {
+        warnings.push(format!("CUDA {} (from {}) has no checked-in bindings, building for CUDA {}; enable the `fresh` or `{}` feature to generate them from its headers",
+            d.version, d.source.display(), version, nearest.feature()));
+      } else if version != d.version {
+        warnings.push(format!("CUDA {} (from {}) is not supported, building for CUDA {}",
+            d.version, d.source.display(), version));
+      }
+      Ok(Selection{version, warnings})
+    }
+    (&[], &Err(ref tried)) => {
+      let tried: Vec<String> = tried.iter().map(|p| p.display().to_string()).collect();
+      Err(format!("could not detect the CUDA toolkit version (tried {}); set CUDA_HOME or enable one of the cuda_X_Y features",
+          tried.join(", ")))
+    }
+    (&[version], detected) => {
+      if !available.contains(&version) {
+        return Err(format!("CUDA {} has no checked-in bindings; enable the `fresh` feature to generate them from its headers",
+            version));
+      }
+      if let Ok(ref d) = *detected {
+        if d.version != version {
+          let msg = format!("feature {} selects CUDA {}, but the toolkit at {} is CUDA {}",
+              version.feature(), version, d.source.display(), d.version);
+          if strict {
+            return Err(msg);
+          }
+          warnings.push(msg);
+        }
+      }
+      Ok(Selection{version, warnings})
+    }
+    (_, _) => {
+      let features: Vec<String> = explicit.iter().map(|v| v.feature()).collect();
+      Err(format!("more than one CUDA version feature is enabled ({}); enable at most one",
+          features.join(", ")))
+    }
+  }
+}
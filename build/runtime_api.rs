@@ -0,0 +1,80 @@
+//! The runtime API manifest, `build/runtime_api.txt`, for `build.rs`.
+//!
+//! With the `fresh` feature, `build.rs` whitelists the functions listed for
+//! the selected version when running bindgen. `tests/build_runtime_api.rs`
+//! reads the same manifest to check the bindings checked in for each
+//! version against it.
+
+use crate::cuda_version::{CudaVersion, parse_dotted};
+
+/// A function listed in the manifest.
+#[derive(Clone, Debug)]
+pub struct Function {
+  /// The `[category]` it is listed under.
+  pub category: String,
+  pub name:     String,
+  /// The first version it is bound for, if not every supported version.
+  pub since:    Option<CudaVersion>,
+  /// The first version it is no longer bound for.
+  pub until:    Option<CudaVersion>,
+}
+
+impl Function {
+  /// Whether the bindings for version `v` include this function.
+  pub fn bound_for(&self, v: CudaVersion) -> bool {
+    self.since.map_or(true, |since| since <= v) && self.until.map_or(true, |until| v < until)
+  }
+}
+
+/// Parse the manifest `src`, in order. Errors name the offending line.
+pub fn parse(src: &str) -> Result<Vec<Function>, String> {
+  let mut fns: Vec<Function> = Vec::new();
+  let mut category: Option<String> = None;
+  for (i, line) in src.lines().enumerate() {
+    let err = |msg: String| format!("runtime_api.txt:{}: {}", i + 1, msg);
+    let line = line.split('#').next().unwrap().trim();
+    if line.is_empty() {
+      continue;
+    }
+    if line.starts_with('[') {
+      match line[1 ..].strip_suffix(']') {
+        Some(name) if !name.trim().is_empty() => category = Some(name.trim().to_string()),
+        _ => return Err(err(format!("bad category header `{}`", line))),
+      }
+      continue;
+    }
+    let mut toks = line.split_whitespace();
+    let name = toks.next().unwrap().to_string();
+    let category = match category {
+      None => return Err(err(format!("`{}` is not under a [category]", name))),
+      Some(ref category) => category.clone(),
+    };
+    if fns.iter().any(|f| f.name == name) {
+      return Err(err(format!("`{}` is listed twice", name)));
+    }
+    let mut f = Function{category, name, since: None, until: None};
+    while let Some(key) = toks.next() {
+      let v = match toks.next().and_then(parse_dotted) {
+        None => return Err(err(format!("expected a version after `{}`", key))),
+        Some(v) => v,
+      };
+      match key {
+        "since" => f.since = Some(v),
+        "until" => f.until = Some(v),
+        _ => return Err(err(format!("unknown key `{}`, expected `since` or `until`", key))),
+      }
+    }
+    if let (Some(since), Some(until)) = (f.since, f.until) {
+      if since >= until {
+        return Err(err(format!("`{}` is bound since {} until {}", f.name, since, until)));
+      }
+    }
+    fns.push(f);
+  }
+  Ok(fns)
+}
+
+/// The names of the functions bound for version `v`, in manifest order.
+pub fn functions_for(fns: &[Function], v: CudaVersion) -> Vec<&str> {
+  fns.iter().filter(|f| f.bound_for(v)).map(|f| f.name.as_str()).collect()
+}
@@ -0,0 +1,441 @@
+//! CUDA arrays: opaque, texture-friendly device allocations whose element
+//! format is described by a channel format descriptor.
+
+#![allow(non_camel_case_types)]
+
+use crate::extent;
+#[cfg(not(feature = "cuda_sys"))]
+use crate::ffi::*;
+use crate::runtime::{CudaError, CudaMemcpyKind, CudaResult, CudaStream, check_copy_len, check_drop, checked_bytes, checked_bytes_2d};
+use crate::timeline;
+
+#[cfg(not(feature = "cuda_sys"))]
+use cuda_api_types::driver_types::*;
+#[cfg(feature = "cuda_sys")]
+use cuda_sys::cudart::*;
+
+use std::marker::{PhantomData};
+use std::os::raw::{c_void, c_uint};
+use std::ptr::{null_mut};
+
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaSuccess: cudaError_t = cudaError_t::Success;
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaErrorCudartUnloading: cudaError_t = cudaError_t::CudartUnloading;
+
+pub const ARRAY_DEFAULT: u32 = 0x00;
+pub const ARRAY_LAYERED: u32 = 0x01;
+pub const ARRAY_SURFACE_LOAD_STORE: u32 = 0x02;
+pub const ARRAY_CUBEMAP: u32 = 0x04;
+pub const ARRAY_TEXTURE_GATHER: u32 = 0x08;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChannelFormatKind {
+  Signed,
+  Unsigned,
+  Float,
+  None,
+}
+
+impl ChannelFormatKind {
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn to_raw(&self) -> cudaChannelFormatKind {
+    match *self {
+      ChannelFormatKind::Signed   => cudaChannelFormatKind_cudaChannelFormatKindSigned,
+      ChannelFormatKind::Unsigned => cudaChannelFormatKind_cudaChannelFormatKindUnsigned,
+      ChannelFormatKind::Float    => cudaChannelFormatKind_cudaChannelFormatKindFloat,
+      ChannelFormatKind::None     => cudaChannelFormatKind_cudaChannelFormatKindNone,
+    }
+  }
+
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn from_raw(kind: cudaChannelFormatKind) -> ChannelFormatKind {
+    match kind {
+      cudaChannelFormatKind_cudaChannelFormatKindSigned   => ChannelFormatKind::Signed,
+      cudaChannelFormatKind_cudaChannelFormatKindUnsigned => ChannelFormatKind::Unsigned,
+      cudaChannelFormatKind_cudaChannelFormatKindFloat    => ChannelFormatKind::Float,
+      _ => ChannelFormatKind::None,
+    }
+  }
+}
+
+/// The bit width of each of up to four components, and how the components
+/// are interpreted.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ChannelFormatDesc {
+  pub x:    i32,
+  pub y:    i32,
+  pub z:    i32,
+  pub w:    i32,
+  pub kind: ChannelFormatKind,
+}
+
+impl ChannelFormatDesc {
+  pub const fn new(x: i32, y: i32, z: i32, w: i32, kind: ChannelFormatKind) -> ChannelFormatDesc {
+    ChannelFormatDesc{x, y, z, w, kind}
+  }
+
+  /// Corresponds to `cudaCreateChannelDesc`.
+  pub fn create(x: i32, y: i32, z: i32, w: i32, kind: ChannelFormatKind) -> ChannelFormatDesc {
+    ChannelFormatDesc::from_raw(&unsafe { cudaCreateChannelDesc(x, y, z, w, kind.to_raw()) })
+  }
+
+  /// Size of one element in bytes.
+  pub fn element_bytes(&self) -> usize {
+    (self.x + self.y + self.z + self.w) as usize / 8
+  }
+
+  pub fn to_raw(&self) -> cudaChannelFormatDesc {
+    cudaChannelFormatDesc{x: self.x, y: self.y, z: self.z, w: self.w, f: self.kind.to_raw()}
+  }
+
+  pub fn from_raw(desc: &cudaChannelFormatDesc) -> ChannelFormatDesc {
+    ChannelFormatDesc::new(desc.x, desc.y, desc.z, desc.w, ChannelFormatKind::from_raw(desc.f))
+  }
+}
+
+/// Element types which can be stored in a `CudaArray`.
+///
+/// This is unsafe to implement: `DESC` must describe exactly the size and
+/// layout of `Self`.
+pub unsafe trait ChannelFormat: Copy + 'static {
+  const DESC: ChannelFormatDesc;
+}
+
+/// A half precision float, stored as its raw bits.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[repr(transparent)]
+pub struct f16(pub u16);
+
+macro_rules! vector_type {
+  ($name:ident, $elem:ty, $align:literal, x, y) => {
+    /// Laid out like the CUDA vector type with the same components.
+    #[derive(Clone, Copy, Default, PartialEq, Debug)]
+    #[repr(C, align($align))]
+    pub struct $name { pub x: $elem, pub y: $elem }
+  };
+  ($name:ident, $elem:ty, $align:literal, x, y, z, w) => {
+    /// Laid out like the CUDA vector type with the same components.
+    #[derive(Clone, Copy, Default, PartialEq, Debug)]
+    #[repr(C, align($align))]
+    pub struct $name { pub x: $elem, pub y: $elem, pub z: $elem, pub w: $elem }
+  };
+}
+
+vector_type!(u8x2,  u8,  2,  x, y);
+vector_type!(u8x4,  u8,  4,  x, y, z, w);
+vector_type!(i8x2,  i8,  2,  x, y);
+vector_type!(i8x4,  i8,  4,  x, y, z, w);
+vector_type!(u16x2, u16, 4,  x, y);
+vector_type!(u16x4, u16, 8,  x, y, z, w);
+vector_type!(i16x2, i16, 4,  x, y);
+vector_type!(i16x4, i16, 8,  x, y, z, w);
+vector_type!(u32x2, u32, 8,  x, y);
+vector_type!(u32x4, u32, 16, x, y, z, w);
+vector_type!(i32x2, i32, 8,  x, y);
+vector_type!(i32x4, i32, 16, x, y, z, w);
+vector_type!(f16x2, f16, 4,  x, y);
+vector_type!(f16x4, f16, 8,  x, y, z, w);
+vector_type!(f32x2, f32, 8,  x, y);
+vector_type!(f32x4, f32, 16, x, y, z, w);
+
+macro_rules! channel_format {
+  ($ty:ty, $kind:ident, $x:expr, $y:expr, $z:expr, $w:expr) => {
+    unsafe impl ChannelFormat for $ty {
+      const DESC: ChannelFormatDesc = ChannelFormatDesc::new($x, $y, $z, $w, ChannelFormatKind::$kind);
+    }
+  };
+}
+
+channel_format!(u8,     Unsigned, 8,  0,  0,  0);
+channel_format!(u8x2,   Unsigned, 8,  8,  0,  0);
+channel_format!(u8x4,   Unsigned, 8,  8,  8,  8);
+channel_format!(i8,     Signed,   8,  0,  0,  0);
+channel_format!(i8x2,   Signed,   8,  8,  0,  0);
+channel_format!(i8x4,   Signed,   8,  8,  8,  8);
+channel_format!(u16,    Unsigned, 16, 0,  0,  0);
+channel_format!(u16x2,  Unsigned, 16, 16, 0,  0);
+channel_format!(u16x4,  Unsigned, 16, 16, 16, 16);
+channel_format!(i16,    Signed,   16, 0,  0,  0);
+channel_format!(i16x2,  Signed,   16, 16, 0,  0);
+channel_format!(i16x4,  Signed,   16, 16, 16, 16);
+channel_format!(u32,    Unsigned, 32, 0,  0,  0);
+channel_format!(u32x2,  Unsigned, 32, 32, 0,  0);
+channel_format!(u32x4,  Unsigned, 32, 32, 32, 32);
+channel_format!(i32,    Signed,   32, 0,  0,  0);
+channel_format!(i32x2,  Signed,   32, 32, 0,  0);
+channel_format!(i32x4,  Signed,   32, 32, 32, 32);
+channel_format!(f16,    Float,    16, 0,  0,  0);
+channel_format!(f16x2,  Float,    16, 16, 0,  0);
+channel_format!(f16x4,  Float,    16, 16, 16, 16);
+channel_format!(f32,    Float,    32, 0,  0,  0);
+channel_format!(f32x2,  Float,    32, 32, 0,  0);
+channel_format!(f32x4,  Float,    32, 32, 32, 32);
+
+/// An owned 1D, 2D or 3D CUDA array of elements of type `T`. A `height` of
+/// zero denotes a 1D array and a `depth` of zero a 1D or 2D array.
+#[derive(Debug)]
+pub struct CudaArray<T: ChannelFormat> {
+  ptr:      cudaArray_t,
+  width:    usize,
+  height:   usize,
+  depth:    usize,
+  flags:    u32,
+  _marker:  PhantomData<T>,
+}
+
+unsafe impl<T: ChannelFormat> Send for CudaArray<T> {}
+unsafe impl<T: ChannelFormat> Sync for CudaArray<T> {}
+
+impl<T: ChannelFormat> Drop for CudaArray<T> {
+  fn drop(&mut self) {
+    check_drop("CudaArray", unsafe { cudaFreeArray(self.ptr) });
+  }
+}
+
+impl<T: ChannelFormat> CudaArray<T> {
+  /// Allocate an uninitialized 1D or 2D array on the current device.
+  pub unsafe fn alloc(width: usize, height: usize) -> CudaResult<CudaArray<T>> {
+    CudaArray::alloc_with_flags(width, height, ARRAY_DEFAULT)
+  }
+
+  /// Like `alloc`, with the given `ARRAY_*` flags.
+  ///
+  /// Corresponds to `cudaMallocArray`.
+  pub unsafe fn alloc_with_flags(width: usize, height: usize, flags: u32) -> CudaResult<CudaArray<T>> {
+    let desc = T::DESC.to_raw();
+    let mut ptr: cudaArray_t = null_mut();
+    match cudaMallocArray(&mut ptr as *mut cudaArray_t, &desc as *const cudaChannelFormatDesc, width, height, flags as c_uint) {
+      cudaError_cudaSuccess => Ok(CudaArray{ptr, width, height, depth: 0, flags, _marker: PhantomData}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Allocate an uninitialized 3D or layered array on the current device,
+  /// with the given `ARRAY_*` flags.
+  ///
+  /// Corresponds to `cudaMalloc3DArray`.
+  pub unsafe fn alloc_3d(width: usize, height: usize, depth: usize, flags: u32) -> CudaResult<CudaArray<T>> {
+    let desc = T::DESC.to_raw();
+    let extent = cudaExtent{width, height, depth};
+    let mut ptr: cudaArray_t = null_mut();
+    match cudaMalloc3DArray(&mut ptr as *mut cudaArray_t, &desc as *const cudaChannelFormatDesc, extent, flags as c_uint) {
+      cudaError_cudaSuccess => Ok(CudaArray{ptr, width, height, depth, flags, _marker: PhantomData}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    self.height
+  }
+
+  pub fn depth(&self) -> usize {
+    self.depth
+  }
+
+  pub fn flags(&self) -> u32 {
+    self.flags
+  }
+
+  /// The number of elements in the array.
+  pub fn len(&self) -> usize {
+    self.width * self.height.max(1) * self.depth.max(1)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  pub fn as_ptr(&self) -> cudaArray_const_t {
+    self.ptr
+  }
+
+  pub fn as_mut_ptr(&mut self) -> cudaArray_t {
+    self.ptr
+  }
+
+  fn row_bytes(&self) -> usize {
+    // NB: The allocation succeeded, so a row of elements fits in `usize`.
+    self.width * T::DESC.element_bytes()
+  }
+
+  /// Copy row-major host data into the whole of a 1D or 2D array.
+  pub fn copy_from_host(&mut self, src: &[T]) -> CudaResult<()> {
+    assert_eq!(self.depth, 0);
+    check_copy_len::<T>(self.len(), src.len())?;
+    let (width, height, pitch) = (self.width, self.height.max(1), self.row_bytes());
+    unsafe { cuda_memcpy_2d_to_array(self, 0, 0, src.as_ptr(), pitch, width, height, CudaMemcpyKind::HostToDevice) }
+  }
+
+  /// Copy the whole of a 1D or 2D array into row-major host data.
+  pub fn copy_to_host(&self, dst: &mut [T]) -> CudaResult<()> {
+    assert_eq!(self.depth, 0);
+    check_copy_len::<T>(self.len(), dst.len())?;
+    let (width, height, pitch) = (self.width, self.height.max(1), self.row_bytes());
+    unsafe { cuda_memcpy_2d_from_array(dst.as_mut_ptr(), pitch, self, 0, 0, width, height, CudaMemcpyKind::DeviceToHost) }
+  }
+
+  /// Enqueue a copy from host memory on `stream`. `src` must stay valid
+  /// and unmodified until the copy completes.
+  pub unsafe fn copy_from_host_async(&mut self, src: &[T], stream: &mut CudaStream) -> CudaResult<()> {
+    assert_eq!(self.depth, 0);
+    check_copy_len::<T>(self.len(), src.len())?;
+    let (width, height, pitch) = (self.width, self.height.max(1), self.row_bytes());
+    cuda_memcpy_2d_to_array_async(self, 0, 0, src.as_ptr(), pitch, width, height, CudaMemcpyKind::HostToDevice, stream)
+  }
+
+  /// Enqueue a copy into host memory on `stream`. `dst` must stay valid
+  /// and untouched until the copy completes.
+  pub unsafe fn copy_to_host_async(&self, dst: &mut [T], stream: &mut CudaStream) -> CudaResult<()> {
+    assert_eq!(self.depth, 0);
+    check_copy_len::<T>(self.len(), dst.len())?;
+    let (width, height, pitch) = (self.width, self.height.max(1), self.row_bytes());
+    cuda_memcpy_2d_from_array_async(dst.as_mut_ptr(), pitch, self, 0, 0, width, height, CudaMemcpyKind::DeviceToHost, stream)
+  }
+}
+
+/// Copy `height` rows of `width` elements, spaced `src_pitch_bytes` apart,
+/// into `dst` starting at element `(x, y)`.
+///
+/// Corresponds to `cudaMemcpy2DToArray`.
+pub unsafe fn cuda_memcpy_2d_to_array<T: ChannelFormat>(
+    dst: &mut CudaArray<T>,
+    x: usize,
+    y: usize,
+    src: *const T,
+    src_pitch_bytes: usize,
+    width: usize,
+    height: usize,
+    kind: CudaMemcpyKind) -> CudaResult<()>
+{
+  let x_bytes = checked_bytes::<T>(x)?;
+  let width_bytes = checked_bytes::<T>(width)?;
+  let src_size = checked_bytes_2d(width_bytes, src_pitch_bytes, height)?;
+  extent::check(src as *const u8, src_size)?;
+  match cudaMemcpy2DToArray(
+      dst.as_mut_ptr(),
+      x_bytes,
+      y,
+      src as *const c_void,
+      src_pitch_bytes,
+      width_bytes,
+      height,
+      kind.to_raw())
+  {
+    cudaError_cudaSuccess => Ok(()),
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Copy `height` rows of `width` elements of `src` starting at element
+/// `(x, y)` into `dst`, spacing rows `dst_pitch_bytes` apart.
+///
+/// Corresponds to `cudaMemcpy2DFromArray`.
+pub unsafe fn cuda_memcpy_2d_from_array<T: ChannelFormat>(
+    dst: *mut T,
+    dst_pitch_bytes: usize,
+    src: &CudaArray<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    kind: CudaMemcpyKind) -> CudaResult<()>
+{
+  let x_bytes = checked_bytes::<T>(x)?;
+  let width_bytes = checked_bytes::<T>(width)?;
+  let dst_size = checked_bytes_2d(width_bytes, dst_pitch_bytes, height)?;
+  extent::check(dst as *const u8, dst_size)?;
+  match cudaMemcpy2DFromArray(
+      dst as *mut c_void,
+      dst_pitch_bytes,
+      src.as_ptr(),
+      x_bytes,
+      y,
+      width_bytes,
+      height,
+      kind.to_raw())
+  {
+    cudaError_cudaSuccess => Ok(()),
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Like `cuda_memcpy_2d_to_array`, enqueued on `stream`.
+///
+/// Corresponds to `cudaMemcpy2DToArrayAsync`.
+pub unsafe fn cuda_memcpy_2d_to_array_async<T: ChannelFormat>(
+    dst: &mut CudaArray<T>,
+    x: usize,
+    y: usize,
+    src: *const T,
+    src_pitch_bytes: usize,
+    width: usize,
+    height: usize,
+    kind: CudaMemcpyKind,
+    stream: &mut CudaStream) -> CudaResult<()>
+{
+  let x_bytes = checked_bytes::<T>(x)?;
+  let width_bytes = checked_bytes::<T>(width)?;
+  let src_size = checked_bytes_2d(width_bytes, src_pitch_bytes, height)?;
+  extent::check(src as *const u8, src_size)?;
+  let op = timeline::begin(stream);
+  match cudaMemcpy2DToArrayAsync(
+      dst.as_mut_ptr(),
+      x_bytes,
+      y,
+      src as *const c_void,
+      src_pitch_bytes,
+      width_bytes,
+      height,
+      kind.to_raw(),
+      stream.as_mut_ptr())
+  {
+    cudaError_cudaSuccess => {
+      timeline::end(op, "cudaMemcpy2DToArrayAsync", Some(width_bytes * height), stream);
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Like `cuda_memcpy_2d_from_array`, enqueued on `stream`.
+///
+/// Corresponds to `cudaMemcpy2DFromArrayAsync`.
+pub unsafe fn cuda_memcpy_2d_from_array_async<T: ChannelFormat>(
+    dst: *mut T,
+    dst_pitch_bytes: usize,
+    src: &CudaArray<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    kind: CudaMemcpyKind,
+    stream: &mut CudaStream) -> CudaResult<()>
+{
+  let x_bytes = checked_bytes::<T>(x)?;
+  let width_bytes = checked_bytes::<T>(width)?;
+  let dst_size = checked_bytes_2d(width_bytes, dst_pitch_bytes, height)?;
+  extent::check(dst as *const u8, dst_size)?;
+  let op = timeline::begin(stream);
+  match cudaMemcpy2DFromArrayAsync(
+      dst as *mut c_void,
+      dst_pitch_bytes,
+      src.as_ptr(),
+      x_bytes,
+      y,
+      width_bytes,
+      height,
+      kind.to_raw(),
+      stream.as_mut_ptr())
+  {
+    cudaError_cudaSuccess => {
+      timeline::end(op, "cudaMemcpy2DFromArrayAsync", Some(width_bytes * height), stream);
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
+  }
+}
@@ -0,0 +1,329 @@
+use crate::runtime::{
+  CudaDevice,
+  CudaError,
+  CudaEvent,
+  CudaEventStatus,
+  CudaResult,
+  CudaStream,
+  cuda_alloc_device,
+  cuda_free_device,
+  with_device,
+};
+
+use std::collections::{BTreeMap, HashMap};
+
+const SMALL_CLASS_MIN: usize = 512;
+const SMALL_CLASS_MAX: usize = 1 << 20;
+const LARGE_CLASS_ROUND: usize = 2 << 20;
+
+/// The size class of a request of `size` bytes. Requests up to 1 MiB are
+/// rounded up to a power of two (at least 512 bytes); larger requests are
+/// rounded up to a multiple of 2 MiB.
+pub fn size_class(size: usize) -> CudaResult<usize> {
+  if size <= SMALL_CLASS_MAX {
+    Ok(size.max(SMALL_CLASS_MIN).next_power_of_two())
+  } else {
+    size.checked_add(LARGE_CLASS_ROUND - 1)
+      .map(|s| s / LARGE_CLASS_ROUND * LARGE_CLASS_ROUND)
+      .ok_or(CudaError::SizeOverflow)
+  }
+}
+
+/// Device memory, stream, and event operations used by a `CachingAllocator`.
+///
+/// `CudaCachingBackend` goes through the runtime. Other implementations make
+/// it possible to exercise the allocator bookkeeping without a device.
+pub trait CachingBackend {
+  type Stream;
+  type Event;
+
+  fn current_device(&mut self) -> CudaResult<i32>;
+  /// A key which is equal for two streams iff they are the same stream.
+  fn stream_key(&mut self, stream: &mut Self::Stream) -> usize;
+  fn alloc(&mut self, device: i32, size: usize) -> CudaResult<*mut u8>;
+  unsafe fn free(&mut self, device: i32, ptr: *mut u8) -> CudaResult<()>;
+  fn record(&mut self, stream: &mut Self::Stream) -> CudaResult<Self::Event>;
+  fn query(&mut self, event: &mut Self::Event) -> CudaResult<bool>;
+  fn synchronize(&mut self, event: &mut Self::Event) -> CudaResult<()>;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CudaCachingBackend;
+
+impl CachingBackend for CudaCachingBackend {
+  type Stream = CudaStream;
+  type Event = CudaEvent;
+
+  fn current_device(&mut self) -> CudaResult<i32> {
+    CudaDevice::get_current().map(|dev| dev.0)
+  }
+
+  fn stream_key(&mut self, stream: &mut CudaStream) -> usize {
+    unsafe { stream.as_mut_ptr() as usize }
+  }
+
+  fn alloc(&mut self, device: i32, size: usize) -> CudaResult<*mut u8> {
+    with_device(device, || cuda_alloc_device(size))
+  }
+
+  unsafe fn free(&mut self, device: i32, ptr: *mut u8) -> CudaResult<()> {
+    with_device(device, || cuda_free_device(ptr))
+  }
+
+  fn record(&mut self, stream: &mut CudaStream) -> CudaResult<CudaEvent> {
+    let mut event = CudaEvent::create_fastest()?;
+    event.record(stream)?;
+    Ok(event)
+  }
+
+  fn query(&mut self, event: &mut CudaEvent) -> CudaResult<bool> {
+    match event.query()? {
+      CudaEventStatus::Complete => Ok(true),
+      CudaEventStatus::NotReady => Ok(false),
+    }
+  }
+
+  fn synchronize(&mut self, event: &mut CudaEvent) -> CudaResult<()> {
+    event.synchronize()
+  }
+}
+
+/// Per-device allocator statistics.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CachingStats {
+  /// Bytes held from the device, whether handed out or cached.
+  pub reserved_bytes: usize,
+  /// Bytes in blocks currently handed out.
+  pub allocated_bytes: usize,
+  /// Bytes requested by callers for the blocks currently handed out.
+  pub requested_bytes: usize,
+  pub peak_reserved_bytes: usize,
+  pub peak_allocated_bytes: usize,
+  pub num_device_allocs: usize,
+  pub num_device_frees: usize,
+  pub num_cache_hits: usize,
+}
+
+impl CachingStats {
+  /// Bytes held in the cache, not handed out.
+  pub fn cached_bytes(&self) -> usize {
+    self.reserved_bytes - self.allocated_bytes
+  }
+
+  /// Fraction of reserved bytes which do not back a caller request, either
+  /// because they are cached or because of size class rounding.
+  pub fn fragmentation(&self) -> f64 {
+    if self.reserved_bytes == 0 {
+      return 0.0;
+    }
+    1.0 - self.requested_bytes as f64 / self.reserved_bytes as f64
+  }
+}
+
+struct LiveBlock {
+  device:       i32,
+  size:         usize,
+  requested:    usize,
+}
+
+struct CachedBlock<E> {
+  ptr:          *mut u8,
+  stream_key:   usize,
+  event:        Option<E>,
+}
+
+/// A stream-ordered caching allocator for device memory.
+///
+/// Blocks are binned by device and size class. A freed block remembers the
+/// stream it was freed on, along with an event recorded on that stream; it is
+/// reused right away by requests on the same stream, and by requests on other
+/// streams once its event has completed. Callers must free a block on the
+/// last stream that used it.
+pub struct CachingAllocator<B: CachingBackend = CudaCachingBackend> {
+  backend:  B,
+  live:     HashMap<usize, LiveBlock>,
+  cached:   BTreeMap<(i32, usize), Vec<CachedBlock<B::Event>>>,
+  stats:    BTreeMap<i32, CachingStats>,
+}
+
+unsafe impl<B: CachingBackend + Send> Send for CachingAllocator<B> where B::Event: Send {}
+
+impl CachingAllocator<CudaCachingBackend> {
+  pub fn new() -> CachingAllocator<CudaCachingBackend> {
+    CachingAllocator::with_backend(CudaCachingBackend)
+  }
+}
+
+impl<B: CachingBackend> Drop for CachingAllocator<B> {
+  fn drop(&mut self) {
+    // NB: Blocks still handed out are leaked rather than freed from under
+    // their users. Errors are suppressed, as this commonly runs while the
+    // runtime is shutting down.
+    let _ = self.empty_cache();
+  }
+}
+
+impl<B: CachingBackend> CachingAllocator<B> {
+  pub fn with_backend(backend: B) -> CachingAllocator<B> {
+    CachingAllocator{
+      backend,
+      live:     HashMap::new(),
+      cached:   BTreeMap::new(),
+      stats:    BTreeMap::new(),
+    }
+  }
+
+  pub fn backend(&mut self) -> &mut B {
+    &mut self.backend
+  }
+
+  /// Statistics for the given device.
+  pub fn stats(&self, device: i32) -> CachingStats {
+    self.stats.get(&device).cloned().unwrap_or_default()
+  }
+
+  /// Allocate a block of at least `size` bytes on the current device, for
+  /// use on `stream`.
+  pub fn alloc(&mut self, size: usize, stream: &mut B::Stream) -> CudaResult<*mut u8> {
+    let device = self.backend.current_device()?;
+    let class = size_class(size)?;
+    let stream_key = self.backend.stream_key(stream);
+    let ptr = match self.take_cached(device, class, stream_key)? {
+      Some(ptr) => {
+        self.stats.entry(device).or_default().num_cache_hits += 1;
+        ptr
+      }
+      None => {
+        let ptr = match self.backend.alloc(device, class) {
+          Ok(ptr) => ptr,
+          Err(e) => {
+            // Return completed cached blocks to the device and try again.
+            // Whatever stopped the release, `e` is why the allocation failed.
+            match self.release_cached(false) {
+              Ok(false) => return Err(e),
+              Ok(true) | Err(_) => {}
+            }
+            self.backend.alloc(device, class)?
+          }
+        };
+        let stats = self.stats.entry(device).or_default();
+        stats.reserved_bytes += class;
+        stats.peak_reserved_bytes = stats.peak_reserved_bytes.max(stats.reserved_bytes);
+        stats.num_device_allocs += 1;
+        ptr
+      }
+    };
+    self.live.insert(ptr as usize, LiveBlock{device, size: class, requested: size});
+    let stats = self.stats.entry(device).or_default();
+    stats.allocated_bytes += class;
+    stats.requested_bytes += size;
+    stats.peak_allocated_bytes = stats.peak_allocated_bytes.max(stats.allocated_bytes);
+    Ok(ptr)
+  }
+
+  /// Return a block to the cache. `stream` must be the last stream to use
+  /// the block; an event is recorded on it so that the block is not reused
+  /// on another stream before that work completes.
+  pub unsafe fn free(&mut self, ptr: *mut u8, stream: &mut B::Stream) -> CudaResult<()> {
+    let block = match self.live.remove(&(ptr as usize)) {
+      None => return Err(CudaError::UnknownPointer{ptr: ptr as usize}),
+      Some(block) => block,
+    };
+    let event = match self.backend.record(stream) {
+      Err(e) => {
+        self.live.insert(ptr as usize, block);
+        return Err(e);
+      }
+      Ok(event) => event,
+    };
+    let stream_key = self.backend.stream_key(stream);
+    let stats = self.stats.entry(block.device).or_default();
+    stats.allocated_bytes -= block.size;
+    stats.requested_bytes -= block.requested;
+    self.cached.entry((block.device, block.size)).or_default()
+      .push(CachedBlock{ptr, stream_key, event: Some(event)});
+    Ok(())
+  }
+
+  /// Return all cached blocks to the device, waiting for any work still
+  /// pending on them. Blocks which are handed out are not affected.
+  pub fn empty_cache(&mut self) -> CudaResult<()> {
+    self.release_cached(true).map(|_| ())
+  }
+
+  fn take_cached(&mut self, device: i32, class: usize, stream_key: usize) -> CudaResult<Option<*mut u8>> {
+    let backend = &mut self.backend;
+    let bin = match self.cached.get_mut(&(device, class)) {
+      None => return Ok(None),
+      Some(bin) => bin,
+    };
+    if let Some(idx) = bin.iter().position(|b| b.stream_key == stream_key) {
+      return Ok(Some(bin.swap_remove(idx).ptr));
+    }
+    for idx in 0 .. bin.len() {
+      let complete = match bin[idx].event {
+        None => true,
+        Some(ref mut event) => backend.query(event)?,
+      };
+      if complete {
+        return Ok(Some(bin.swap_remove(idx).ptr));
+      }
+    }
+    Ok(None)
+  }
+
+  /// Free cached blocks. If `wait` is false, only blocks whose last use has
+  /// completed are freed. Returns whether any block was freed.
+  fn release_cached(&mut self, wait: bool) -> CudaResult<bool> {
+    let mut released = false;
+    let keys: Vec<_> = self.cached.keys().cloned().collect();
+    for (device, class) in keys {
+      let mut bin = self.cached.remove(&(device, class)).unwrap_or_default();
+      let mut res = Ok(());
+      let mut kept = Vec::new();
+      while let Some(mut block) = bin.pop() {
+        if res.is_err() {
+          kept.push(block);
+          continue;
+        }
+        if let Some(ref mut event) = block.event {
+          let ready = if wait {
+            self.backend.synchronize(event).map(|_| true)
+          } else {
+            self.backend.query(event)
+          };
+          match ready {
+            Err(e) => {
+              res = Err(e);
+              kept.push(block);
+              continue;
+            }
+            Ok(false) => {
+              kept.push(block);
+              continue;
+            }
+            Ok(true) => {}
+          }
+        }
+        block.event = None;
+        match unsafe { self.backend.free(device, block.ptr) } {
+          Err(e) => {
+            res = Err(e);
+            kept.push(block);
+          }
+          Ok(_) => {
+            let stats = self.stats.entry(device).or_default();
+            stats.reserved_bytes -= class;
+            stats.num_device_frees += 1;
+            released = true;
+          }
+        }
+      }
+      if !kept.is_empty() {
+        self.cached.insert((device, class), kept);
+      }
+      res?;
+    }
+    Ok(released)
+  }
+}
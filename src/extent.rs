@@ -0,0 +1,40 @@
+use crate::runtime::{CudaError, CudaResult};
+
+use std::collections::{BTreeMap};
+use std::sync::{Mutex};
+
+/// Base address and size of every live allocation made through `cudart`.
+static EXTENTS: Mutex<BTreeMap<usize, usize>> = Mutex::new(BTreeMap::new());
+
+pub(crate) fn insert(ptr: *mut u8, size: usize) {
+  if ptr.is_null() {
+    return;
+  }
+  let mut extents = EXTENTS.lock().unwrap();
+  extents.insert(ptr as usize, size);
+}
+
+pub(crate) fn remove(ptr: *mut u8) -> Option<usize> {
+  let mut extents = EXTENTS.lock().unwrap();
+  extents.remove(&(ptr as usize))
+}
+
+/// Check that `size` bytes starting at `ptr` stay inside the allocation
+/// containing `ptr`, if `ptr` belongs to a known allocation. Pointers not
+/// allocated through `cudart` are not checked.
+pub(crate) fn check(ptr: *const u8, size: usize) -> CudaResult<()> {
+  let addr = ptr as usize;
+  let extents = EXTENTS.lock().unwrap();
+  let (&base, &alloc_size) = match extents.range( ..= addr).next_back() {
+    None => return Ok(()),
+    Some(kv) => kv,
+  };
+  let offset = addr - base;
+  if offset >= alloc_size {
+    return Ok(());
+  }
+  match offset.checked_add(size) {
+    Some(end) if end <= alloc_size => Ok(()),
+    _ => Err(CudaError::OutOfBounds{offset, size, alloc_size}),
+  }
+}
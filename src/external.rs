@@ -0,0 +1,246 @@
+//! Importing memory and semaphores exported by other APIs, e.g. Vulkan, as
+//! opaque file descriptors (CUDA 10.0 and later).
+
+use crate::ffi::*;
+use crate::ffi::{
+  cudaExternalHandle,
+  cudaExternalMemory_t,
+  cudaExternalMemoryBufferDesc,
+  cudaExternalMemoryHandleDesc,
+  cudaExternalSemaphore_t,
+  cudaExternalSemaphoreHandleDesc,
+  cudaExternalSemaphoreSignalParams,
+  cudaExternalSemaphoreWaitParams,
+  cudaExternalMemoryDedicated,
+  cudaExternalMemoryHandleType_cudaExternalMemoryHandleTypeOpaqueFd,
+  cudaExternalSemaphoreHandleType_cudaExternalSemaphoreHandleTypeOpaqueFd,
+};
+// CUDA 11.2 headers likewise redirect these to the `_v2` entry points.
+#[cfg(cuda_gte_11_2)]
+use crate::ffi::{
+  cudaSignalExternalSemaphoresAsync_v2 as cudaSignalExternalSemaphoresAsync,
+  cudaWaitExternalSemaphoresAsync_v2 as cudaWaitExternalSemaphoresAsync,
+};
+use crate::launch::{KernelArg};
+use crate::runtime::{CudaError, CudaMemcpyKind, CudaResult, CudaStream, check_copy_len, check_drop, checked_bytes, cuda_memcpy};
+
+use cuda_api_types::driver_types::*;
+
+use std::marker::{PhantomData};
+use std::os::raw::{c_void};
+use std::os::unix::io::{AsRawFd, IntoRawFd, OwnedFd};
+use std::ptr::{null_mut};
+
+/// Memory imported from another API. Buffers mapped from it borrow it.
+pub struct ExternalMemory {
+  ptr:  cudaExternalMemory_t,
+  size: u64,
+}
+
+unsafe impl Send for ExternalMemory {}
+unsafe impl Sync for ExternalMemory {}
+
+impl Drop for ExternalMemory {
+  fn drop(&mut self) {
+    check_drop("ExternalMemory", unsafe { cudaDestroyExternalMemory(self.ptr) });
+  }
+}
+
+impl ExternalMemory {
+  /// Import `size` bytes of memory exported as an opaque file descriptor.
+  /// `dedicated` must be set for dedicated allocations (e.g. Vulkan memory
+  /// allocated with `VkMemoryDedicatedAllocateInfo`).
+  ///
+  /// On success the runtime takes over `fd`; on failure `fd` is closed.
+  ///
+  /// Corresponds to `cudaImportExternalMemory`.
+  pub fn import_fd(fd: OwnedFd, size: u64, dedicated: bool) -> CudaResult<ExternalMemory> {
+    let desc = cudaExternalMemoryHandleDesc{
+      type_:    cudaExternalMemoryHandleType_cudaExternalMemoryHandleTypeOpaqueFd,
+      handle:   cudaExternalHandle{fd: fd.as_raw_fd()},
+      size,
+      flags:    if dedicated { cudaExternalMemoryDedicated } else { 0 },
+    };
+    let mut ptr: cudaExternalMemory_t = null_mut();
+    match unsafe { cudaImportExternalMemory(&mut ptr as *mut cudaExternalMemory_t, &desc as *const cudaExternalMemoryHandleDesc) } {
+      cudaError_cudaSuccess => {
+        let _ = fd.into_raw_fd();
+        Ok(ExternalMemory{ptr, size})
+      }
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  pub fn size(&self) -> u64 {
+    self.size
+  }
+
+  /// Map `len` elements starting `offset` bytes into the memory as a device
+  /// buffer. The offset must meet the alignment requirements of the
+  /// exporting API.
+  ///
+  /// Corresponds to `cudaExternalMemoryGetMappedBuffer`.
+  pub fn mapped_buffer<T: Copy + 'static>(&self, offset: u64, len: usize) -> CudaResult<ExternalBuffer<'_, T>> {
+    let size = checked_bytes::<T>(len)? as u64;
+    check_mapped_range(offset, size, self.size)?;
+    let desc = cudaExternalMemoryBufferDesc{offset, size, flags: 0};
+    let mut ptr: *mut c_void = null_mut();
+    match unsafe { cudaExternalMemoryGetMappedBuffer(&mut ptr as *mut *mut c_void, self.ptr, &desc as *const cudaExternalMemoryBufferDesc) } {
+      cudaError_cudaSuccess => Ok(ExternalBuffer{ptr: ptr as *mut T, len, _memory: PhantomData}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
+
+/// Check that `size` bytes at `offset` fit in external memory of
+/// `memory_size` bytes.
+fn check_mapped_range(offset: u64, size: u64, memory_size: u64) -> CudaResult<()> {
+  if offset.checked_add(size).map_or(true, |end| end > memory_size) {
+    return Err(CudaError::OutOfBounds{
+      offset:     offset as usize,
+      size:       size as usize,
+      alloc_size: memory_size as usize,
+    });
+  }
+  Ok(())
+}
+
+/// A device buffer mapped onto `ExternalMemory`. The mapping is freed on
+/// drop.
+pub struct ExternalBuffer<'a, T: Copy + 'static> {
+  ptr:      *mut T,
+  len:      usize,
+  _memory:  PhantomData<&'a ExternalMemory>,
+}
+
+unsafe impl<'a, T: Copy + 'static> Send for ExternalBuffer<'a, T> {}
+unsafe impl<'a, T: Copy + 'static> Sync for ExternalBuffer<'a, T> {}
+
+impl<'a, T: Copy + 'static> Drop for ExternalBuffer<'a, T> {
+  fn drop(&mut self) {
+    check_drop("ExternalBuffer", unsafe { cudaFree(self.ptr as *mut c_void) });
+  }
+}
+
+impl<'a, T: Copy + 'static> ExternalBuffer<'a, T> {
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  pub fn as_ptr(&self) -> *const T {
+    self.ptr
+  }
+
+  pub fn as_mut_ptr(&mut self) -> *mut T {
+    self.ptr
+  }
+
+  pub fn copy_from_host(&mut self, src: &[T]) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, src.len())?;
+    unsafe { cuda_memcpy(self.ptr, src.as_ptr(), self.len, CudaMemcpyKind::HostToDevice) }
+  }
+
+  pub fn copy_to_host(&self, dst: &mut [T]) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, dst.len())?;
+    unsafe { cuda_memcpy(dst.as_mut_ptr(), self.ptr, self.len, CudaMemcpyKind::DeviceToHost) }
+  }
+}
+
+/// A mapped buffer is passed as a pointer to its first element.
+unsafe impl<'a, T: Copy + 'static> KernelArg for ExternalBuffer<'a, T> {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    &self.ptr as *const *mut T as *mut c_void
+  }
+}
+
+/// A semaphore imported from another API.
+pub struct ExternalSemaphore {
+  ptr:  cudaExternalSemaphore_t,
+}
+
+unsafe impl Send for ExternalSemaphore {}
+
+impl Drop for ExternalSemaphore {
+  fn drop(&mut self) {
+    check_drop("ExternalSemaphore", unsafe { cudaDestroyExternalSemaphore(self.ptr) });
+  }
+}
+
+impl ExternalSemaphore {
+  /// Import a semaphore exported as an opaque file descriptor.
+  ///
+  /// On success the runtime takes over `fd`; on failure `fd` is closed.
+  ///
+  /// Corresponds to `cudaImportExternalSemaphore`.
+  pub fn import_fd(fd: OwnedFd) -> CudaResult<ExternalSemaphore> {
+    let desc = cudaExternalSemaphoreHandleDesc{
+      type_:    cudaExternalSemaphoreHandleType_cudaExternalSemaphoreHandleTypeOpaqueFd,
+      handle:   cudaExternalHandle{fd: fd.as_raw_fd()},
+      flags:    0,
+    };
+    let mut ptr: cudaExternalSemaphore_t = null_mut();
+    match unsafe { cudaImportExternalSemaphore(&mut ptr as *mut cudaExternalSemaphore_t, &desc as *const cudaExternalSemaphoreHandleDesc) } {
+      cudaError_cudaSuccess => {
+        let _ = fd.into_raw_fd();
+        Ok(ExternalSemaphore{ptr})
+      }
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Enqueue a signal of the semaphore on `stream`, after all work enqueued
+  /// on it so far.
+  ///
+  /// Corresponds to `cudaSignalExternalSemaphoresAsync`.
+  pub fn signal(&mut self, stream: &mut CudaStream) -> CudaResult<()> {
+    let params = cudaExternalSemaphoreSignalParams::default();
+    match unsafe { cudaSignalExternalSemaphoresAsync(
+        &self.ptr as *const cudaExternalSemaphore_t,
+        &params as *const cudaExternalSemaphoreSignalParams,
+        1,
+        stream.as_mut_ptr()) }
+    {
+      cudaError_cudaSuccess => Ok(()),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Make work enqueued on `stream` after this call wait until the
+  /// semaphore is signaled. Each signal satisfies a single wait.
+  ///
+  /// Corresponds to `cudaWaitExternalSemaphoresAsync`.
+  pub fn wait(&mut self, stream: &mut CudaStream) -> CudaResult<()> {
+    let params = cudaExternalSemaphoreWaitParams::default();
+    match unsafe { cudaWaitExternalSemaphoresAsync(
+        &self.ptr as *const cudaExternalSemaphore_t,
+        &params as *const cudaExternalSemaphoreWaitParams,
+        1,
+        stream.as_mut_ptr()) }
+    {
+      cudaError_cudaSuccess => Ok(()),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_check_mapped_range() {
+    check_mapped_range(0, 4096, 4096).unwrap();
+    check_mapped_range(1024, 1024, 4096).unwrap();
+    check_mapped_range(4096, 0, 4096).unwrap();
+    match check_mapped_range(1024, 4096, 4096) {
+      Err(CudaError::OutOfBounds{offset: 1024, size: 4096, alloc_size: 4096}) => {}
+      e => panic!("expected out of bounds: {:?}", e),
+    }
+    assert!(check_mapped_range(4097, 0, 4096).is_err());
+    assert!(check_mapped_range(u64::max_value(), 1, u64::max_value()).is_err());
+  }
+}
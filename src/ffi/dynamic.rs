@@ -0,0 +1,185 @@
+//! Runtime loading of `libcudart` (with the `dynamic_loading` feature).
+//!
+//! The runtime API functions of `ffi` resolve their symbol on first call.
+//! The library is searched for, in order:
+//!
+//! - at `$CUDART_LIBRARY`, if set, and nowhere else;
+//! - in `$CUDA_HOME/lib64` and `$CUDA_PATH/lib64`;
+//! - in the dynamic loader's search path (`LD_LIBRARY_PATH`, the `ld.so`
+//!   cache, etc.);
+//! - in `/usr/local/cuda/lib64`.
+//!
+//! In each place the soname of the enabled CUDA version is preferred over
+//! plain `libcudart.so`. While the library (or one of its symbols) is
+//! unavailable, runtime API calls fail with `RUNTIME_UNAVAILABLE`, which
+//! the wrappers report as `CudaError::RuntimeUnavailable`.
+
+use cuda_api_types::driver_types::*;
+
+use std::env;
+use std::ffi::{CStr, CString};
+use std::mem::{zeroed};
+use std::os::raw::{c_char, c_void};
+use std::path::{PathBuf};
+use std::ptr::{null_mut};
+use std::sync::{OnceLock};
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+/// The error code returned by runtime API calls while the runtime is
+/// unavailable. It is outside the range of the runtime's own codes.
+pub const RUNTIME_UNAVAILABLE: cudaError_t = 0xffff_ffff;
+
+#[cfg(cuda_6_5)]
+const SONAME: &str = "libcudart.so.6.5";
+#[cfg(cuda_7_0)]
+const SONAME: &str = "libcudart.so.7.0";
+#[cfg(cuda_7_5)]
+const SONAME: &str = "libcudart.so.7.5";
+#[cfg(cuda_8_0)]
+const SONAME: &str = "libcudart.so.8.0";
+#[cfg(cuda_9_0)]
+const SONAME: &str = "libcudart.so.9.0";
+#[cfg(cuda_9_1)]
+const SONAME: &str = "libcudart.so.9.1";
+#[cfg(cuda_9_2)]
+const SONAME: &str = "libcudart.so.9.2";
+#[cfg(cuda_10_0)]
+const SONAME: &str = "libcudart.so.10.0";
+#[cfg(cuda_10_1)]
+const SONAME: &str = "libcudart.so.10.1";
+#[cfg(cuda_10_2)]
+const SONAME: &str = "libcudart.so.10.2";
+// From CUDA 11, the soname only carries the major version.
+#[cfg(all(cuda_gte_11_0, not(cuda_gte_12_0)))]
+const SONAME: &str = "libcudart.so.11.0";
+#[cfg(cuda_gte_12_0)]
+const SONAME: &str = "libcudart.so.12";
+
+struct Library {
+  handle:   *mut c_void,
+  path:     String,
+}
+
+unsafe impl Send for Library {}
+unsafe impl Sync for Library {}
+
+/// The loaded library, or the reason it could not be loaded.
+static LIBRARY: OnceLock<Result<Library, String>> = OnceLock::new();
+
+fn library() -> &'static Result<Library, String> {
+  LIBRARY.get_or_init(load)
+}
+
+fn candidates() -> Vec<String> {
+  if let Some(path) = env::var_os("CUDART_LIBRARY") {
+    return vec![path.to_string_lossy().into_owned()];
+  }
+  let mut dirs = Vec::new();
+  for var in &["CUDA_HOME", "CUDA_PATH"] {
+    if let Some(dir) = env::var_os(var) {
+      dirs.push(PathBuf::from(dir).join("lib64"));
+    }
+  }
+  let mut paths = Vec::new();
+  for dir in dirs.iter() {
+    paths.push(dir.join(SONAME).to_string_lossy().into_owned());
+    paths.push(dir.join("libcudart.so").to_string_lossy().into_owned());
+  }
+  // Bare names are looked up by the dynamic loader.
+  paths.push(SONAME.to_owned());
+  paths.push("libcudart.so".to_owned());
+  let dir = PathBuf::from("/usr/local/cuda/lib64");
+  paths.push(dir.join(SONAME).to_string_lossy().into_owned());
+  paths.push(dir.join("libcudart.so").to_string_lossy().into_owned());
+  paths
+}
+
+fn load() -> Result<Library, String> {
+  let mut errors = Vec::new();
+  for path in candidates() {
+    let c_path = match CString::new(path.clone()) {
+      Err(_) => continue,
+      Ok(c_path) => c_path,
+    };
+    let handle = unsafe { libc::dlopen(c_path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+    if !handle.is_null() {
+      return Ok(Library{handle, path});
+    }
+    let err = unsafe { libc::dlerror() };
+    if err.is_null() {
+      errors.push(path);
+    } else {
+      errors.push(unsafe { CStr::from_ptr(err) }.to_string_lossy().into_owned());
+    }
+  }
+  Err(format!("libcudart not found: {}", errors.join("; ")))
+}
+
+/// Whether the runtime library could be loaded. Loads it if not yet
+/// attempted.
+pub fn is_available() -> bool {
+  library().is_ok()
+}
+
+/// The path the runtime library was loaded from.
+pub fn library_path() -> Option<&'static str> {
+  library().as_ref().ok().map(|lib| lib.path.as_str())
+}
+
+/// Why the runtime library could not be loaded, listing each path tried.
+pub fn load_error() -> Option<&'static str> {
+  library().as_ref().err().map(|e| e.as_str())
+}
+
+/// A lazily resolved runtime API symbol.
+pub struct Symbol {
+  name: &'static str,
+  ptr:  AtomicPtr<c_void>,
+}
+
+impl Symbol {
+  /// `name` must be NUL terminated.
+  pub const fn new(name: &'static str) -> Symbol {
+    Symbol{name, ptr: AtomicPtr::new(null_mut())}
+  }
+
+  pub fn get(&self) -> Option<*mut c_void> {
+    let ptr = self.ptr.load(Ordering::Acquire);
+    if !ptr.is_null() {
+      return Some(ptr);
+    }
+    let lib = library().as_ref().ok()?;
+    let ptr = unsafe { libc::dlsym(lib.handle, self.name.as_ptr() as *const c_char) };
+    if ptr.is_null() {
+      return None;
+    }
+    self.ptr.store(ptr, Ordering::Release);
+    Some(ptr)
+  }
+}
+
+/// The return value of a runtime API call while the runtime is
+/// unavailable.
+pub trait Unavailable {
+  fn unavailable() -> Self;
+}
+
+impl Unavailable for cudaError_t {
+  fn unavailable() -> cudaError_t {
+    RUNTIME_UNAVAILABLE
+  }
+}
+
+/// `cudaGetErrorName` and `cudaGetErrorString`.
+impl Unavailable for *const c_char {
+  fn unavailable() -> *const c_char {
+    b"CUDA runtime unavailable\0".as_ptr() as *const c_char
+  }
+}
+
+/// `cudaCreateChannelDesc`.
+impl Unavailable for cudaChannelFormatDesc {
+  fn unavailable() -> cudaChannelFormatDesc {
+    unsafe { zeroed() }
+  }
+}
@@ -4,38 +4,807 @@
 
 pub use self::v::cuda_runtime_api::*;
 
-#[cfg(feature = "cuda_8_0")]
+#[cfg(feature = "dynamic_loading")]
+pub mod dynamic;
+
+use std::os::raw::{c_uint};
+#[cfg(cuda_gte_9_0)]
+use std::os::raw::{c_void};
+#[cfg(cuda_gte_9_0)]
+use cuda_api_types::driver_types::{cudaStream_t};
+
+/// `dim3` from `vector_types.h`.
+#[derive(Clone, Copy, Default, Debug)]
+#[repr(C)]
+pub struct dim3 {
+  pub x: c_uint,
+  pub y: c_uint,
+  pub z: c_uint,
+}
+
+/// `cudaLaunchParams` from `driver_types.h`, declared here alongside `dim3`.
+#[cfg(cuda_gte_9_0)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct cudaLaunchParams {
+  pub func: *mut c_void,
+  pub gridDim: dim3,
+  pub blockDim: dim3,
+  pub args: *mut *mut c_void,
+  pub sharedMem: usize,
+  pub stream: cudaStream_t,
+}
+
+/// `cudaKernelNodeParams` from `driver_types.h`, declared here alongside
+/// `dim3`.
+#[cfg(cuda_gte_10_0)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct cudaKernelNodeParams {
+  pub func: *mut c_void,
+  pub gridDim: dim3,
+  pub blockDim: dim3,
+  pub sharedMemBytes: c_uint,
+  pub kernelParams: *mut *mut c_void,
+  pub extra: *mut *mut c_void,
+}
+
+/// External memory and semaphore types from `driver_types.h`, declared here
+/// with named unions.
+#[cfg(cuda_gte_10_0)]
+mod external {
+  use std::os::raw::{c_int, c_uint, c_ulonglong, c_void};
+
+  #[repr(C)]
+  pub struct CUexternalMemory_st { _unused: [u8; 0] }
+  pub type cudaExternalMemory_t = *mut CUexternalMemory_st;
+  #[repr(C)]
+  pub struct CUexternalSemaphore_st { _unused: [u8; 0] }
+  pub type cudaExternalSemaphore_t = *mut CUexternalSemaphore_st;
+
+  pub type cudaExternalMemoryHandleType = c_uint;
+  pub const cudaExternalMemoryHandleType_cudaExternalMemoryHandleTypeOpaqueFd: cudaExternalMemoryHandleType = 1;
+  pub const cudaExternalMemoryHandleType_cudaExternalMemoryHandleTypeOpaqueWin32: cudaExternalMemoryHandleType = 2;
+  pub const cudaExternalMemoryHandleType_cudaExternalMemoryHandleTypeOpaqueWin32Kmt: cudaExternalMemoryHandleType = 3;
+  pub const cudaExternalMemoryHandleType_cudaExternalMemoryHandleTypeD3D12Heap: cudaExternalMemoryHandleType = 4;
+  pub const cudaExternalMemoryHandleType_cudaExternalMemoryHandleTypeD3D12Resource: cudaExternalMemoryHandleType = 5;
+  pub const cudaExternalMemoryDedicated: c_uint = 0x1;
+
+  pub type cudaExternalSemaphoreHandleType = c_uint;
+  pub const cudaExternalSemaphoreHandleType_cudaExternalSemaphoreHandleTypeOpaqueFd: cudaExternalSemaphoreHandleType = 1;
+  pub const cudaExternalSemaphoreHandleType_cudaExternalSemaphoreHandleTypeOpaqueWin32: cudaExternalSemaphoreHandleType = 2;
+  pub const cudaExternalSemaphoreHandleType_cudaExternalSemaphoreHandleTypeOpaqueWin32Kmt: cudaExternalSemaphoreHandleType = 3;
+  pub const cudaExternalSemaphoreHandleType_cudaExternalSemaphoreHandleTypeD3D12Fence: cudaExternalSemaphoreHandleType = 4;
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub struct cudaExternalHandleWin32 {
+    pub handle: *mut c_void,
+    pub name: *const c_void,
+  }
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub union cudaExternalHandle {
+    pub fd: c_int,
+    pub win32: cudaExternalHandleWin32,
+  }
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub struct cudaExternalMemoryHandleDesc {
+    pub type_: cudaExternalMemoryHandleType,
+    pub handle: cudaExternalHandle,
+    pub size: c_ulonglong,
+    pub flags: c_uint,
+  }
+
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalMemoryBufferDesc {
+    pub offset: c_ulonglong,
+    pub size: c_ulonglong,
+    pub flags: c_uint,
+  }
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreHandleDesc {
+    pub type_: cudaExternalSemaphoreHandleType,
+    pub handle: cudaExternalHandle,
+    pub flags: c_uint,
+  }
+
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreFence {
+    pub value: c_ulonglong,
+  }
+
+  #[cfg(not(cuda_gte_10_2))]
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreSignalParams {
+    pub fence: cudaExternalSemaphoreFence,
+    pub flags: c_uint,
+  }
+
+  #[cfg(not(cuda_gte_10_2))]
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreWaitParams {
+    pub fence: cudaExternalSemaphoreFence,
+    pub flags: c_uint,
+  }
+
+  // CUDA 10.2 nests the fence in `params`, next to the NvSciSync and keyed
+  // mutex parameters. CUDA 11.2 pads the structs with reserved fields, and
+  // takes them through new `_v2` entry points.
+
+  #[cfg(cuda_gte_10_2)]
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreKeyedMutexSignal {
+    pub key: c_ulonglong,
+  }
+
+  #[cfg(cuda_gte_10_2)]
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreKeyedMutexWait {
+    pub key: c_ulonglong,
+    pub timeoutMs: c_uint,
+  }
+
+  /// The `nvSciSync` union is declared as its `reserved` integer member;
+  /// the `fence` pointer member does not affect the layout.
+  #[cfg(cuda_gte_10_2)]
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreSignalParamsParams {
+    pub fence: cudaExternalSemaphoreFence,
+    pub nvSciSync: c_ulonglong,
+    pub keyedMutex: cudaExternalSemaphoreKeyedMutexSignal,
+    #[cfg(cuda_gte_11_2)]
+    pub reserved: [c_uint; 12],
+  }
+
+  #[cfg(cuda_gte_10_2)]
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreSignalParams {
+    pub params: cudaExternalSemaphoreSignalParamsParams,
+    pub flags: c_uint,
+    #[cfg(cuda_gte_11_2)]
+    pub reserved: [c_uint; 16],
+  }
+
+  /// As for `cudaExternalSemaphoreSignalParamsParams`.
+  #[cfg(cuda_gte_10_2)]
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreWaitParamsParams {
+    pub fence: cudaExternalSemaphoreFence,
+    pub nvSciSync: c_ulonglong,
+    pub keyedMutex: cudaExternalSemaphoreKeyedMutexWait,
+    #[cfg(cuda_gte_11_2)]
+    pub reserved: [c_uint; 10],
+  }
+
+  #[cfg(cuda_gte_10_2)]
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaExternalSemaphoreWaitParams {
+    pub params: cudaExternalSemaphoreWaitParamsParams,
+    pub flags: c_uint,
+    #[cfg(cuda_gte_11_2)]
+    pub reserved: [c_uint; 16],
+  }
+}
+
+#[cfg(cuda_gte_10_0)]
+pub use self::external::*;
+
+/// `cudaStreamCaptureMode` from `driver_types.h` (CUDA 10.1 and later).
+#[cfg(cuda_gte_10_1)]
+pub type cudaStreamCaptureMode = c_uint;
+#[cfg(cuda_gte_10_1)]
+pub const cudaStreamCaptureMode_cudaStreamCaptureModeGlobal: cudaStreamCaptureMode = 0;
+#[cfg(cuda_gte_10_1)]
+pub const cudaStreamCaptureMode_cudaStreamCaptureModeThreadLocal: cudaStreamCaptureMode = 1;
+#[cfg(cuda_gte_10_1)]
+pub const cudaStreamCaptureMode_cudaStreamCaptureModeRelaxed: cudaStreamCaptureMode = 2;
+
+/// `cudaDeviceProp` and `cudaFuncAttributes` from `driver_types.h`, declared
+/// here for the versions where they have grown past the `cuda_api_types`
+/// definitions. CUDA 11.0 adds device properties; CUDA 12.0 appends more
+/// along with reserved space, filled in through `cudaGetDeviceProperties_v2`,
+/// and adds the cluster attributes of a function.
+#[cfg(cuda_gte_11_0)]
+mod device {
+  use cuda_api_types::driver_types::{cudaUUID_t};
+  use std::os::raw::{c_char, c_int, c_uint};
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub struct cudaDeviceProp {
+    pub name: [c_char; 256],
+    pub uuid: cudaUUID_t,
+    pub luid: [c_char; 8],
+    pub luidDeviceNodeMask: c_uint,
+    pub totalGlobalMem: usize,
+    pub sharedMemPerBlock: usize,
+    pub regsPerBlock: c_int,
+    pub warpSize: c_int,
+    pub memPitch: usize,
+    pub maxThreadsPerBlock: c_int,
+    pub maxThreadsDim: [c_int; 3],
+    pub maxGridSize: [c_int; 3],
+    pub clockRate: c_int,
+    pub totalConstMem: usize,
+    pub major: c_int,
+    pub minor: c_int,
+    pub textureAlignment: usize,
+    pub texturePitchAlignment: usize,
+    pub deviceOverlap: c_int,
+    pub multiProcessorCount: c_int,
+    pub kernelExecTimeoutEnabled: c_int,
+    pub integrated: c_int,
+    pub canMapHostMemory: c_int,
+    pub computeMode: c_int,
+    pub maxTexture1D: c_int,
+    pub maxTexture1DMipmap: c_int,
+    pub maxTexture1DLinear: c_int,
+    pub maxTexture2D: [c_int; 2],
+    pub maxTexture2DMipmap: [c_int; 2],
+    pub maxTexture2DLinear: [c_int; 3],
+    pub maxTexture2DGather: [c_int; 2],
+    pub maxTexture3D: [c_int; 3],
+    pub maxTexture3DAlt: [c_int; 3],
+    pub maxTextureCubemap: c_int,
+    pub maxTexture1DLayered: [c_int; 2],
+    pub maxTexture2DLayered: [c_int; 3],
+    pub maxTextureCubemapLayered: [c_int; 2],
+    pub maxSurface1D: c_int,
+    pub maxSurface2D: [c_int; 2],
+    pub maxSurface3D: [c_int; 3],
+    pub maxSurface1DLayered: [c_int; 2],
+    pub maxSurface2DLayered: [c_int; 3],
+    pub maxSurfaceCubemap: c_int,
+    pub maxSurfaceCubemapLayered: [c_int; 2],
+    pub surfaceAlignment: usize,
+    pub concurrentKernels: c_int,
+    pub ECCEnabled: c_int,
+    pub pciBusID: c_int,
+    pub pciDeviceID: c_int,
+    pub pciDomainID: c_int,
+    pub tccDriver: c_int,
+    pub asyncEngineCount: c_int,
+    pub unifiedAddressing: c_int,
+    pub memoryClockRate: c_int,
+    pub memoryBusWidth: c_int,
+    pub l2CacheSize: c_int,
+    pub persistingL2CacheMaxSize: c_int,
+    pub maxThreadsPerMultiProcessor: c_int,
+    pub streamPrioritiesSupported: c_int,
+    pub globalL1CacheSupported: c_int,
+    pub localL1CacheSupported: c_int,
+    pub sharedMemPerMultiprocessor: usize,
+    pub regsPerMultiprocessor: c_int,
+    pub managedMemory: c_int,
+    pub isMultiGpuBoard: c_int,
+    pub multiGpuBoardGroupID: c_int,
+    pub hostNativeAtomicSupported: c_int,
+    pub singleToDoublePrecisionPerfRatio: c_int,
+    pub pageableMemoryAccess: c_int,
+    pub concurrentManagedAccess: c_int,
+    pub computePreemptionSupported: c_int,
+    pub canUseHostPointerForRegisteredMem: c_int,
+    pub cooperativeLaunch: c_int,
+    pub cooperativeMultiDeviceLaunch: c_int,
+    pub sharedMemPerBlockOptin: usize,
+    pub pageableMemoryAccessUsesHostPageTables: c_int,
+    pub directManagedMemAccessFromHost: c_int,
+    pub maxBlocksPerMultiProcessor: c_int,
+    pub accessPolicyMaxWindowSize: c_int,
+    pub reservedSharedMemPerBlock: usize,
+    #[cfg(cuda_gte_12_0)]
+    pub hostRegisterSupported: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub sparseCudaArraySupported: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub hostRegisterReadOnlySupported: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub timelineSemaphoreInteropSupported: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub memoryPoolsSupported: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub gpuDirectRDMASupported: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub gpuDirectRDMAFlushWritesOptions: c_uint,
+    #[cfg(cuda_gte_12_0)]
+    pub gpuDirectRDMAWritesOrdering: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub memoryPoolSupportedHandleTypes: c_uint,
+    #[cfg(cuda_gte_12_0)]
+    pub deferredMappingCudaArraySupported: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub ipcEventSupported: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub clusterLaunch: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub unifiedFunctionPointers: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub reserved2: [c_int; 2],
+    #[cfg(cuda_gte_12_0)]
+    pub reserved1: [c_int; 1],
+    #[cfg(cuda_gte_12_0)]
+    pub reserved: [c_int; 60],
+  }
+
+  #[cfg(cuda_gte_12_0)]
+  #[derive(Clone, Copy, Debug)]
+  #[repr(C)]
+  pub struct cudaFuncAttributes {
+    pub sharedSizeBytes: usize,
+    pub constSizeBytes: usize,
+    pub localSizeBytes: usize,
+    pub maxThreadsPerBlock: c_int,
+    pub numRegs: c_int,
+    pub ptxVersion: c_int,
+    pub binaryVersion: c_int,
+    pub cacheModeCA: c_int,
+    pub maxDynamicSharedSizeBytes: c_int,
+    pub preferredShmemCarveout: c_int,
+    pub clusterDimMustBeSet: c_int,
+    pub requiredClusterWidth: c_int,
+    pub requiredClusterHeight: c_int,
+    pub requiredClusterDepth: c_int,
+    pub clusterSchedulingPolicyPreference: c_int,
+    pub nonPortableClusterSizeAllowed: c_int,
+    pub reserved: [c_int; 16],
+  }
+}
+
+#[cfg(cuda_gte_11_0)]
+pub use self::device::*;
+
+/// Texture and surface object types from `driver_types.h`,
+/// `texture_types.h` and `surface_types.h`, declared here with named unions.
+mod texture {
+  use cuda_api_types::driver_types::{cudaArray_t, cudaChannelFormatDesc};
+  use std::os::raw::{c_int, c_uint, c_ulonglong, c_void};
+
+  pub type cudaTextureObject_t = c_ulonglong;
+  pub type cudaSurfaceObject_t = c_ulonglong;
+
+  pub type cudaResourceType = c_uint;
+  pub const cudaResourceType_cudaResourceTypeArray: cudaResourceType = 0;
+  pub const cudaResourceType_cudaResourceTypeMipmappedArray: cudaResourceType = 1;
+  pub const cudaResourceType_cudaResourceTypeLinear: cudaResourceType = 2;
+  pub const cudaResourceType_cudaResourceTypePitch2D: cudaResourceType = 3;
+
+  pub type cudaTextureAddressMode = c_uint;
+  pub const cudaTextureAddressMode_cudaAddressModeWrap: cudaTextureAddressMode = 0;
+  pub const cudaTextureAddressMode_cudaAddressModeClamp: cudaTextureAddressMode = 1;
+  pub const cudaTextureAddressMode_cudaAddressModeMirror: cudaTextureAddressMode = 2;
+  pub const cudaTextureAddressMode_cudaAddressModeBorder: cudaTextureAddressMode = 3;
+
+  pub type cudaTextureFilterMode = c_uint;
+  pub const cudaTextureFilterMode_cudaFilterModePoint: cudaTextureFilterMode = 0;
+  pub const cudaTextureFilterMode_cudaFilterModeLinear: cudaTextureFilterMode = 1;
+
+  pub type cudaTextureReadMode = c_uint;
+  pub const cudaTextureReadMode_cudaReadModeElementType: cudaTextureReadMode = 0;
+  pub const cudaTextureReadMode_cudaReadModeNormalizedFloat: cudaTextureReadMode = 1;
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub struct cudaResourceDescArray {
+    pub array: cudaArray_t,
+  }
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub struct cudaResourceDescLinear {
+    pub devPtr: *mut c_void,
+    pub desc: cudaChannelFormatDesc,
+    pub sizeInBytes: usize,
+  }
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub struct cudaResourceDescPitch2D {
+    pub devPtr: *mut c_void,
+    pub desc: cudaChannelFormatDesc,
+    pub width: usize,
+    pub height: usize,
+    pub pitchInBytes: usize,
+  }
+
+  /// The mipmapped array member is left out; it does not affect the layout.
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub union cudaResourceDescRes {
+    pub array: cudaResourceDescArray,
+    pub linear: cudaResourceDescLinear,
+    pub pitch2D: cudaResourceDescPitch2D,
+  }
+
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  pub struct cudaResourceDesc {
+    pub resType: cudaResourceType,
+    pub res: cudaResourceDescRes,
+  }
+
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaResourceViewDesc {
+    pub format: c_uint,
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+    pub firstMipmapLevel: c_uint,
+    pub lastMipmapLevel: c_uint,
+    pub firstLayer: c_uint,
+    pub lastLayer: c_uint,
+  }
+
+  #[derive(Clone, Copy, Default, Debug)]
+  #[repr(C)]
+  pub struct cudaTextureDesc {
+    pub addressMode: [cudaTextureAddressMode; 3],
+    pub filterMode: cudaTextureFilterMode,
+    pub readMode: cudaTextureReadMode,
+    pub sRGB: c_int,
+    #[cfg(cuda_gte_8_0)]
+    pub borderColor: [f32; 4],
+    pub normalizedCoords: c_int,
+    pub maxAnisotropy: c_uint,
+    pub mipmapFilterMode: cudaTextureFilterMode,
+    pub mipmapLevelBias: f32,
+    pub minMipmapLevelClamp: f32,
+    pub maxMipmapLevelClamp: f32,
+    #[cfg(cuda_gte_11_0)]
+    pub disableTrilinearOptimization: c_int,
+    #[cfg(cuda_gte_12_0)]
+    pub seamlessCubemap: c_int,
+  }
+}
+
+pub use self::texture::*;
+
+/// With `fresh`, the types passed to the bound functions as generated from
+/// the toolkit headers, to check the declarations above and in
+/// `cuda_api_types` against.
+#[cfg(feature = "fresh")]
+#[allow(warnings)]
+mod header {
+  include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_types.rs"));
+}
+
+/// Fail the build if a declared type differs in size or alignment from its
+/// definition in the toolkit headers.
+#[cfg(feature = "fresh")]
+macro_rules! check_layout {
+  ($($decl:path => $ty:ident,)*) => {
+    $(
+      const _: () = assert!(
+        ::std::mem::size_of::<$decl>() == ::std::mem::size_of::<header::$ty>()
+          && ::std::mem::align_of::<$decl>() == ::std::mem::align_of::<header::$ty>(),
+        concat!("`", stringify!($ty), "` does not match its definition in the CUDA headers"));
+    )*
+  };
+}
+
+#[cfg(feature = "fresh")]
+check_layout! {
+  cuda_api_types::driver_types::cudaChannelFormatDesc => cudaChannelFormatDesc,
+  cuda_api_types::driver_types::cudaExtent => cudaExtent,
+  cuda_api_types::driver_types::cudaIpcEventHandle_t => cudaIpcEventHandle_t,
+  cuda_api_types::driver_types::cudaIpcMemHandle_t => cudaIpcMemHandle_t,
+  self::texture::cudaResourceDesc => cudaResourceDesc,
+  self::texture::cudaResourceViewDesc => cudaResourceViewDesc,
+  self::texture::cudaTextureDesc => cudaTextureDesc,
+}
+#[cfg(all(feature = "fresh", not(cuda_gte_11_0)))]
+check_layout! {
+  cuda_api_types::driver_types::cudaDeviceProp => cudaDeviceProp,
+}
+#[cfg(all(feature = "fresh", cuda_gte_11_0))]
+check_layout! {
+  self::device::cudaDeviceProp => cudaDeviceProp,
+}
+#[cfg(all(feature = "fresh", not(cuda_gte_12_0)))]
+check_layout! {
+  cuda_api_types::driver_types::cudaFuncAttributes => cudaFuncAttributes,
+}
+#[cfg(all(feature = "fresh", cuda_gte_12_0))]
+check_layout! {
+  self::device::cudaFuncAttributes => cudaFuncAttributes,
+}
+#[cfg(all(feature = "fresh", cuda_gte_7_0))]
+check_layout! {
+  self::dim3 => dim3,
+}
+#[cfg(all(feature = "fresh", cuda_gte_9_0, not(cuda_gte_12_0)))]
+check_layout! {
+  self::cudaLaunchParams => cudaLaunchParams,
+}
+#[cfg(all(feature = "fresh", cuda_gte_10_0))]
+check_layout! {
+  self::cudaKernelNodeParams => cudaKernelNodeParams,
+  cuda_api_types::driver_types::cudaHostNodeParams => cudaHostNodeParams,
+  cuda_api_types::driver_types::cudaMemcpy3DParms => cudaMemcpy3DParms,
+  cuda_api_types::driver_types::cudaMemsetParams => cudaMemsetParams,
+  self::external::cudaExternalMemoryBufferDesc => cudaExternalMemoryBufferDesc,
+  self::external::cudaExternalMemoryHandleDesc => cudaExternalMemoryHandleDesc,
+  self::external::cudaExternalSemaphoreHandleDesc => cudaExternalSemaphoreHandleDesc,
+  self::external::cudaExternalSemaphoreSignalParams => cudaExternalSemaphoreSignalParams,
+  self::external::cudaExternalSemaphoreWaitParams => cudaExternalSemaphoreWaitParams,
+}
+
+#[cfg(cuda_6_5)]
+mod v {
+  pub mod cuda_runtime_api {
+    use cuda_api_types::cuda_runtime_api::*;
+    use cuda_api_types::driver_types::*;
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(not(feature = "dynamic_loading"))]
+    include!("v6_5/_cuda_runtime_api.rs");
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
+  }
+}
+
+#[cfg(cuda_7_0)]
+mod v {
+  pub mod cuda_runtime_api {
+    use cuda_api_types::cuda_runtime_api::*;
+    use cuda_api_types::driver_types::*;
+    use crate::ffi::dim3;
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(not(feature = "dynamic_loading"))]
+    include!("v7_0/_cuda_runtime_api.rs");
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
+  }
+}
+
+#[cfg(cuda_7_5)]
+mod v {
+  pub mod cuda_runtime_api {
+    use cuda_api_types::cuda_runtime_api::*;
+    use cuda_api_types::driver_types::*;
+    use crate::ffi::dim3;
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(all(not(feature = "dynamic_loading"), not(cuda_generated_bindings)))]
+    include!("v7_5/_cuda_runtime_api.rs");
+    #[cfg(all(not(feature = "dynamic_loading"), cuda_generated_bindings))]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api.rs"));
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
+  }
+}
+
+#[cfg(cuda_8_0)]
 mod v {
   pub mod cuda_runtime_api {
     use cuda_api_types::cuda_runtime_api::*;
     use cuda_api_types::driver_types::*;
+    use crate::ffi::dim3;
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(not(feature = "dynamic_loading"))]
     include!("v8_0/_cuda_runtime_api.rs");
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
   }
 }
 
-#[cfg(feature = "cuda_9_0")]
+#[cfg(cuda_9_0)]
 mod v {
   pub mod cuda_runtime_api {
     use cuda_api_types::cuda_runtime_api::*;
     use cuda_api_types::driver_types::*;
+    use crate::ffi::{dim3, cudaLaunchParams};
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(not(feature = "dynamic_loading"))]
     include!("v9_0/_cuda_runtime_api.rs");
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
   }
 }
 
-#[cfg(feature = "cuda_9_2")]
+#[cfg(cuda_9_1)]
 mod v {
   pub mod cuda_runtime_api {
     use cuda_api_types::cuda_runtime_api::*;
     use cuda_api_types::driver_types::*;
+    use crate::ffi::{dim3, cudaLaunchParams};
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(all(not(feature = "dynamic_loading"), not(cuda_generated_bindings)))]
+    include!("v9_1/_cuda_runtime_api.rs");
+    #[cfg(all(not(feature = "dynamic_loading"), cuda_generated_bindings))]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api.rs"));
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
+  }
+}
+
+#[cfg(cuda_9_2)]
+mod v {
+  pub mod cuda_runtime_api {
+    use cuda_api_types::cuda_runtime_api::*;
+    use cuda_api_types::driver_types::*;
+    use crate::ffi::{dim3, cudaLaunchParams};
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(not(feature = "dynamic_loading"))]
     include!("v9_2/_cuda_runtime_api.rs");
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
   }
 }
 
-#[cfg(feature = "cuda_10_0")]
+#[cfg(cuda_10_0)]
 mod v {
   pub mod cuda_runtime_api {
     use cuda_api_types::cuda_runtime_api::*;
     use cuda_api_types::driver_types::*;
+    use crate::ffi::{dim3, cudaKernelNodeParams, cudaLaunchParams};
+    use crate::ffi::external::{
+      cudaExternalMemory_t,
+      cudaExternalMemoryBufferDesc,
+      cudaExternalMemoryHandleDesc,
+      cudaExternalSemaphore_t,
+      cudaExternalSemaphoreHandleDesc,
+      cudaExternalSemaphoreSignalParams,
+      cudaExternalSemaphoreWaitParams,
+    };
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(not(feature = "dynamic_loading"))]
     include!("v10_0/_cuda_runtime_api.rs");
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
+  }
+}
+
+// No bindings are checked in for the versions below yet: their features
+// enable `fresh`, and build.rs generates the bindings into `$OUT_DIR`.
+
+#[cfg(all(cuda_gte_10_1, not(cuda_gte_11_0)))]
+mod v {
+  pub mod cuda_runtime_api {
+    use cuda_api_types::cuda_runtime_api::*;
+    use cuda_api_types::driver_types::*;
+    use crate::ffi::{dim3, cudaKernelNodeParams, cudaLaunchParams, cudaStreamCaptureMode};
+    use crate::ffi::external::{
+      cudaExternalMemory_t,
+      cudaExternalMemoryBufferDesc,
+      cudaExternalMemoryHandleDesc,
+      cudaExternalSemaphore_t,
+      cudaExternalSemaphoreHandleDesc,
+      cudaExternalSemaphoreSignalParams,
+      cudaExternalSemaphoreWaitParams,
+    };
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(all(not(feature = "dynamic_loading"), cuda_generated_bindings))]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api.rs"));
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
+  }
+}
+
+#[cfg(all(cuda_gte_11_0, not(cuda_gte_12_0)))]
+mod v {
+  pub mod cuda_runtime_api {
+    use cuda_api_types::cuda_runtime_api::*;
+    use cuda_api_types::driver_types::*;
+    use crate::ffi::{dim3, cudaKernelNodeParams, cudaLaunchParams, cudaStreamCaptureMode};
+    use crate::ffi::device::{cudaDeviceProp};
+    use crate::ffi::external::{
+      cudaExternalMemory_t,
+      cudaExternalMemoryBufferDesc,
+      cudaExternalMemoryHandleDesc,
+      cudaExternalSemaphore_t,
+      cudaExternalSemaphoreHandleDesc,
+      cudaExternalSemaphoreSignalParams,
+      cudaExternalSemaphoreWaitParams,
+    };
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(all(not(feature = "dynamic_loading"), cuda_generated_bindings))]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api.rs"));
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
+  }
+}
+
+#[cfg(cuda_gte_12_0)]
+mod v {
+  pub mod cuda_runtime_api {
+    use cuda_api_types::cuda_runtime_api::*;
+    use cuda_api_types::driver_types::*;
+    use crate::ffi::{dim3, cudaKernelNodeParams, cudaStreamCaptureMode};
+    use crate::ffi::device::{cudaDeviceProp, cudaFuncAttributes};
+    use crate::ffi::external::{
+      cudaExternalMemory_t,
+      cudaExternalMemoryBufferDesc,
+      cudaExternalMemoryHandleDesc,
+      cudaExternalSemaphore_t,
+      cudaExternalSemaphoreHandleDesc,
+      cudaExternalSemaphoreSignalParams,
+      cudaExternalSemaphoreWaitParams,
+    };
+    use crate::ffi::texture::{
+      cudaResourceDesc,
+      cudaResourceViewDesc,
+      cudaSurfaceObject_t,
+      cudaTextureDesc,
+      cudaTextureObject_t,
+    };
+    #[cfg(all(not(feature = "dynamic_loading"), cuda_generated_bindings))]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api.rs"));
+    #[cfg(feature = "dynamic_loading")]
+    include!(concat!(env!("OUT_DIR"), "/_cuda_runtime_api_dynamic.rs"));
   }
 }
@@ -41,6 +41,318 @@ extern "C" {
     #[doc = " ::cuCtxSynchronize"]
     pub fn cudaDeviceSynchronize() -> cudaError_t;
 }
+extern "C" {
+    #[doc = " \\brief Returns the preferred cache configuration for the current device."]
+    #[doc = ""]
+    #[doc = " On devices where the L1 cache and shared memory use the same hardware"]
+    #[doc = " resources, this returns through \\p pCacheConfig the preferred cache"]
+    #[doc = " configuration for the current device. This is only a preference. The"]
+    #[doc = " runtime will use the requested configuration if possible, but it is free to"]
+    #[doc = " choose a different configuration if required to execute functions."]
+    #[doc = ""]
+    #[doc = " \\param pCacheConfig - Returned cache configuration"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDeviceSetCacheConfig,"]
+    #[doc = " ::cuCtxGetCacheConfig"]
+    pub fn cudaDeviceGetCacheConfig(pCacheConfig: *mut cudaFuncCache) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Sets the preferred cache configuration for the current device."]
+    #[doc = ""]
+    #[doc = " On devices where the L1 cache and shared memory use the same hardware"]
+    #[doc = " resources, this sets through \\p cacheConfig the preferred cache configuration"]
+    #[doc = " for the current device. This is only a preference. The runtime will use"]
+    #[doc = " the requested configuration if possible, but it is free to choose a different"]
+    #[doc = " configuration if required to execute the function."]
+    #[doc = ""]
+    #[doc = " \\param cacheConfig - Requested cache configuration"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDeviceGetCacheConfig,"]
+    #[doc = " ::cuCtxSetCacheConfig"]
+    pub fn cudaDeviceSetCacheConfig(cacheConfig: cudaFuncCache) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Returns the shared memory configuration for the current device."]
+    #[doc = ""]
+    #[doc = " This function will return in \\p pConfig the current size of shared memory banks"]
+    #[doc = " on the current device."]
+    #[doc = ""]
+    #[doc = " \\param pConfig - Returned cache configuration"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDeviceSetSharedMemConfig,"]
+    #[doc = " ::cuCtxGetSharedMemConfig"]
+    pub fn cudaDeviceGetSharedMemConfig(pConfig: *mut cudaSharedMemConfig) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Sets the shared memory configuration for the current device."]
+    #[doc = ""]
+    #[doc = " On devices with configurable shared memory banks, this function will set"]
+    #[doc = " the shared memory bank size which is used for all subsequent kernel launches."]
+    #[doc = " Any per-function setting of shared memory set via ::cudaFuncSetSharedMemConfig"]
+    #[doc = " will override the device wide setting."]
+    #[doc = ""]
+    #[doc = " \\param config - Requested cache configuration"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDeviceGetSharedMemConfig,"]
+    #[doc = " ::cuCtxSetSharedMemConfig"]
+    pub fn cudaDeviceSetSharedMemConfig(config: cudaSharedMemConfig) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Gets an interprocess handle for a previously allocated event"]
+    #[doc = ""]
+    #[doc = " Takes as input a previously allocated event. This event must have been"]
+    #[doc = " created with the ::cudaEventInterprocess and ::cudaEventDisableTiming"]
+    #[doc = " flags set. This opaque handle may be copied into other processes and"]
+    #[doc = " opened with ::cudaIpcOpenEventHandle to allow efficient hardware"]
+    #[doc = " synchronization between GPU work in different processes."]
+    #[doc = ""]
+    #[doc = " After the event has been been opened in the importing process,"]
+    #[doc = " ::cudaEventRecord, ::cudaEventSynchronize, ::cudaStreamWaitEvent and"]
+    #[doc = " ::cudaEventQuery may be used in either process. Performing operations"]
+    #[doc = " on the imported event after the exported event has been freed"]
+    #[doc = " with ::cudaEventDestroy will result in undefined behavior."]
+    #[doc = ""]
+    #[doc = " IPC functionality is restricted to devices with support for unified"]
+    #[doc = " addressing on Linux operating systems."]
+    #[doc = ""]
+    #[doc = " \\param handle - Pointer to a user allocated cudaIpcEventHandle"]
+    #[doc = "                    in which to return the opaque event handle"]
+    #[doc = " \\param event   - Event allocated with ::cudaEventInterprocess and"]
+    #[doc = "                    ::cudaEventDisableTiming flags."]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorMemoryAllocation,"]
+    #[doc = " ::cudaErrorMapBufferObjectFailed,"]
+    #[doc = " ::cudaErrorNotSupported"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaEventCreate,"]
+    #[doc = " ::cudaEventDestroy,"]
+    #[doc = " ::cudaEventSynchronize,"]
+    #[doc = " ::cudaEventQuery,"]
+    #[doc = " ::cudaStreamWaitEvent,"]
+    #[doc = " ::cudaIpcOpenEventHandle,"]
+    #[doc = " ::cudaIpcGetMemHandle,"]
+    #[doc = " ::cudaIpcOpenMemHandle,"]
+    #[doc = " ::cudaIpcCloseMemHandle,"]
+    #[doc = " ::cuIpcGetEventHandle"]
+    pub fn cudaIpcGetEventHandle(
+        handle: *mut cudaIpcEventHandle_t,
+        event: cudaEvent_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Opens an interprocess event handle for use in the current process"]
+    #[doc = ""]
+    #[doc = " Opens an interprocess event handle exported from another process with"]
+    #[doc = " ::cudaIpcGetEventHandle. This function returns a ::cudaEvent_t that behaves like"]
+    #[doc = " a locally created event with the ::cudaEventDisableTiming flag specified."]
+    #[doc = " This event must be freed with ::cudaEventDestroy."]
+    #[doc = ""]
+    #[doc = " Performing operations on the imported event after the exported event has"]
+    #[doc = " been freed with ::cudaEventDestroy will result in undefined behavior."]
+    #[doc = ""]
+    #[doc = " IPC functionality is restricted to devices with support for unified"]
+    #[doc = " addressing on Linux operating systems."]
+    #[doc = ""]
+    #[doc = " \\param event - Returns the imported event"]
+    #[doc = " \\param handle  - Interprocess handle to open"]
+    #[doc = ""]
+    #[doc = " \\returns"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorMapBufferObjectFailed,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorNotSupported"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaEventCreate,"]
+    #[doc = " ::cudaEventDestroy,"]
+    #[doc = " ::cudaEventSynchronize,"]
+    #[doc = " ::cudaEventQuery,"]
+    #[doc = " ::cudaStreamWaitEvent,"]
+    #[doc = " ::cudaIpcGetEventHandle,"]
+    #[doc = " ::cudaIpcGetMemHandle,"]
+    #[doc = " ::cudaIpcOpenMemHandle,"]
+    #[doc = " ::cudaIpcCloseMemHandle,"]
+    #[doc = " ::cuIpcOpenEventHandle"]
+    pub fn cudaIpcOpenEventHandle(
+        event: *mut cudaEvent_t,
+        handle: cudaIpcEventHandle_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Gets an interprocess memory handle for an existing device memory"]
+    #[doc = "          allocation"]
+    #[doc = ""]
+    #[doc = " Takes a pointer to the base of an existing device memory allocation created"]
+    #[doc = " with ::cudaMalloc and exports it for use in another process. This is a"]
+    #[doc = " lightweight operation and may be called multiple times on an allocation"]
+    #[doc = " without adverse effects."]
+    #[doc = ""]
+    #[doc = " If a region of memory is freed with ::cudaFree and a subsequent call"]
+    #[doc = " to ::cudaMalloc returns memory with the same device address,"]
+    #[doc = " ::cudaIpcGetMemHandle will return a unique handle for the"]
+    #[doc = " new memory."]
+    #[doc = ""]
+    #[doc = " IPC functionality is restricted to devices with support for unified"]
+    #[doc = " addressing on Linux operating systems."]
+    #[doc = ""]
+    #[doc = " \\param handle - Pointer to user allocated ::cudaIpcMemHandle to return"]
+    #[doc = "                    the handle in."]
+    #[doc = " \\param devPtr - Base pointer to previously allocated device memory"]
+    #[doc = ""]
+    #[doc = " \\returns"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorMemoryAllocation,"]
+    #[doc = " ::cudaErrorMapBufferObjectFailed,"]
+    #[doc = " ::cudaErrorNotSupported"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaMalloc,"]
+    #[doc = " ::cudaFree,"]
+    #[doc = " ::cudaIpcGetEventHandle,"]
+    #[doc = " ::cudaIpcOpenEventHandle,"]
+    #[doc = " ::cudaIpcOpenMemHandle,"]
+    #[doc = " ::cudaIpcCloseMemHandle,"]
+    #[doc = " ::cuIpcGetMemHandle"]
+    pub fn cudaIpcGetMemHandle(
+        handle: *mut cudaIpcMemHandle_t,
+        devPtr: *mut ::std::os::raw::c_void,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Opens an interprocess memory handle exported from another process"]
+    #[doc = "          and returns a device pointer usable in the local process."]
+    #[doc = ""]
+    #[doc = " Maps memory exported from another process with ::cudaIpcGetMemHandle into"]
+    #[doc = " the current device address space. For contexts on different devices"]
+    #[doc = " ::cudaIpcOpenMemHandle can attempt to enable peer access between the"]
+    #[doc = " devices as if the user called ::cudaDeviceEnablePeerAccess. This behavior is"]
+    #[doc = " controlled by the ::cudaIpcMemLazyEnablePeerAccess flag."]
+    #[doc = " ::cudaDeviceCanAccessPeer can determine if a mapping is possible."]
+    #[doc = ""]
+    #[doc = " ::cudaIpcOpenMemHandle can open handles to devices that may not be visible"]
+    #[doc = " in the process calling the API."]
+    #[doc = ""]
+    #[doc = " Contexts that may open ::cudaIpcMemHandles are restricted in the following way."]
+    #[doc = " ::cudaIpcMemHandles from each device in a given process may only be opened"]
+    #[doc = " by one context per device per other process."]
+    #[doc = ""]
+    #[doc = " Memory returned from ::cudaIpcOpenMemHandle must be freed with"]
+    #[doc = " ::cudaIpcCloseMemHandle."]
+    #[doc = ""]
+    #[doc = " Calling ::cudaFree on an exported memory region before calling"]
+    #[doc = " ::cudaIpcCloseMemHandle in the importing context will result in undefined"]
+    #[doc = " behavior."]
+    #[doc = ""]
+    #[doc = " IPC functionality is restricted to devices with support for unified"]
+    #[doc = " addressing on Linux operating systems."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Returned device pointer"]
+    #[doc = " \\param handle - ::cudaIpcMemHandle to open"]
+    #[doc = " \\param flags  - Flags for this operation. Must be specified as ::cudaIpcMemLazyEnablePeerAccess"]
+    #[doc = ""]
+    #[doc = " \\returns"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorMapBufferObjectFailed,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorTooManyPeers,"]
+    #[doc = " ::cudaErrorNotSupported"]
+    #[doc = ""]
+    #[doc = " \\note No guarantees are made about the address returned in \\p *devPtr."]
+    #[doc = " In particular, multiple processes may not receive the same address for the same \\p handle."]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaMalloc,"]
+    #[doc = " ::cudaFree,"]
+    #[doc = " ::cudaIpcGetEventHandle,"]
+    #[doc = " ::cudaIpcOpenEventHandle,"]
+    #[doc = " ::cudaIpcGetMemHandle,"]
+    #[doc = " ::cudaIpcCloseMemHandle,"]
+    #[doc = " ::cudaDeviceEnablePeerAccess,"]
+    #[doc = " ::cudaDeviceCanAccessPeer,"]
+    #[doc = " ::cuIpcOpenMemHandle"]
+    pub fn cudaIpcOpenMemHandle(
+        devPtr: *mut *mut ::std::os::raw::c_void,
+        handle: cudaIpcMemHandle_t,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Close memory mapped with cudaIpcOpenMemHandle"]
+    #[doc = ""]
+    #[doc = " Unmaps memory returnd by ::cudaIpcOpenMemHandle. The original allocation"]
+    #[doc = " in the exporting process as well as imported mappings in other processes"]
+    #[doc = " will be unaffected."]
+    #[doc = ""]
+    #[doc = " Any resources used to enable peer access will be freed if this is the"]
+    #[doc = " last mapping using them."]
+    #[doc = ""]
+    #[doc = " IPC functionality is restricted to devices with support for unified"]
+    #[doc = " addressing on Linux operating systems."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Device pointer returned by ::cudaIpcOpenMemHandle"]
+    #[doc = ""]
+    #[doc = " \\returns"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorMapBufferObjectFailed,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorNotSupported"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaMalloc,"]
+    #[doc = " ::cudaFree,"]
+    #[doc = " ::cudaIpcGetEventHandle,"]
+    #[doc = " ::cudaIpcOpenEventHandle,"]
+    #[doc = " ::cudaIpcGetMemHandle,"]
+    #[doc = " ::cudaIpcOpenMemHandle,"]
+    #[doc = " ::cuIpcCloseMemHandle"]
+    pub fn cudaIpcCloseMemHandle(devPtr: *mut ::std::os::raw::c_void) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Returns the string representation of an error code enum name"]
+    #[doc = ""]
+    #[doc = " Returns a string containing the name of an error code in the enum.  If the error"]
+    #[doc = " code is not recognized, \"unrecognized error code\" is returned."]
+    #[doc = ""]
+    #[doc = " \\param error - Error code to convert to string"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " \\p char* pointer to a NULL-terminated string"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaGetErrorString, ::cudaGetLastError, ::cudaPeekAtLastError, ::cudaError,"]
+    #[doc = " ::cuGetErrorName"]
+    pub fn cudaGetErrorName(error: cudaError_t) -> *const ::std::os::raw::c_char;
+}
 extern "C" {
     #[doc = " \\brief Returns the description string for an error code"]
     #[doc = ""]
@@ -1077,6 +1389,96 @@ extern "C" {
         flags: ::std::os::raw::c_uint,
     ) -> cudaError_t;
 }
+extern "C" {
+    #[doc = " \\brief Begins graph capture on a stream"]
+    #[doc = ""]
+    #[doc = " Begin graph capture on \\p stream. When a stream is in capture mode, all operations"]
+    #[doc = " pushed into the stream will not be executed, but will instead be captured into"]
+    #[doc = " a graph, which will be returned via ::cudaStreamEndCapture. Capture may not be initiated"]
+    #[doc = " if \\p stream is ::cudaStreamLegacy. Capture must be ended on the same stream in which"]
+    #[doc = " it was initiated, and it may only be initiated if the stream is not already in capture"]
+    #[doc = " mode. The capture mode may be queried via ::cudaStreamIsCapturing."]
+    #[doc = ""]
+    #[doc = " \\note Kernels captured using this API must not use texture and surface references."]
+    #[doc = " Reading or writing through any texture or surface reference is undefined"]
+    #[doc = " behavior. This restriction does not apply to texture and surface objects."]
+    #[doc = ""]
+    #[doc = " \\param stream - Stream in which to initiate capture"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaStreamCreate,"]
+    #[doc = " ::cudaStreamIsCapturing,"]
+    #[doc = " ::cudaStreamEndCapture"]
+    pub fn cudaStreamBeginCapture(stream: cudaStream_t) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Ends capture on a stream, returning the captured graph"]
+    #[doc = ""]
+    #[doc = " End capture on \\p stream, returning the captured graph via \\p pGraph."]
+    #[doc = " Capture must have been initiated on \\p stream via a call to ::cudaStreamBeginCapture."]
+    #[doc = " If capture was invalidated, due to a violation of the rules of stream capture, then"]
+    #[doc = " a NULL graph will be returned."]
+    #[doc = ""]
+    #[doc = " \\param stream - Stream to query"]
+    #[doc = " \\param pGraph - The captured graph"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorStreamCaptureWrongThread"]
+    #[doc = " \\notefnerr"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaStreamCreate,"]
+    #[doc = " ::cudaStreamBeginCapture,"]
+    #[doc = " ::cudaStreamIsCapturing"]
+    pub fn cudaStreamEndCapture(stream: cudaStream_t, pGraph: *mut cudaGraph_t) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Returns a stream\'s capture status"]
+    #[doc = ""]
+    #[doc = " Return the capture status of \\p stream via \\p pCaptureStatus. After a successful"]
+    #[doc = " call, \\p *pCaptureStatus will contain one of the following:"]
+    #[doc = " - ::cudaStreamCaptureStatusNone: The stream is not capturing."]
+    #[doc = " - ::cudaStreamCaptureStatusActive: The stream is capturing."]
+    #[doc = " - ::cudaStreamCaptureStatusInvalidated: The stream was capturing but an error"]
+    #[doc = "   has invalidated the capture sequence. The capture sequence must be terminated"]
+    #[doc = "   with ::cudaStreamEndCapture on the stream where it was initiated in order to"]
+    #[doc = "   continue using \\p stream."]
+    #[doc = ""]
+    #[doc = " Note that, if this is called on ::cudaStreamLegacy (the \"null stream\") while"]
+    #[doc = " a blocking stream on the same device is capturing, it will return"]
+    #[doc = " ::cudaErrorStreamCaptureImplicit and \\p *pCaptureStatus is unspecified"]
+    #[doc = " after the call. The blocking stream capture is not invalidated."]
+    #[doc = ""]
+    #[doc = " When a blocking stream is capturing, the legacy stream is in an"]
+    #[doc = " unusable state until the blocking stream capture is terminated. The legacy"]
+    #[doc = " stream is not supported for stream capture, but attempted use would have an"]
+    #[doc = " implicit dependency on the capturing stream(s)."]
+    #[doc = ""]
+    #[doc = " \\param stream         - Stream to query"]
+    #[doc = " \\param pCaptureStatus - Returns the stream\'s capture status"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorStreamCaptureImplicit"]
+    #[doc = " \\notefnerr"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaStreamCreate,"]
+    #[doc = " ::cudaStreamBeginCapture,"]
+    #[doc = " ::cudaStreamEndCapture"]
+    pub fn cudaStreamIsCapturing(
+        stream: cudaStream_t,
+        pCaptureStatus: *mut cudaStreamCaptureStatus,
+    ) -> cudaError_t;
+}
 extern "C" {
     #[doc = " \\brief Creates an event object"]
     #[doc = ""]
@@ -1305,695 +1707,2247 @@ extern "C" {
     pub fn cudaEventElapsedTime(ms: *mut f32, start: cudaEvent_t, end: cudaEvent_t) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Allocates memory that will be automatically managed by the Unified Memory system"]
-    #[doc = ""]
-    #[doc = " Allocates \\p size bytes of managed memory on the device and returns in"]
-    #[doc = " \\p *devPtr a pointer to the allocated memory. If the device doesn\'t support"]
-    #[doc = " allocating managed memory, ::cudaErrorNotSupported is returned. Support"]
-    #[doc = " for managed memory can be queried using the device attribute"]
-    #[doc = " ::cudaDevAttrManagedMemory. The allocated memory is suitably"]
-    #[doc = " aligned for any kind of variable. The memory is not cleared. If \\p size"]
-    #[doc = " is 0, ::cudaMallocManaged returns ::cudaErrorInvalidValue. The pointer"]
-    #[doc = " is valid on the CPU and on all GPUs in the system that support managed memory."]
-    #[doc = " All accesses to this pointer must obey the Unified Memory programming model."]
-    #[doc = ""]
-    #[doc = " \\p flags specifies the default stream association for this allocation."]
-    #[doc = " \\p flags must be one of ::cudaMemAttachGlobal or ::cudaMemAttachHost. The"]
-    #[doc = " default value for \\p flags is ::cudaMemAttachGlobal."]
-    #[doc = " If ::cudaMemAttachGlobal is specified, then this memory is accessible from"]
-    #[doc = " any stream on any device. If ::cudaMemAttachHost is specified, then the"]
-    #[doc = " allocation should not be accessed from devices that have a zero value for the"]
-    #[doc = " device attribute ::cudaDevAttrConcurrentManagedAccess; an explicit call to"]
-    #[doc = " ::cudaStreamAttachMemAsync will be required to enable access on such devices."]
-    #[doc = ""]
-    #[doc = " If the association is later changed via ::cudaStreamAttachMemAsync to"]
-    #[doc = " a single stream, the default association, as specifed during ::cudaMallocManaged,"]
-    #[doc = " is restored when that stream is destroyed. For __managed__ variables, the"]
-    #[doc = " default association is always ::cudaMemAttachGlobal. Note that destroying a"]
-    #[doc = " stream is an asynchronous operation, and as a result, the change to default"]
-    #[doc = " association won\'t happen until all work in the stream has completed."]
+    #[doc = " \\brief Imports an external memory object"]
     #[doc = ""]
-    #[doc = " Memory allocated with ::cudaMallocManaged should be released with ::cudaFree."]
+    #[doc = " Imports an externally allocated memory object and returns"]
+    #[doc = " a handle to that in \\p extMem_out."]
     #[doc = ""]
-    #[doc = " Device memory oversubscription is possible for GPUs that have a non-zero value for the"]
-    #[doc = " device attribute ::cudaDevAttrConcurrentManagedAccess. Managed memory on"]
-    #[doc = " such GPUs may be evicted from device memory to host memory at any time by the Unified"]
-    #[doc = " Memory driver in order to make room for other allocations."]
+    #[doc = " The properties of the handle being imported must be described in"]
+    #[doc = " \\p memHandleDesc."]
     #[doc = ""]
-    #[doc = " In a multi-GPU system where all GPUs have a non-zero value for the device attribute"]
-    #[doc = " ::cudaDevAttrConcurrentManagedAccess, managed memory may not be populated when this"]
-    #[doc = " API returns and instead may be populated on access. In such systems, managed memory can"]
-    #[doc = " migrate to any processor\'s memory at any time. The Unified Memory driver will employ heuristics to"]
-    #[doc = " maintain data locality and prevent excessive page faults to the extent possible. The application"]
-    #[doc = " can also guide the driver about memory usage patterns via ::cudaMemAdvise. The application"]
-    #[doc = " can also explicitly migrate memory to a desired processor\'s memory via"]
-    #[doc = " ::cudaMemPrefetchAsync."]
+    #[doc = " If ::cudaExternalMemoryHandleDesc::type is"]
+    #[doc = " ::cudaExternalMemoryHandleTypeOpaqueFd, then"]
+    #[doc = " ::cudaExternalMemoryHandleDesc::handle::fd must be a valid"]
+    #[doc = " file descriptor referencing a memory object. Ownership of"]
+    #[doc = " the file descriptor is transferred to the CUDA driver when the"]
+    #[doc = " handle is imported successfully. Performing any operations on the"]
+    #[doc = " file descriptor after it is imported results in undefined behavior."]
     #[doc = ""]
-    #[doc = " In a multi-GPU system where all of the GPUs have a zero value for the device attribute"]
-    #[doc = " ::cudaDevAttrConcurrentManagedAccess and all the GPUs have peer-to-peer support"]
-    #[doc = " with each other, the physical storage for managed memory is created on the GPU which is active"]
-    #[doc = " at the time ::cudaMallocManaged is called. All other GPUs will reference the data at reduced"]
-    #[doc = " bandwidth via peer mappings over the PCIe bus. The Unified Memory driver does not migrate"]
-    #[doc = " memory among such GPUs."]
+    #[doc = " The size of the memory object must be specified in"]
+    #[doc = " ::cudaExternalMemoryHandleDesc::size."]
     #[doc = ""]
-    #[doc = " In a multi-GPU system where not all GPUs have peer-to-peer support with each other and"]
-    #[doc = " where the value of the device attribute ::cudaDevAttrConcurrentManagedAccess"]
-    #[doc = " is zero for at least one of those GPUs, the location chosen for physical storage of managed"]
-    #[doc = " memory is system-dependent."]
-    #[doc = " - On Linux, the location chosen will be device memory as long as the current set of active"]
-    #[doc = " contexts are on devices that either have peer-to-peer support with each other or have a"]
-    #[doc = " non-zero value for the device attribute ::cudaDevAttrConcurrentManagedAccess."]
-    #[doc = " If there is an active context on a GPU that does not have a non-zero value for that device"]
-    #[doc = " attribute and it does not have peer-to-peer support with the other devices that have active"]
-    #[doc = " contexts on them, then the location for physical storage will be \'zero-copy\' or host memory."]
-    #[doc = " Note that this means that managed memory that is located in device memory is migrated to"]
-    #[doc = " host memory if a new context is created on a GPU that doesn\'t have a non-zero value for"]
-    #[doc = " the device attribute and does not support peer-to-peer with at least one of the other devices"]
-    #[doc = " that has an active context. This in turn implies that context creation may fail if there is"]
-    #[doc = " insufficient host memory to migrate all managed allocations."]
-    #[doc = " - On Windows, the physical storage is always created in \'zero-copy\' or host memory."]
-    #[doc = " All GPUs will reference the data at reduced bandwidth over the PCIe bus. In these"]
-    #[doc = " circumstances, use of the environment variable CUDA_VISIBLE_DEVICES is recommended to"]
-    #[doc = " restrict CUDA to only use those GPUs that have peer-to-peer support."]
-    #[doc = " Alternatively, users can also set CUDA_MANAGED_FORCE_DEVICE_ALLOC to a non-zero"]
-    #[doc = " value to force the driver to always use device memory for physical storage."]
-    #[doc = " When this environment variable is set to a non-zero value, all devices used in"]
-    #[doc = " that process that support managed memory have to be peer-to-peer compatible"]
-    #[doc = " with each other. The error ::cudaErrorInvalidDevice will be returned if a device"]
-    #[doc = " that supports managed memory is used and it is not peer-to-peer compatible with"]
-    #[doc = " any of the other managed memory supporting devices that were previously used in"]
-    #[doc = " that process, even if ::cudaDeviceReset has been called on those devices. These"]
-    #[doc = " environment variables are described in the CUDA programming guide under the"]
-    #[doc = " \"CUDA environment variables\" section."]
+    #[doc = " Specifying the flag ::cudaExternalMemoryDedicated in"]
+    #[doc = " ::cudaExternalMemoryHandleDesc::flags indicates that the"]
+    #[doc = " resource is a dedicated resource."]
     #[doc = ""]
-    #[doc = " \\param devPtr - Pointer to allocated device memory"]
-    #[doc = " \\param size   - Requested allocation size in bytes"]
-    #[doc = " \\param flags  - Must be either ::cudaMemAttachGlobal or ::cudaMemAttachHost (defaults to ::cudaMemAttachGlobal)"]
+    #[doc = " \\param extMem_out    - Returned handle to an external memory object"]
+    #[doc = " \\param memHandleDesc - Memory import handle descriptor"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorMemoryAllocation,"]
-    #[doc = " ::cudaErrorNotSupported,"]
-    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " ::cudaErrorInvalidResourceHandle"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMallocPitch, ::cudaFree, ::cudaMallocArray, ::cudaFreeArray,"]
-    #[doc = " ::cudaMalloc3D, ::cudaMalloc3DArray,"]
-    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
-    #[doc = " ::cudaFreeHost, ::cudaHostAlloc, ::cudaDeviceGetAttribute, ::cudaStreamAttachMemAsync,"]
-    #[doc = " ::cuMemAllocManaged"]
-    pub fn cudaMallocManaged(
-        devPtr: *mut *mut ::std::os::raw::c_void,
-        size: usize,
-        flags: ::std::os::raw::c_uint,
+    #[doc = " \\note If the Vulkan memory imported into CUDA is mapped on the CPU then the"]
+    #[doc = " application must use vkInvalidateMappedMemoryRanges/vkFlushMappedMemoryRanges"]
+    #[doc = " as well as appropriate Vulkan pipeline barriers to maintain coherence between"]
+    #[doc = " CPU and GPU. For more information on these APIs, please refer to \"Synchronization"]
+    #[doc = " and Cache Control\" chapter from Vulkan specification."]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDestroyExternalMemory,"]
+    #[doc = " ::cudaExternalMemoryGetMappedBuffer,"]
+    #[doc = " ::cudaExternalMemoryGetMappedMipmappedArray"]
+    pub fn cudaImportExternalMemory(
+        extMem_out: *mut cudaExternalMemory_t,
+        memHandleDesc: *const cudaExternalMemoryHandleDesc,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Allocate memory on the device"]
+    #[doc = " \\brief Maps a buffer onto an imported memory object"]
     #[doc = ""]
-    #[doc = " Allocates \\p size bytes of linear memory on the device and returns in"]
-    #[doc = " \\p *devPtr a pointer to the allocated memory. The allocated memory is"]
-    #[doc = " suitably aligned for any kind of variable. The memory is not cleared."]
-    #[doc = " ::cudaMalloc() returns ::cudaErrorMemoryAllocation in case of failure."]
+    #[doc = " Maps a buffer onto an imported memory object and returns a device"]
+    #[doc = " pointer in \\p devPtr."]
     #[doc = ""]
-    #[doc = " The device version of ::cudaFree cannot be used with a \\p *devPtr"]
-    #[doc = " allocated using the host API, and vice versa."]
+    #[doc = " The properties of the buffer being mapped must be described in"]
+    #[doc = " \\p bufferDesc. The offset and size have to be suitably aligned to match the"]
+    #[doc = " requirements of the external API. Mapping two buffers whose ranges overlap may"]
+    #[doc = " or may not result in the same virtual address being returned for the"]
+    #[doc = " overlapped portion. In such cases, the application must ensure that all"]
+    #[doc = " accesses to that region from the GPU are volatile. Otherwise writes made via"]
+    #[doc = " one address are not guaranteed to be visible via the other address, even if"]
+    #[doc = " they\'re issued by the same thread. It is recommended that applications map"]
+    #[doc = " the combined range instead of mapping separate buffers and then apply the"]
+    #[doc = " appropriate offsets to the returned pointer to derive the individual buffers."]
     #[doc = ""]
-    #[doc = " \\param devPtr - Pointer to allocated device memory"]
-    #[doc = " \\param size   - Requested allocation size in bytes"]
+    #[doc = " The returned pointer \\p devPtr must be freed using ::cudaFree."]
+    #[doc = ""]
+    #[doc = " \\param devPtr     - Returned device pointer to buffer"]
+    #[doc = " \\param extMem     - Handle to external memory object"]
+    #[doc = " \\param bufferDesc - Buffer descriptor"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " ::cudaErrorInvalidResourceHandle"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMallocPitch, ::cudaFree, ::cudaMallocArray, ::cudaFreeArray,"]
-    #[doc = " ::cudaMalloc3D, ::cudaMalloc3DArray,"]
-    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
-    #[doc = " ::cudaFreeHost, ::cudaHostAlloc,"]
-    #[doc = " ::cuMemAlloc"]
-    pub fn cudaMalloc(devPtr: *mut *mut ::std::os::raw::c_void, size: usize) -> cudaError_t;
+    #[doc = " \\sa ::cudaImportExternalMemory"]
+    #[doc = " ::cudaDestroyExternalMemory,"]
+    #[doc = " ::cudaExternalMemoryGetMappedMipmappedArray"]
+    pub fn cudaExternalMemoryGetMappedBuffer(
+        devPtr: *mut *mut ::std::os::raw::c_void,
+        extMem: cudaExternalMemory_t,
+        bufferDesc: *const cudaExternalMemoryBufferDesc,
+    ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Allocates page-locked memory on the host"]
+    #[doc = " \\brief Destroys an external memory object."]
     #[doc = ""]
-    #[doc = " Allocates \\p size bytes of host memory that is page-locked and accessible"]
-    #[doc = " to the device. The driver tracks the virtual memory ranges allocated with"]
-    #[doc = " this function and automatically accelerates calls to functions such as"]
-    #[doc = " ::cudaMemcpy*(). Since the memory can be accessed directly by the device,"]
-    #[doc = " it can be read or written with much higher bandwidth than pageable memory"]
-    #[doc = " obtained with functions such as ::malloc(). Allocating excessive amounts of"]
-    #[doc = " memory with ::cudaMallocHost() may degrade system performance, since it"]
-    #[doc = " reduces the amount of memory available to the system for paging. As a"]
-    #[doc = " result, this function is best used sparingly to allocate staging areas for"]
-    #[doc = " data exchange between host and device."]
+    #[doc = " Destroys the specified external memory object. Any existing buffers"]
+    #[doc = " and CUDA mipmapped arrays mapped onto this object must no longer be"]
+    #[doc = " used and must be explicitly freed using ::cudaFree and"]
+    #[doc = " ::cudaFreeMipmappedArray respectively."]
     #[doc = ""]
-    #[doc = " \\param ptr  - Pointer to allocated host memory"]
-    #[doc = " \\param size - Requested allocation size in bytes"]
+    #[doc = " \\param extMem - External memory object to be destroyed"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " ::cudaErrorInvalidResourceHandle"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
+    #[doc = " \\note_destroy_ub"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMalloc, ::cudaMallocPitch, ::cudaMallocArray, ::cudaMalloc3D,"]
-    #[doc = " ::cudaMalloc3DArray, ::cudaHostAlloc, ::cudaFree, ::cudaFreeArray,"]
-    #[doc = " \\ref ::cudaMallocHost(void**, size_t, unsigned int) \"cudaMallocHost (C++ API)\","]
-    #[doc = " ::cudaFreeHost, ::cudaHostAlloc,"]
-    #[doc = " ::cuMemAllocHost"]
-    pub fn cudaMallocHost(ptr: *mut *mut ::std::os::raw::c_void, size: usize) -> cudaError_t;
+    #[doc = " \\sa ::cudaImportExternalMemory"]
+    #[doc = " ::cudaExternalMemoryGetMappedBuffer,"]
+    #[doc = " ::cudaExternalMemoryGetMappedMipmappedArray"]
+    pub fn cudaDestroyExternalMemory(extMem: cudaExternalMemory_t) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Frees memory on the device"]
+    #[doc = " \\brief Imports an external semaphore"]
     #[doc = ""]
-    #[doc = " Frees the memory space pointed to by \\p devPtr, which must have been"]
-    #[doc = " returned by a previous call to ::cudaMalloc() or ::cudaMallocPitch()."]
-    #[doc = " Otherwise, or if ::cudaFree(\\p devPtr) has already been called before,"]
-    #[doc = " an error is returned. If \\p devPtr is 0, no operation is performed."]
-    #[doc = " ::cudaFree() returns ::cudaErrorInvalidDevicePointer in case of failure."]
+    #[doc = " Imports an externally allocated synchronization object and returns"]
+    #[doc = " a handle to that in \\p extSem_out."]
     #[doc = ""]
-    #[doc = " The device version of ::cudaFree cannot be used with a \\p *devPtr"]
-    #[doc = " allocated using the host API, and vice versa."]
+    #[doc = " The properties of the handle being imported must be described in"]
+    #[doc = " \\p semHandleDesc."]
     #[doc = ""]
-    #[doc = " \\param devPtr - Device pointer to memory to free"]
+    #[doc = " If ::cudaExternalSemaphoreHandleDesc::type is"]
+    #[doc = " ::cudaExternalSemaphoreHandleTypeOpaqueFd, then"]
+    #[doc = " ::cudaExternalSemaphoreHandleDesc::handle::fd must be a valid"]
+    #[doc = " file descriptor referencing a synchronization object. Ownership of"]
+    #[doc = " the file descriptor is transferred to the CUDA driver when the"]
+    #[doc = " handle is imported successfully. Performing any operations on the"]
+    #[doc = " file descriptor after it is imported results in undefined behavior."]
+    #[doc = ""]
+    #[doc = " \\param extSem_out    - Returned handle to an external semaphore"]
+    #[doc = " \\param semHandleDesc - Semaphore import handle descriptor"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidDevicePointer"]
+    #[doc = " ::cudaErrorInvalidResourceHandle"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMalloc, ::cudaMallocPitch, ::cudaMallocArray, ::cudaFreeArray,"]
-    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
-    #[doc = " ::cudaFreeHost, ::cudaMalloc3D, ::cudaMalloc3DArray,"]
-    #[doc = " ::cudaHostAlloc,"]
-    #[doc = " ::cuMemFree"]
-    pub fn cudaFree(devPtr: *mut ::std::os::raw::c_void) -> cudaError_t;
+    #[doc = " \\sa ::cudaDestroyExternalSemaphore,"]
+    #[doc = " ::cudaSignalExternalSemaphoresAsync,"]
+    #[doc = " ::cudaWaitExternalSemaphoresAsync"]
+    pub fn cudaImportExternalSemaphore(
+        extSem_out: *mut cudaExternalSemaphore_t,
+        semHandleDesc: *const cudaExternalSemaphoreHandleDesc,
+    ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Frees page-locked memory"]
+    #[doc = " \\brief Signals a set of external semaphore objects"]
     #[doc = ""]
-    #[doc = " Frees the memory space pointed to by \\p hostPtr, which must have been"]
-    #[doc = " returned by a previous call to ::cudaMallocHost() or ::cudaHostAlloc()."]
+    #[doc = " Enqueues a signal operation on a set of externally allocated"]
+    #[doc = " semaphore object in the specified stream. The operations will be"]
+    #[doc = " executed when all prior operations in the stream complete."]
     #[doc = ""]
-    #[doc = " \\param ptr - Pointer to memory to free"]
+    #[doc = " The exact semantics of signaling a semaphore depends on the type of"]
+    #[doc = " the object."]
+    #[doc = ""]
+    #[doc = " If the semaphore object is any one of the following types:"]
+    #[doc = " ::cudaExternalSemaphoreHandleTypeOpaqueFd,"]
+    #[doc = " ::cudaExternalSemaphoreHandleTypeOpaqueWin32,"]
+    #[doc = " ::cudaExternalSemaphoreHandleTypeOpaqueWin32Kmt"]
+    #[doc = " then signaling the semaphore will set it to the signaled state."]
+    #[doc = ""]
+    #[doc = " If the semaphore object is of the type ::cudaExternalSemaphoreHandleTypeD3D12Fence,"]
+    #[doc = " then the semaphore will be set to the value specified in"]
+    #[doc = " ::cudaExternalSemaphoreSignalParams::params::fence::value."]
+    #[doc = ""]
+    #[doc = " \\param extSemArray - Set of external semaphores to be signaled"]
+    #[doc = " \\param paramsArray - Array of semaphore parameters"]
+    #[doc = " \\param numExtSems  - Number of semaphores to signal"]
+    #[doc = " \\param stream     - Stream to enqueue the signal operations in"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " ::cudaErrorInvalidResourceHandle"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMalloc, ::cudaMallocPitch, ::cudaFree, ::cudaMallocArray,"]
-    #[doc = " ::cudaFreeArray,"]
-    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
-    #[doc = " ::cudaMalloc3D, ::cudaMalloc3DArray, ::cudaHostAlloc,"]
-    #[doc = " ::cuMemFreeHost"]
-    pub fn cudaFreeHost(ptr: *mut ::std::os::raw::c_void) -> cudaError_t;
+    #[doc = " \\sa ::cudaImportExternalSemaphore,"]
+    #[doc = " ::cudaDestroyExternalSemaphore,"]
+    #[doc = " ::cudaWaitExternalSemaphoresAsync"]
+    pub fn cudaSignalExternalSemaphoresAsync(
+        extSemArray: *const cudaExternalSemaphore_t,
+        paramsArray: *const cudaExternalSemaphoreSignalParams,
+        numExtSems: ::std::os::raw::c_uint,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Allocates page-locked memory on the host"]
-    #[doc = ""]
-    #[doc = " Allocates \\p size bytes of host memory that is page-locked and accessible"]
-    #[doc = " to the device. The driver tracks the virtual memory ranges allocated with"]
-    #[doc = " this function and automatically accelerates calls to functions such as"]
-    #[doc = " ::cudaMemcpy(). Since the memory can be accessed directly by the device, it"]
-    #[doc = " can be read or written with much higher bandwidth than pageable memory"]
-    #[doc = " obtained with functions such as ::malloc(). Allocating excessive amounts of"]
-    #[doc = " pinned memory may degrade system performance, since it reduces the amount"]
-    #[doc = " of memory available to the system for paging. As a result, this function is"]
-    #[doc = " best used sparingly to allocate staging areas for data exchange between host"]
-    #[doc = " and device."]
-    #[doc = ""]
-    #[doc = " The \\p flags parameter enables different options to be specified that affect"]
-    #[doc = " the allocation, as follows."]
-    #[doc = " - ::cudaHostAllocDefault: This flag\'s value is defined to be 0 and causes"]
-    #[doc = " ::cudaHostAlloc() to emulate ::cudaMallocHost()."]
-    #[doc = " - ::cudaHostAllocPortable: The memory returned by this call will be"]
-    #[doc = " considered as pinned memory by all CUDA contexts, not just the one that"]
-    #[doc = " performed the allocation."]
-    #[doc = " - ::cudaHostAllocMapped: Maps the allocation into the CUDA address space."]
-    #[doc = " The device pointer to the memory may be obtained by calling"]
-    #[doc = " ::cudaHostGetDevicePointer()."]
-    #[doc = " - ::cudaHostAllocWriteCombined: Allocates the memory as write-combined (WC)."]
-    #[doc = " WC memory can be transferred across the PCI Express bus more quickly on some"]
-    #[doc = " system configurations, but cannot be read efficiently by most CPUs.  WC"]
-    #[doc = " memory is a good option for buffers that will be written by the CPU and read"]
-    #[doc = " by the device via mapped pinned memory or host->device transfers."]
-    #[doc = ""]
-    #[doc = " All of these flags are orthogonal to one another: a developer may allocate"]
-    #[doc = " memory that is portable, mapped and/or write-combined with no restrictions."]
+    #[doc = " \\brief Waits on a set of external semaphore objects"]
+    #[doc = ""]
+    #[doc = " Enqueues a wait operation on a set of externally allocated"]
+    #[doc = " semaphore object in the specified stream. The operations will be"]
+    #[doc = " executed when all prior operations in the stream complete."]
+    #[doc = ""]
+    #[doc = " The exact semantics of waiting on a semaphore depends on the type"]
+    #[doc = " of the object."]
+    #[doc = ""]
+    #[doc = " If the semaphore object is any one of the following types:"]
+    #[doc = " ::cudaExternalSemaphoreHandleTypeOpaqueFd,"]
+    #[doc = " ::cudaExternalSemaphoreHandleTypeOpaqueWin32,"]
+    #[doc = " ::cudaExternalSemaphoreHandleTypeOpaqueWin32Kmt"]
+    #[doc = " then waiting on the semaphore will wait until the semaphore reaches"]
+    #[doc = " the signaled state. The semaphore will then be reset to the"]
+    #[doc = " unsignaled state. Therefore for every signal operation, there can"]
+    #[doc = " only be one wait operation."]
+    #[doc = ""]
+    #[doc = " If the semaphore object is of the type ::cudaExternalSemaphoreHandleTypeD3D12Fence,"]
+    #[doc = " then waiting on the semaphore will wait until the value of the"]
+    #[doc = " semaphore is greater than or equal to"]
+    #[doc = " ::cudaExternalSemaphoreWaitParams::params::fence::value."]
+    #[doc = ""]
+    #[doc = " \\param extSemArray - External semaphores to be waited on"]
+    #[doc = " \\param paramsArray - Array of semaphore parameters"]
+    #[doc = " \\param numExtSems  - Number of semaphores to wait on"]
+    #[doc = " \\param stream      - Stream to enqueue the wait operations in"]
     #[doc = ""]
-    #[doc = " In order for the ::cudaHostAllocMapped flag to have any effect, the CUDA context"]
-    #[doc = " must support the ::cudaDeviceMapHost flag, which can be checked via"]
-    #[doc = " ::cudaGetDeviceFlags(). The ::cudaDeviceMapHost flag is implicitly set for"]
-    #[doc = " contexts created via the runtime API."]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " The ::cudaHostAllocMapped flag may be specified on CUDA contexts for devices"]
-    #[doc = " that do not support mapped pinned memory. The failure is deferred to"]
-    #[doc = " ::cudaHostGetDevicePointer() because the memory may be mapped into other"]
-    #[doc = " CUDA contexts via the ::cudaHostAllocPortable flag."]
+    #[doc = " \\sa ::cudaImportExternalSemaphore,"]
+    #[doc = " ::cudaDestroyExternalSemaphore,"]
+    #[doc = " ::cudaSignalExternalSemaphoresAsync"]
+    pub fn cudaWaitExternalSemaphoresAsync(
+        extSemArray: *const cudaExternalSemaphore_t,
+        paramsArray: *const cudaExternalSemaphoreWaitParams,
+        numExtSems: ::std::os::raw::c_uint,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Destroys an external semaphore"]
     #[doc = ""]
-    #[doc = " Memory allocated by this function must be freed with ::cudaFreeHost()."]
+    #[doc = " Destroys an external semaphore object and releases any references"]
+    #[doc = " to the underlying resource. Any outstanding signals or waits must"]
+    #[doc = " have completed before the semaphore is destroyed."]
     #[doc = ""]
-    #[doc = " \\param pHost - Device pointer to allocated memory"]
-    #[doc = " \\param size  - Requested allocation size in bytes"]
-    #[doc = " \\param flags - Requested properties of allocated memory"]
+    #[doc = " \\param extSem - External semaphore to be destroyed"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " ::cudaErrorInvalidResourceHandle"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
+    #[doc = " \\note_destroy_ub"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaSetDeviceFlags,"]
-    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
-    #[doc = " ::cudaFreeHost,"]
-    #[doc = " ::cudaGetDeviceFlags,"]
-    #[doc = " ::cuMemHostAlloc"]
-    pub fn cudaHostAlloc(
-        pHost: *mut *mut ::std::os::raw::c_void,
-        size: usize,
-        flags: ::std::os::raw::c_uint,
-    ) -> cudaError_t;
+    #[doc = " \\sa ::cudaImportExternalSemaphore,"]
+    #[doc = " ::cudaSignalExternalSemaphoresAsync,"]
+    #[doc = " ::cudaWaitExternalSemaphoresAsync"]
+    pub fn cudaDestroyExternalSemaphore(extSem: cudaExternalSemaphore_t) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Registers an existing host memory range for use by CUDA"]
+    #[doc = " \\brief Launches a device function"]
     #[doc = ""]
-    #[doc = " Page-locks the memory range specified by \\p ptr and \\p size and maps it"]
-    #[doc = " for the device(s) as specified by \\p flags. This memory range also is added"]
-    #[doc = " to the same tracking mechanism as ::cudaHostAlloc() to automatically accelerate"]
-    #[doc = " calls to functions such as ::cudaMemcpy(). Since the memory can be accessed"]
-    #[doc = " directly by the device, it can be read or written with much higher bandwidth"]
-    #[doc = " than pageable memory that has not been registered.  Page-locking excessive"]
-    #[doc = " amounts of memory may degrade system performance, since it reduces the amount"]
-    #[doc = " of memory available to the system for paging. As a result, this function is"]
-    #[doc = " best used sparingly to register staging areas for data exchange between"]
-    #[doc = " host and device."]
+    #[doc = " The function invokes kernel \\p func on \\p gridDim (\\p gridDim.x &times; \\p gridDim.y"]
+    #[doc = " &times; \\p gridDim.z) grid of blocks. Each block contains \\p blockDim (\\p blockDim.x &times;"]
+    #[doc = " \\p blockDim.y &times; \\p blockDim.z) threads."]
     #[doc = ""]
-    #[doc = " ::cudaHostRegister is supported only on I/O coherent devices that have a non-zero"]
-    #[doc = " value for the device attribute ::cudaDevAttrHostRegisterSupported."]
+    #[doc = " If the kernel has N parameters the \\p args should point to array of N pointers."]
+    #[doc = " Each pointer, from <tt>args[0]</tt> to <tt>args[N - 1]</tt>, point to the region"]
+    #[doc = " of memory from which the actual parameter will be copied."]
     #[doc = ""]
-    #[doc = " The \\p flags parameter enables different options to be specified that"]
-    #[doc = " affect the allocation, as follows."]
+    #[doc = " \\param func        - Device function symbol"]
+    #[doc = " \\param gridDim     - Grid dimentions"]
+    #[doc = " \\param blockDim    - Block dimentions"]
+    #[doc = " \\param args        - Arguments"]
+    #[doc = " \\param sharedMem   - Shared memory"]
+    #[doc = " \\param stream      - Stream identifier"]
     #[doc = ""]
-    #[doc = " - ::cudaHostRegisterDefault: On a system with unified virtual addressing,"]
-    #[doc = "   the memory will be both mapped and portable.  On a system with no unified"]
-    #[doc = "   virtual addressing, the memory will be neither mapped nor portable."]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction,"]
+    #[doc = " ::cudaErrorInvalidConfiguration,"]
+    #[doc = " ::cudaErrorLaunchFailure,"]
+    #[doc = " ::cudaErrorLaunchTimeout,"]
+    #[doc = " ::cudaErrorLaunchOutOfResources,"]
+    #[doc = " ::cudaErrorSharedObjectInitFailed,"]
+    #[doc = " ::cudaErrorInvalidPtx,"]
+    #[doc = " ::cudaErrorNoKernelImageForDevice,"]
+    #[doc = " ::cudaErrorJitCompilerNotFound"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " - ::cudaHostRegisterPortable: The memory returned by this call will be"]
-    #[doc = "   considered as pinned memory by all CUDA contexts, not just the one that"]
-    #[doc = "   performed the allocation."]
+    #[doc = " \\sa"]
+    #[doc = " \\ref ::cudaLaunchKernel(const T *func, dim3 gridDim, dim3 blockDim, void **args, size_t sharedMem, cudaStream_t stream) \"cudaLaunchKernel (C++ API)\","]
+    #[doc = " ::cuLaunchKernel"]
+    pub fn cudaLaunchKernel(
+        func: *const ::std::os::raw::c_void,
+        gridDim: dim3,
+        blockDim: dim3,
+        args: *mut *mut ::std::os::raw::c_void,
+        sharedMem: usize,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Launches a device function where thread blocks can cooperate and synchronize as they execute"]
     #[doc = ""]
-    #[doc = " - ::cudaHostRegisterMapped: Maps the allocation into the CUDA address"]
-    #[doc = "   space. The device pointer to the memory may be obtained by calling"]
-    #[doc = "   ::cudaHostGetDevicePointer()."]
+    #[doc = " The function invokes kernel \\p func on \\p gridDim (\\p gridDim.x &times; \\p gridDim.y"]
+    #[doc = " &times; \\p gridDim.z) grid of blocks. Each block contains \\p blockDim (\\p blockDim.x &times;"]
+    #[doc = " \\p blockDim.y &times; \\p blockDim.z) threads."]
     #[doc = ""]
-    #[doc = " - ::cudaHostRegisterIoMemory: The passed memory pointer is treated as"]
-    #[doc = "   pointing to some memory-mapped I/O space, e.g. belonging to a"]
-    #[doc = "   third-party PCIe device, and it will marked as non cache-coherent and"]
-    #[doc = "   contiguous."]
+    #[doc = " The device on which this kernel is invoked must have a non-zero value for"]
+    #[doc = " the device attribute ::cudaDevAttrCooperativeLaunch."]
     #[doc = ""]
-    #[doc = " All of these flags are orthogonal to one another: a developer may page-lock"]
-    #[doc = " memory that is portable or mapped with no restrictions."]
+    #[doc = " The total number of blocks launched cannot exceed the maximum number of blocks per"]
+    #[doc = " multiprocessor as returned by ::cudaOccupancyMaxActiveBlocksPerMultiprocessor (or"]
+    #[doc = " ::cudaOccupancyMaxActiveBlocksPerMultiprocessorWithFlags) times the number of multiprocessors"]
+    #[doc = " as specified by the device attribute ::cudaDevAttrMultiProcessorCount."]
     #[doc = ""]
-    #[doc = " The CUDA context must have been created with the ::cudaMapHost flag in"]
-    #[doc = " order for the ::cudaHostRegisterMapped flag to have any effect."]
+    #[doc = " \\param func        - Device function symbol"]
+    #[doc = " \\param gridDim     - Grid dimentions"]
+    #[doc = " \\param blockDim    - Block dimentions"]
+    #[doc = " \\param args        - Arguments"]
+    #[doc = " \\param sharedMem   - Shared memory"]
+    #[doc = " \\param stream      - Stream identifier"]
     #[doc = ""]
-    #[doc = " The ::cudaHostRegisterMapped flag may be specified on CUDA contexts for"]
-    #[doc = " devices that do not support mapped pinned memory. The failure is deferred"]
-    #[doc = " to ::cudaHostGetDevicePointer() because the memory may be mapped into"]
-    #[doc = " other CUDA contexts via the ::cudaHostRegisterPortable flag."]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction,"]
+    #[doc = " ::cudaErrorInvalidConfiguration,"]
+    #[doc = " ::cudaErrorLaunchFailure,"]
+    #[doc = " ::cudaErrorLaunchTimeout,"]
+    #[doc = " ::cudaErrorLaunchOutOfResources,"]
+    #[doc = " ::cudaErrorCooperativeLaunchTooLarge,"]
+    #[doc = " ::cudaErrorSharedObjectInitFailed"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " For devices that have a non-zero value for the device attribute"]
-    #[doc = " ::cudaDevAttrCanUseHostPointerForRegisteredMem, the memory"]
-    #[doc = " can also be accessed from the device using the host pointer \\p ptr."]
-    #[doc = " The device pointer returned by ::cudaHostGetDevicePointer() may or may not"]
-    #[doc = " match the original host pointer \\p ptr and depends on the devices visible to the"]
-    #[doc = " application. If all devices visible to the application have a non-zero value for the"]
-    #[doc = " device attribute, the device pointer returned by ::cudaHostGetDevicePointer()"]
-    #[doc = " will match the original pointer \\p ptr. If any device visible to the application"]
-    #[doc = " has a zero value for the device attribute, the device pointer returned by"]
-    #[doc = " ::cudaHostGetDevicePointer() will not match the original host pointer \\p ptr,"]
-    #[doc = " but it will be suitable for use on all devices provided Unified Virtual Addressing"]
-    #[doc = " is enabled. In such systems, it is valid to access the memory using either pointer"]
-    #[doc = " on devices that have a non-zero value for the device attribute. Note however that"]
-    #[doc = " such devices should access the memory using only of the two pointers and not both."]
+    #[doc = " \\sa"]
+    #[doc = " ::cuLaunchCooperativeKernel"]
+    pub fn cudaLaunchCooperativeKernel(
+        func: *const ::std::os::raw::c_void,
+        gridDim: dim3,
+        blockDim: dim3,
+        args: *mut *mut ::std::os::raw::c_void,
+        sharedMem: usize,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Launches device functions on multiple devices where thread blocks can cooperate and synchronize as they execute"]
     #[doc = ""]
-    #[doc = " The memory page-locked by this function must be unregistered with ::cudaHostUnregister()."]
+    #[doc = " Invokes kernels as specified in the \\p launchParamsList array where each element"]
+    #[doc = " of the array specifies all the parameters required to perform a single kernel launch."]
+    #[doc = " These kernels can cooperate and synchronize as they execute. The size of the array is"]
+    #[doc = " specified by \\p numDevices."]
     #[doc = ""]
-    #[doc = " \\param ptr   - Host pointer to memory to page-lock"]
-    #[doc = " \\param size  - Size in bytes of the address range to page-lock in bytes"]
-    #[doc = " \\param flags - Flags for allocation request"]
+    #[doc = " No two kernels can be launched on the same device. All the devices targeted by this"]
+    #[doc = " multi-device launch must be identical. All devices must have a non-zero value for the"]
+    #[doc = " device attribute ::cudaDevAttrCooperativeMultiDeviceLaunch."]
+    #[doc = ""]
+    #[doc = " \\param launchParamsList - List of launch parameters, one per device"]
+    #[doc = " \\param numDevices       - Size of the \\p launchParamsList array"]
+    #[doc = " \\param flags            - Flags to control launch behavior"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorMemoryAllocation,"]
-    #[doc = " ::cudaErrorHostMemoryAlreadyRegistered,"]
-    #[doc = " ::cudaErrorNotSupported"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction,"]
+    #[doc = " ::cudaErrorInvalidConfiguration,"]
+    #[doc = " ::cudaErrorLaunchFailure,"]
+    #[doc = " ::cudaErrorLaunchTimeout,"]
+    #[doc = " ::cudaErrorLaunchOutOfResources,"]
+    #[doc = " ::cudaErrorCooperativeLaunchTooLarge,"]
+    #[doc = " ::cudaErrorSharedObjectInitFailed"]
+    #[doc = " \\note_null_stream"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaHostUnregister, ::cudaHostGetFlags, ::cudaHostGetDevicePointer,"]
-    #[doc = " ::cuMemHostRegister"]
-    pub fn cudaHostRegister(
-        ptr: *mut ::std::os::raw::c_void,
-        size: usize,
+    #[doc = " \\sa"]
+    #[doc = " ::cuLaunchCooperativeKernelMultiDevice"]
+    pub fn cudaLaunchCooperativeKernelMultiDevice(
+        launchParamsList: *mut cudaLaunchParams,
+        numDevices: ::std::os::raw::c_uint,
         flags: ::std::os::raw::c_uint,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Unregisters a memory range that was registered with cudaHostRegister"]
-    #[doc = ""]
-    #[doc = " Unmaps the memory range whose base address is specified by \\p ptr, and makes"]
-    #[doc = " it pageable again."]
+    #[doc = " \\brief Sets the preferred cache configuration for a device function"]
     #[doc = ""]
-    #[doc = " The base address must be the same one specified to ::cudaHostRegister()."]
+    #[doc = " On devices where the L1 cache and shared memory use the same hardware"]
+    #[doc = " resources, this sets through \\p cacheConfig the preferred cache configuration"]
+    #[doc = " for the function specified via \\p func. This is only a preference. The"]
+    #[doc = " runtime will use the requested configuration if possible, but it is free to"]
+    #[doc = " choose a different configuration if required to execute \\p func."]
     #[doc = ""]
-    #[doc = " \\param ptr - Host pointer to memory to unregister"]
+    #[doc = " \\param func        - Device function symbol"]
+    #[doc = " \\param cacheConfig - Requested cache configuration"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorHostMemoryNotRegistered"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction"]
     #[doc = " \\notefnerr"]
+    #[doc = " \\note_string_api_deprecation2"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaHostUnregister,"]
-    #[doc = " ::cuMemHostUnregister"]
-    pub fn cudaHostUnregister(ptr: *mut ::std::os::raw::c_void) -> cudaError_t;
+    #[doc = " \\sa ::cudaFuncGetAttributes,"]
+    #[doc = " ::cuFuncSetCacheConfig"]
+    pub fn cudaFuncSetCacheConfig(
+        func: *const ::std::os::raw::c_void,
+        cacheConfig: cudaFuncCache,
+    ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Passes back device pointer of mapped host memory allocated by"]
-    #[doc = " cudaHostAlloc or registered by cudaHostRegister"]
+    #[doc = " \\brief Find out attributes for a given function"]
     #[doc = ""]
-    #[doc = " Passes back the device pointer corresponding to the mapped, pinned host"]
-    #[doc = " buffer allocated by ::cudaHostAlloc() or registered by ::cudaHostRegister()."]
+    #[doc = " This function obtains the attributes of a function specified via \\p func."]
+    #[doc = " \\p func is a device function symbol and must be declared as a"]
+    #[doc = " \\c __global__ function. The fetched attributes are placed in \\p attr."]
+    #[doc = " If the specified function does not exist, then"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction is returned."]
     #[doc = ""]
-    #[doc = " ::cudaHostGetDevicePointer() will fail if the ::cudaDeviceMapHost flag was"]
-    #[doc = " not specified before deferred context creation occurred, or if called on a"]
-    #[doc = " device that does not support mapped, pinned memory."]
-    #[doc = ""]
-    #[doc = " For devices that have a non-zero value for the device attribute"]
-    #[doc = " ::cudaDevAttrCanUseHostPointerForRegisteredMem, the memory"]
-    #[doc = " can also be accessed from the device using the host pointer \\p pHost."]
-    #[doc = " The device pointer returned by ::cudaHostGetDevicePointer() may or may not"]
-    #[doc = " match the original host pointer \\p pHost and depends on the devices visible to the"]
-    #[doc = " application. If all devices visible to the application have a non-zero value for the"]
-    #[doc = " device attribute, the device pointer returned by ::cudaHostGetDevicePointer()"]
-    #[doc = " will match the original pointer \\p pHost. If any device visible to the application"]
-    #[doc = " has a zero value for the device attribute, the device pointer returned by"]
-    #[doc = " ::cudaHostGetDevicePointer() will not match the original host pointer \\p pHost,"]
-    #[doc = " but it will be suitable for use on all devices provided Unified Virtual Addressing"]
-    #[doc = " is enabled. In such systems, it is valid to access the memory using either pointer"]
-    #[doc = " on devices that have a non-zero value for the device attribute. Note however that"]
-    #[doc = " such devices should access the memory using only of the two pointers and not both."]
-    #[doc = ""]
-    #[doc = " \\p flags provides for future releases.  For now, it must be set to 0."]
-    #[doc = ""]
-    #[doc = " \\param pDevice - Returned device pointer for mapped memory"]
-    #[doc = " \\param pHost   - Requested host pointer mapping"]
-    #[doc = " \\param flags   - Flags for extensions (must be 0 for now)"]
+    #[doc = " \\param attr - Return pointer to function\'s attributes"]
+    #[doc = " \\param func - Device function symbol"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction"]
     #[doc = " \\notefnerr"]
+    #[doc = " \\note_string_api_deprecation2"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaSetDeviceFlags, ::cudaHostAlloc,"]
-    #[doc = " ::cuMemHostGetDevicePointer"]
-    pub fn cudaHostGetDevicePointer(
-        pDevice: *mut *mut ::std::os::raw::c_void,
-        pHost: *mut ::std::os::raw::c_void,
-        flags: ::std::os::raw::c_uint,
+    #[doc = " \\sa ::cudaFuncSetCacheConfig,"]
+    #[doc = " ::cuFuncGetAttribute"]
+    pub fn cudaFuncGetAttributes(
+        attr: *mut cudaFuncAttributes,
+        func: *const ::std::os::raw::c_void,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Passes back flags used to allocate pinned host memory allocated by"]
-    #[doc = " cudaHostAlloc"]
+    #[doc = " \\brief Set attributes for a given function"]
     #[doc = ""]
-    #[doc = " ::cudaHostGetFlags() will fail if the input pointer does not"]
-    #[doc = " reside in an address range allocated by ::cudaHostAlloc()."]
+    #[doc = " This function sets the attributes of a function specified via \\p func."]
+    #[doc = " The parameter \\p func must be a pointer to a function that executes"]
+    #[doc = " on the device. The parameter specified by \\p func must be declared as a \\p __global__"]
+    #[doc = " function. The enumeration defined by \\p attr is set to the value defined by \\p value."]
+    #[doc = " If the specified function does not exist, then ::cudaErrorInvalidDeviceFunction is returned."]
+    #[doc = " If the specified attribute cannot be written, or if the value is incorrect,"]
+    #[doc = " then ::cudaErrorInvalidValue is returned."]
     #[doc = ""]
-    #[doc = " \\param pFlags - Returned flags word"]
-    #[doc = " \\param pHost - Host pointer"]
+    #[doc = " \\param func  - Function to get attributes of"]
+    #[doc = " \\param attr  - Attribute to set"]
+    #[doc = " \\param value - Value to set"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction,"]
     #[doc = " ::cudaErrorInvalidValue"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
-    #[doc = ""]
-    #[doc = " \\sa ::cudaHostAlloc,"]
-    #[doc = " ::cuMemHostGetFlags"]
-    pub fn cudaHostGetFlags(
-        pFlags: *mut ::std::os::raw::c_uint,
-        pHost: *mut ::std::os::raw::c_void,
+    pub fn cudaFuncSetAttribute(
+        func: *const ::std::os::raw::c_void,
+        attr: cudaFuncAttribute,
+        value: ::std::os::raw::c_int,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Copies data between host and device"]
-    #[doc = ""]
-    #[doc = " Copies \\p count bytes from the memory area pointed to by \\p src to the"]
-    #[doc = " memory area pointed to by \\p dst, where \\p kind specifies the direction"]
-    #[doc = " of the copy, and must be one of ::cudaMemcpyHostToHost,"]
-    #[doc = " ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
-    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
-    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
-    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
-    #[doc = " allowed on systems that support unified virtual addressing. Calling"]
-    #[doc = " ::cudaMemcpy() with dst and src pointers that do not match the direction of"]
-    #[doc = " the copy results in an undefined behavior."]
-    #[doc = ""]
-    #[doc = " \\param dst   - Destination memory address"]
-    #[doc = " \\param src   - Source memory address"]
-    #[doc = " \\param count - Size in bytes to copy"]
-    #[doc = " \\param kind  - Type of transfer"]
-    #[doc = ""]
-    #[doc = " \\return"]
-    #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
-    #[doc = " \\notefnerr"]
-    #[doc = " \\note_init_rt"]
-    #[doc = " \\note_callback"]
+    #[doc = " \\brief Enqueues a host function call in a stream"]
     #[doc = ""]
-    #[doc = " \\note_sync"]
+    #[doc = " Enqueues a host function to run in a stream.  The function will be called"]
+    #[doc = " after currently enqueued work and will block work added after it."]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
-    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
-    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
-    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
-    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
-    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
-    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
-    #[doc = " ::cuMemcpyDtoH,"]
-    #[doc = " ::cuMemcpyHtoD,"]
-    #[doc = " ::cuMemcpyDtoD,"]
-    #[doc = " ::cuMemcpy"]
-    pub fn cudaMemcpy(
-        dst: *mut ::std::os::raw::c_void,
-        src: *const ::std::os::raw::c_void,
-        count: usize,
-        kind: cudaMemcpyKind,
-    ) -> cudaError_t;
-}
-extern "C" {
-    #[doc = " \\brief Copies memory between two devices"]
+    #[doc = " The host function must not make any CUDA API calls.  Attempting to use a"]
+    #[doc = " CUDA API may result in ::cudaErrorNotPermitted, but this is not required."]
+    #[doc = " The host function must not perform any synchronization that may depend on"]
+    #[doc = " outstanding CUDA work not mandated to run earlier.  Host functions without a"]
+    #[doc = " mandated order (such as in independent streams) execute in undefined order"]
+    #[doc = " and may be serialized."]
     #[doc = ""]
-    #[doc = " Copies memory from one device to memory on another device.  \\p dst is the"]
-    #[doc = " base device pointer of the destination memory and \\p dstDevice is the"]
-    #[doc = " destination device.  \\p src is the base device pointer of the source memory"]
-    #[doc = " and \\p srcDevice is the source device.  \\p count specifies the number of bytes"]
-    #[doc = " to copy."]
+    #[doc = " For the purposes of Unified Memory, execution makes a number of guarantees:"]
+    #[doc = " <ul>"]
+    #[doc = " <li>The stream is considered idle for the duration of the function\'s"]
+    #[doc = " execution.  Thus, for example, the function may always use memory attached"]
+    #[doc = " to the stream it was enqueued in.</li>"]
+    #[doc = " <li>The start of execution of the function has the same effect as"]
+    #[doc = " synchronizing an event recorded in the same stream immediately prior to"]
+    #[doc = " the function.  It thus synchronizes streams which have been \"joined\""]
+    #[doc = " prior to the function.</li>"]
+    #[doc = " <li>Adding device work to any stream does not have the effect of making"]
+    #[doc = " the stream active until all preceding host functions and stream callbacks"]
+    #[doc = " have executed.  Thus, for"]
+    #[doc = " example, a function might use global attached memory even if work has"]
+    #[doc = " been added to another stream, if the work has been ordered behind the"]
+    #[doc = " function call with an event.</li>"]
+    #[doc = " <li>Completion of the function does not cause a stream to become"]
+    #[doc = " active except as described above.  The stream will remain idle"]
+    #[doc = " if no device work follows the function, and will remain idle across"]
+    #[doc = " consecutive host functions or stream callbacks without device work in"]
+    #[doc = " between.  Thus, for example,"]
+    #[doc = " stream synchronization can be done by signaling from a host function at the"]
+    #[doc = " end of the stream.</li>"]
+    #[doc = " </ul>"]
     #[doc = ""]
-    #[doc = " Note that this function is asynchronous with respect to the host, but"]
-    #[doc = " serialized with respect all pending and future asynchronous work in to the"]
-    #[doc = " current device, \\p srcDevice, and \\p dstDevice (use ::cudaMemcpyPeerAsync"]
-    #[doc = " to avoid this synchronization)."]
+    #[doc = " Note that, in constrast to ::cudaStreamAddCallback, the function will not be"]
+    #[doc = " called in the event of an error in the CUDA context."]
     #[doc = ""]
-    #[doc = " \\param dst       - Destination device pointer"]
-    #[doc = " \\param dstDevice - Destination device"]
-    #[doc = " \\param src       - Source device pointer"]
-    #[doc = " \\param srcDevice - Source device"]
-    #[doc = " \\param count     - Size of memory copy in bytes"]
+    #[doc = " \\param stream   - Stream to enqueue function call in"]
+    #[doc = " \\param fn       - The function to call once preceding stream operations are complete"]
+    #[doc = " \\param userData - User-specified data to be passed to the function"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
     #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " ::cudaErrorNotSupported"]
+    #[doc = " \\note_null_stream"]
     #[doc = " \\notefnerr"]
-    #[doc = " \\note_sync"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyAsync, ::cudaMemcpyPeerAsync,"]
-    #[doc = " ::cudaMemcpy3DPeerAsync,"]
-    #[doc = " ::cuMemcpyPeer"]
-    pub fn cudaMemcpyPeer(
-        dst: *mut ::std::os::raw::c_void,
-        dstDevice: ::std::os::raw::c_int,
-        src: *const ::std::os::raw::c_void,
-        srcDevice: ::std::os::raw::c_int,
-        count: usize,
+    #[doc = " \\sa ::cudaStreamCreate,"]
+    #[doc = " ::cudaStreamQuery,"]
+    #[doc = " ::cudaStreamSynchronize,"]
+    #[doc = " ::cudaStreamWaitEvent,"]
+    #[doc = " ::cudaStreamDestroy,"]
+    #[doc = " ::cudaMallocManaged,"]
+    #[doc = " ::cudaStreamAttachMemAsync,"]
+    #[doc = " ::cudaStreamAddCallback,"]
+    #[doc = " ::cuLaunchHostFunc"]
+    pub fn cudaLaunchHostFunc(
+        stream: cudaStream_t,
+        fn_: cudaHostFn_t,
+        userData: *mut ::std::os::raw::c_void,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Copies data between host and device"]
+    #[doc = " \\brief Returns occupancy for a device function"]
     #[doc = ""]
-    #[doc = " Copies a matrix (\\p height rows of \\p width bytes each) from the memory"]
-    #[doc = " area pointed to by \\p src to the memory area pointed to by \\p dst, where"]
-    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
-    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
-    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
-    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
-    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
-    #[doc = " allowed on systems that support unified virtual addressing. \\p dpitch and"]
-    #[doc = " \\p spitch are the widths in memory in bytes of the 2D arrays pointed to by"]
-    #[doc = " \\p dst and \\p src, including any padding added to the end of each row. The"]
-    #[doc = " memory areas may not overlap. \\p width must not exceed either \\p dpitch or"]
-    #[doc = " \\p spitch. Calling ::cudaMemcpy2D() with \\p dst and \\p src pointers that do"]
-    #[doc = " not match the direction of the copy results in an undefined behavior."]
-    #[doc = " ::cudaMemcpy2D() returns an error if \\p dpitch or \\p spitch exceeds"]
-    #[doc = " the maximum allowed."]
+    #[doc = " Returns in \\p *numBlocks the maximum number of active blocks per"]
+    #[doc = " streaming multiprocessor for the device function."]
     #[doc = ""]
-    #[doc = " \\param dst    - Destination memory address"]
-    #[doc = " \\param dpitch - Pitch of destination memory"]
-    #[doc = " \\param src    - Source memory address"]
-    #[doc = " \\param spitch - Pitch of source memory"]
-    #[doc = " \\param width  - Width of matrix transfer (columns in bytes)"]
-    #[doc = " \\param height - Height of matrix transfer (rows)"]
-    #[doc = " \\param kind   - Type of transfer"]
+    #[doc = " \\param numBlocks       - Returned occupancy"]
+    #[doc = " \\param func            - Kernel function for which occupancy is calculated"]
+    #[doc = " \\param blockSize       - Block size the kernel is intended to be launched with"]
+    #[doc = " \\param dynamicSMemSize - Per-block dynamic shared memory usage intended, in bytes"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidDevice,"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction,"]
     #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidPitchValue,"]
-    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " ::cudaErrorUnknown,"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyToArray,"]
-    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
-    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
-    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
-    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
-    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
-    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
-    #[doc = " ::cuMemcpy2D,"]
-    #[doc = " ::cuMemcpy2DUnaligned"]
-    pub fn cudaMemcpy2D(
-        dst: *mut ::std::os::raw::c_void,
-        dpitch: usize,
-        src: *const ::std::os::raw::c_void,
-        spitch: usize,
-        width: usize,
-        height: usize,
-        kind: cudaMemcpyKind,
+    #[doc = " \\sa ::cudaOccupancyMaxActiveBlocksPerMultiprocessorWithFlags,"]
+    #[doc = " ::cuOccupancyMaxActiveBlocksPerMultiprocessor"]
+    pub fn cudaOccupancyMaxActiveBlocksPerMultiprocessor(
+        numBlocks: *mut ::std::os::raw::c_int,
+        func: *const ::std::os::raw::c_void,
+        blockSize: ::std::os::raw::c_int,
+        dynamicSMemSize: usize,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Copies data between host and device"]
+    #[doc = " \\brief Returns occupancy for a device function with the specified flags"]
     #[doc = ""]
-    #[doc = " Copies \\p count bytes from the memory area pointed to by \\p src to the"]
-    #[doc = " memory area pointed to by \\p dst, where \\p kind specifies the"]
-    #[doc = " direction of the copy, and must be one of ::cudaMemcpyHostToHost,"]
-    #[doc = " ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
-    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
-    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
-    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
-    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = " Returns in \\p *numBlocks the maximum number of active blocks per"]
+    #[doc = " streaming multiprocessor for the device function."]
     #[doc = ""]
-    #[doc = " The memory areas may not overlap. Calling ::cudaMemcpyAsync() with \\p dst and"]
-    #[doc = " \\p src pointers that do not match the direction of the copy results in an"]
-    #[doc = " undefined behavior."]
+    #[doc = " The \\p flags parameter controls how special cases are handled. Valid flags include:"]
     #[doc = ""]
-    #[doc = " ::cudaMemcpyAsync() is asynchronous with respect to the host, so the call"]
-    #[doc = " may return before the copy is complete. The copy can optionally be"]
-    #[doc = " associated to a stream by passing a non-zero \\p stream argument. If \\p kind"]
-    #[doc = " is ::cudaMemcpyHostToDevice or ::cudaMemcpyDeviceToHost and the \\p stream is"]
-    #[doc = " non-zero, the copy may overlap with operations in other streams."]
+    #[doc = " - ::cudaOccupancyDefault: keeps the default behavior as"]
+    #[doc = " ::cudaOccupancyMaxActiveBlocksPerMultiprocessor"]
     #[doc = ""]
-    #[doc = " The device version of this function only handles device to device copies and"]
-    #[doc = " cannot be given local or shared pointers."]
+    #[doc = " - ::cudaOccupancyDisableCachingOverride: This flag suppresses the default behavior"]
+    #[doc = " on platform where global caching affects occupancy. On such platforms, if caching"]
+    #[doc = " is enabled, but per-block SM resource usage would result in zero occupancy, the"]
+    #[doc = " occupancy calculator will calculate the occupancy as if caching is disabled."]
+    #[doc = " Setting this flag makes the occupancy calculator to return 0 in such cases."]
     #[doc = ""]
-    #[doc = " \\param dst    - Destination memory address"]
-    #[doc = " \\param src    - Source memory address"]
-    #[doc = " \\param count  - Size in bytes to copy"]
-    #[doc = " \\param kind   - Type of transfer"]
-    #[doc = " \\param stream - Stream identifier"]
+    #[doc = " \\param numBlocks       - Returned occupancy"]
+    #[doc = " \\param func            - Kernel function for which occupancy is calculated"]
+    #[doc = " \\param blockSize       - Block size the kernel is intended to be launched with"]
+    #[doc = " \\param dynamicSMemSize - Per-block dynamic shared memory usage intended, in bytes"]
+    #[doc = " \\param flags           - Requested behavior for the occupancy calculator"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidDevice,"]
+    #[doc = " ::cudaErrorInvalidDeviceFunction,"]
     #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " ::cudaErrorUnknown,"]
     #[doc = " \\notefnerr"]
-    #[doc = " \\note_async"]
-    #[doc = " \\note_null_stream"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
-    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
-    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
-    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpy2DAsync,"]
-    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
-    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
-    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync"]
-    #[doc = " ::cuMemcpyAsync,"]
-    #[doc = " ::cuMemcpyDtoHAsync,"]
-    #[doc = " ::cuMemcpyHtoDAsync,"]
-    #[doc = " ::cuMemcpyDtoDAsync"]
-    pub fn cudaMemcpyAsync(
-        dst: *mut ::std::os::raw::c_void,
-        src: *const ::std::os::raw::c_void,
-        count: usize,
-        kind: cudaMemcpyKind,
-        stream: cudaStream_t,
+    #[doc = " \\sa ::cudaOccupancyMaxActiveBlocksPerMultiprocessor,"]
+    #[doc = " ::cuOccupancyMaxActiveBlocksPerMultiprocessorWithFlags"]
+    pub fn cudaOccupancyMaxActiveBlocksPerMultiprocessorWithFlags(
+        numBlocks: *mut ::std::os::raw::c_int,
+        func: *const ::std::os::raw::c_void,
+        blockSize: ::std::os::raw::c_int,
+        dynamicSMemSize: usize,
+        flags: ::std::os::raw::c_uint,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Copies memory between two devices asynchronously."]
-    #[doc = ""]
-    #[doc = " Copies memory from one device to memory on another device.  \\p dst is the"]
-    #[doc = " base device pointer of the destination memory and \\p dstDevice is the"]
-    #[doc = " destination device.  \\p src is the base device pointer of the source memory"]
-    #[doc = " and \\p srcDevice is the source device.  \\p count specifies the number of bytes"]
-    #[doc = " to copy."]
+    #[doc = " \\brief Allocates memory that will be automatically managed by the Unified Memory system"]
     #[doc = ""]
-    #[doc = " Note that this function is asynchronous with respect to the host and all work"]
-    #[doc = " on other devices."]
+    #[doc = " Allocates \\p size bytes of managed memory on the device and returns in"]
+    #[doc = " \\p *devPtr a pointer to the allocated memory. If the device doesn\'t support"]
+    #[doc = " allocating managed memory, ::cudaErrorNotSupported is returned. Support"]
+    #[doc = " for managed memory can be queried using the device attribute"]
+    #[doc = " ::cudaDevAttrManagedMemory. The allocated memory is suitably"]
+    #[doc = " aligned for any kind of variable. The memory is not cleared. If \\p size"]
+    #[doc = " is 0, ::cudaMallocManaged returns ::cudaErrorInvalidValue. The pointer"]
+    #[doc = " is valid on the CPU and on all GPUs in the system that support managed memory."]
+    #[doc = " All accesses to this pointer must obey the Unified Memory programming model."]
     #[doc = ""]
-    #[doc = " \\param dst       - Destination device pointer"]
-    #[doc = " \\param dstDevice - Destination device"]
-    #[doc = " \\param src       - Source device pointer"]
-    #[doc = " \\param srcDevice - Source device"]
-    #[doc = " \\param count     - Size of memory copy in bytes"]
-    #[doc = " \\param stream    - Stream identifier"]
+    #[doc = " \\p flags specifies the default stream association for this allocation."]
+    #[doc = " \\p flags must be one of ::cudaMemAttachGlobal or ::cudaMemAttachHost. The"]
+    #[doc = " default value for \\p flags is ::cudaMemAttachGlobal."]
+    #[doc = " If ::cudaMemAttachGlobal is specified, then this memory is accessible from"]
+    #[doc = " any stream on any device. If ::cudaMemAttachHost is specified, then the"]
+    #[doc = " allocation should not be accessed from devices that have a zero value for the"]
+    #[doc = " device attribute ::cudaDevAttrConcurrentManagedAccess; an explicit call to"]
+    #[doc = " ::cudaStreamAttachMemAsync will be required to enable access on such devices."]
     #[doc = ""]
-    #[doc = " \\return"]
-    #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " If the association is later changed via ::cudaStreamAttachMemAsync to"]
+    #[doc = " a single stream, the default association, as specifed during ::cudaMallocManaged,"]
+    #[doc = " is restored when that stream is destroyed. For __managed__ variables, the"]
+    #[doc = " default association is always ::cudaMemAttachGlobal. Note that destroying a"]
+    #[doc = " stream is an asynchronous operation, and as a result, the change to default"]
+    #[doc = " association won\'t happen until all work in the stream has completed."]
+    #[doc = ""]
+    #[doc = " Memory allocated with ::cudaMallocManaged should be released with ::cudaFree."]
+    #[doc = ""]
+    #[doc = " Device memory oversubscription is possible for GPUs that have a non-zero value for the"]
+    #[doc = " device attribute ::cudaDevAttrConcurrentManagedAccess. Managed memory on"]
+    #[doc = " such GPUs may be evicted from device memory to host memory at any time by the Unified"]
+    #[doc = " Memory driver in order to make room for other allocations."]
+    #[doc = ""]
+    #[doc = " In a multi-GPU system where all GPUs have a non-zero value for the device attribute"]
+    #[doc = " ::cudaDevAttrConcurrentManagedAccess, managed memory may not be populated when this"]
+    #[doc = " API returns and instead may be populated on access. In such systems, managed memory can"]
+    #[doc = " migrate to any processor\'s memory at any time. The Unified Memory driver will employ heuristics to"]
+    #[doc = " maintain data locality and prevent excessive page faults to the extent possible. The application"]
+    #[doc = " can also guide the driver about memory usage patterns via ::cudaMemAdvise. The application"]
+    #[doc = " can also explicitly migrate memory to a desired processor\'s memory via"]
+    #[doc = " ::cudaMemPrefetchAsync."]
+    #[doc = ""]
+    #[doc = " In a multi-GPU system where all of the GPUs have a zero value for the device attribute"]
+    #[doc = " ::cudaDevAttrConcurrentManagedAccess and all the GPUs have peer-to-peer support"]
+    #[doc = " with each other, the physical storage for managed memory is created on the GPU which is active"]
+    #[doc = " at the time ::cudaMallocManaged is called. All other GPUs will reference the data at reduced"]
+    #[doc = " bandwidth via peer mappings over the PCIe bus. The Unified Memory driver does not migrate"]
+    #[doc = " memory among such GPUs."]
+    #[doc = ""]
+    #[doc = " In a multi-GPU system where not all GPUs have peer-to-peer support with each other and"]
+    #[doc = " where the value of the device attribute ::cudaDevAttrConcurrentManagedAccess"]
+    #[doc = " is zero for at least one of those GPUs, the location chosen for physical storage of managed"]
+    #[doc = " memory is system-dependent."]
+    #[doc = " - On Linux, the location chosen will be device memory as long as the current set of active"]
+    #[doc = " contexts are on devices that either have peer-to-peer support with each other or have a"]
+    #[doc = " non-zero value for the device attribute ::cudaDevAttrConcurrentManagedAccess."]
+    #[doc = " If there is an active context on a GPU that does not have a non-zero value for that device"]
+    #[doc = " attribute and it does not have peer-to-peer support with the other devices that have active"]
+    #[doc = " contexts on them, then the location for physical storage will be \'zero-copy\' or host memory."]
+    #[doc = " Note that this means that managed memory that is located in device memory is migrated to"]
+    #[doc = " host memory if a new context is created on a GPU that doesn\'t have a non-zero value for"]
+    #[doc = " the device attribute and does not support peer-to-peer with at least one of the other devices"]
+    #[doc = " that has an active context. This in turn implies that context creation may fail if there is"]
+    #[doc = " insufficient host memory to migrate all managed allocations."]
+    #[doc = " - On Windows, the physical storage is always created in \'zero-copy\' or host memory."]
+    #[doc = " All GPUs will reference the data at reduced bandwidth over the PCIe bus. In these"]
+    #[doc = " circumstances, use of the environment variable CUDA_VISIBLE_DEVICES is recommended to"]
+    #[doc = " restrict CUDA to only use those GPUs that have peer-to-peer support."]
+    #[doc = " Alternatively, users can also set CUDA_MANAGED_FORCE_DEVICE_ALLOC to a non-zero"]
+    #[doc = " value to force the driver to always use device memory for physical storage."]
+    #[doc = " When this environment variable is set to a non-zero value, all devices used in"]
+    #[doc = " that process that support managed memory have to be peer-to-peer compatible"]
+    #[doc = " with each other. The error ::cudaErrorInvalidDevice will be returned if a device"]
+    #[doc = " that supports managed memory is used and it is not peer-to-peer compatible with"]
+    #[doc = " any of the other managed memory supporting devices that were previously used in"]
+    #[doc = " that process, even if ::cudaDeviceReset has been called on those devices. These"]
+    #[doc = " environment variables are described in the CUDA programming guide under the"]
+    #[doc = " \"CUDA environment variables\" section."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Pointer to allocated device memory"]
+    #[doc = " \\param size   - Requested allocation size in bytes"]
+    #[doc = " \\param flags  - Must be either ::cudaMemAttachGlobal or ::cudaMemAttachHost (defaults to ::cudaMemAttachGlobal)"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorMemoryAllocation,"]
+    #[doc = " ::cudaErrorNotSupported,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMallocPitch, ::cudaFree, ::cudaMallocArray, ::cudaFreeArray,"]
+    #[doc = " ::cudaMalloc3D, ::cudaMalloc3DArray,"]
+    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
+    #[doc = " ::cudaFreeHost, ::cudaHostAlloc, ::cudaDeviceGetAttribute, ::cudaStreamAttachMemAsync,"]
+    #[doc = " ::cuMemAllocManaged"]
+    pub fn cudaMallocManaged(
+        devPtr: *mut *mut ::std::os::raw::c_void,
+        size: usize,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Allocate memory on the device"]
+    #[doc = ""]
+    #[doc = " Allocates \\p size bytes of linear memory on the device and returns in"]
+    #[doc = " \\p *devPtr a pointer to the allocated memory. The allocated memory is"]
+    #[doc = " suitably aligned for any kind of variable. The memory is not cleared."]
+    #[doc = " ::cudaMalloc() returns ::cudaErrorMemoryAllocation in case of failure."]
+    #[doc = ""]
+    #[doc = " The device version of ::cudaFree cannot be used with a \\p *devPtr"]
+    #[doc = " allocated using the host API, and vice versa."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Pointer to allocated device memory"]
+    #[doc = " \\param size   - Requested allocation size in bytes"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMallocPitch, ::cudaFree, ::cudaMallocArray, ::cudaFreeArray,"]
+    #[doc = " ::cudaMalloc3D, ::cudaMalloc3DArray,"]
+    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
+    #[doc = " ::cudaFreeHost, ::cudaHostAlloc,"]
+    #[doc = " ::cuMemAlloc"]
+    pub fn cudaMalloc(devPtr: *mut *mut ::std::os::raw::c_void, size: usize) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Allocates page-locked memory on the host"]
+    #[doc = ""]
+    #[doc = " Allocates \\p size bytes of host memory that is page-locked and accessible"]
+    #[doc = " to the device. The driver tracks the virtual memory ranges allocated with"]
+    #[doc = " this function and automatically accelerates calls to functions such as"]
+    #[doc = " ::cudaMemcpy*(). Since the memory can be accessed directly by the device,"]
+    #[doc = " it can be read or written with much higher bandwidth than pageable memory"]
+    #[doc = " obtained with functions such as ::malloc(). Allocating excessive amounts of"]
+    #[doc = " memory with ::cudaMallocHost() may degrade system performance, since it"]
+    #[doc = " reduces the amount of memory available to the system for paging. As a"]
+    #[doc = " result, this function is best used sparingly to allocate staging areas for"]
+    #[doc = " data exchange between host and device."]
+    #[doc = ""]
+    #[doc = " \\param ptr  - Pointer to allocated host memory"]
+    #[doc = " \\param size - Requested allocation size in bytes"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMalloc, ::cudaMallocPitch, ::cudaMallocArray, ::cudaMalloc3D,"]
+    #[doc = " ::cudaMalloc3DArray, ::cudaHostAlloc, ::cudaFree, ::cudaFreeArray,"]
+    #[doc = " \\ref ::cudaMallocHost(void**, size_t, unsigned int) \"cudaMallocHost (C++ API)\","]
+    #[doc = " ::cudaFreeHost, ::cudaHostAlloc,"]
+    #[doc = " ::cuMemAllocHost"]
+    pub fn cudaMallocHost(ptr: *mut *mut ::std::os::raw::c_void, size: usize) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Allocate an array on the device"]
+    #[doc = ""]
+    #[doc = " Allocates a CUDA array according to the ::cudaChannelFormatDesc structure"]
+    #[doc = " \\p desc and returns a handle to the new CUDA array in \\p *array."]
+    #[doc = ""]
+    #[doc = " The ::cudaChannelFormatDesc is defined as:"]
+    #[doc = " \\code"]
+    #[doc = " struct cudaChannelFormatDesc {"]
+    #[doc = " int x, y, z, w;"]
+    #[doc = " enum cudaChannelFormatKind f;"]
+    #[doc = " };"]
+    #[doc = " \\endcode"]
+    #[doc = " where ::cudaChannelFormatKind is one of ::cudaChannelFormatKindSigned,"]
+    #[doc = " ::cudaChannelFormatKindUnsigned, or ::cudaChannelFormatKindFloat."]
+    #[doc = ""]
+    #[doc = " The \\p flags parameter enables different options to be specified that affect"]
+    #[doc = " the allocation, as follows."]
+    #[doc = " - ::cudaArrayDefault: This flag\'s value is defined to be 0 and provides default array allocation"]
+    #[doc = " - ::cudaArraySurfaceLoadStore: Allocates an array that can be read from or written to using a surface reference"]
+    #[doc = " - ::cudaArrayTextureGather: This flag indicates that texture gather operations will be performed on the array."]
+    #[doc = ""]
+    #[doc = " \\p width and \\p height must meet certain size requirements. See ::cudaMalloc3DArray() for more details."]
+    #[doc = ""]
+    #[doc = " \\param array  - Pointer to allocated array in device memory"]
+    #[doc = " \\param desc   - Requested channel format"]
+    #[doc = " \\param width  - Requested array allocation width"]
+    #[doc = " \\param height - Requested array allocation height"]
+    #[doc = " \\param flags  - Requested properties of allocated array"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " \\notefnerr"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMalloc, ::cudaMallocPitch, ::cudaFree, ::cudaFreeArray,"]
+    #[doc = " ::cudaMallocHost,"]
+    #[doc = " ::cudaFreeHost,"]
+    #[doc = " ::cudaMalloc3D, ::cudaMalloc3DArray,"]
+    #[doc = " ::cudaHostAlloc,"]
+    #[doc = " ::cuArrayCreate"]
+    pub fn cudaMallocArray(
+        array: *mut cudaArray_t,
+        desc: *const cudaChannelFormatDesc,
+        width: usize,
+        height: usize,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Frees memory on the device"]
+    #[doc = ""]
+    #[doc = " Frees the memory space pointed to by \\p devPtr, which must have been"]
+    #[doc = " returned by a previous call to ::cudaMalloc() or ::cudaMallocPitch()."]
+    #[doc = " Otherwise, or if ::cudaFree(\\p devPtr) has already been called before,"]
+    #[doc = " an error is returned. If \\p devPtr is 0, no operation is performed."]
+    #[doc = " ::cudaFree() returns ::cudaErrorInvalidDevicePointer in case of failure."]
+    #[doc = ""]
+    #[doc = " The device version of ::cudaFree cannot be used with a \\p *devPtr"]
+    #[doc = " allocated using the host API, and vice versa."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Device pointer to memory to free"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidDevicePointer"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMalloc, ::cudaMallocPitch, ::cudaMallocArray, ::cudaFreeArray,"]
+    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
+    #[doc = " ::cudaFreeHost, ::cudaMalloc3D, ::cudaMalloc3DArray,"]
+    #[doc = " ::cudaHostAlloc,"]
+    #[doc = " ::cuMemFree"]
+    pub fn cudaFree(devPtr: *mut ::std::os::raw::c_void) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Frees page-locked memory"]
+    #[doc = ""]
+    #[doc = " Frees the memory space pointed to by \\p hostPtr, which must have been"]
+    #[doc = " returned by a previous call to ::cudaMallocHost() or ::cudaHostAlloc()."]
+    #[doc = ""]
+    #[doc = " \\param ptr - Pointer to memory to free"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMalloc, ::cudaMallocPitch, ::cudaFree, ::cudaMallocArray,"]
+    #[doc = " ::cudaFreeArray,"]
+    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
+    #[doc = " ::cudaMalloc3D, ::cudaMalloc3DArray, ::cudaHostAlloc,"]
+    #[doc = " ::cuMemFreeHost"]
+    pub fn cudaFreeHost(ptr: *mut ::std::os::raw::c_void) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Frees an array on the device"]
+    #[doc = ""]
+    #[doc = " Frees the CUDA array \\p array, which must have been * returned by a"]
+    #[doc = " previous call to ::cudaMallocArray(). If \\p devPtr is 0,"]
+    #[doc = " no operation is performed."]
+    #[doc = ""]
+    #[doc = " \\param array - Pointer to array to free"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInitializationError"]
+    #[doc = " \\notefnerr"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMalloc, ::cudaMallocPitch, ::cudaFree, ::cudaMallocArray,"]
+    #[doc = " ::cudaMallocHost, ::cudaFreeHost, ::cudaHostAlloc,"]
+    #[doc = " ::cuArrayDestroy"]
+    pub fn cudaFreeArray(array: cudaArray_t) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Allocates page-locked memory on the host"]
+    #[doc = ""]
+    #[doc = " Allocates \\p size bytes of host memory that is page-locked and accessible"]
+    #[doc = " to the device. The driver tracks the virtual memory ranges allocated with"]
+    #[doc = " this function and automatically accelerates calls to functions such as"]
+    #[doc = " ::cudaMemcpy(). Since the memory can be accessed directly by the device, it"]
+    #[doc = " can be read or written with much higher bandwidth than pageable memory"]
+    #[doc = " obtained with functions such as ::malloc(). Allocating excessive amounts of"]
+    #[doc = " pinned memory may degrade system performance, since it reduces the amount"]
+    #[doc = " of memory available to the system for paging. As a result, this function is"]
+    #[doc = " best used sparingly to allocate staging areas for data exchange between host"]
+    #[doc = " and device."]
+    #[doc = ""]
+    #[doc = " The \\p flags parameter enables different options to be specified that affect"]
+    #[doc = " the allocation, as follows."]
+    #[doc = " - ::cudaHostAllocDefault: This flag\'s value is defined to be 0 and causes"]
+    #[doc = " ::cudaHostAlloc() to emulate ::cudaMallocHost()."]
+    #[doc = " - ::cudaHostAllocPortable: The memory returned by this call will be"]
+    #[doc = " considered as pinned memory by all CUDA contexts, not just the one that"]
+    #[doc = " performed the allocation."]
+    #[doc = " - ::cudaHostAllocMapped: Maps the allocation into the CUDA address space."]
+    #[doc = " The device pointer to the memory may be obtained by calling"]
+    #[doc = " ::cudaHostGetDevicePointer()."]
+    #[doc = " - ::cudaHostAllocWriteCombined: Allocates the memory as write-combined (WC)."]
+    #[doc = " WC memory can be transferred across the PCI Express bus more quickly on some"]
+    #[doc = " system configurations, but cannot be read efficiently by most CPUs.  WC"]
+    #[doc = " memory is a good option for buffers that will be written by the CPU and read"]
+    #[doc = " by the device via mapped pinned memory or host->device transfers."]
+    #[doc = ""]
+    #[doc = " All of these flags are orthogonal to one another: a developer may allocate"]
+    #[doc = " memory that is portable, mapped and/or write-combined with no restrictions."]
+    #[doc = ""]
+    #[doc = " In order for the ::cudaHostAllocMapped flag to have any effect, the CUDA context"]
+    #[doc = " must support the ::cudaDeviceMapHost flag, which can be checked via"]
+    #[doc = " ::cudaGetDeviceFlags(). The ::cudaDeviceMapHost flag is implicitly set for"]
+    #[doc = " contexts created via the runtime API."]
+    #[doc = ""]
+    #[doc = " The ::cudaHostAllocMapped flag may be specified on CUDA contexts for devices"]
+    #[doc = " that do not support mapped pinned memory. The failure is deferred to"]
+    #[doc = " ::cudaHostGetDevicePointer() because the memory may be mapped into other"]
+    #[doc = " CUDA contexts via the ::cudaHostAllocPortable flag."]
+    #[doc = ""]
+    #[doc = " Memory allocated by this function must be freed with ::cudaFreeHost()."]
+    #[doc = ""]
+    #[doc = " \\param pHost - Device pointer to allocated memory"]
+    #[doc = " \\param size  - Requested allocation size in bytes"]
+    #[doc = " \\param flags - Requested properties of allocated memory"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaSetDeviceFlags,"]
+    #[doc = " \\ref ::cudaMallocHost(void**, size_t) \"cudaMallocHost (C API)\","]
+    #[doc = " ::cudaFreeHost,"]
+    #[doc = " ::cudaGetDeviceFlags,"]
+    #[doc = " ::cuMemHostAlloc"]
+    pub fn cudaHostAlloc(
+        pHost: *mut *mut ::std::os::raw::c_void,
+        size: usize,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Registers an existing host memory range for use by CUDA"]
+    #[doc = ""]
+    #[doc = " Page-locks the memory range specified by \\p ptr and \\p size and maps it"]
+    #[doc = " for the device(s) as specified by \\p flags. This memory range also is added"]
+    #[doc = " to the same tracking mechanism as ::cudaHostAlloc() to automatically accelerate"]
+    #[doc = " calls to functions such as ::cudaMemcpy(). Since the memory can be accessed"]
+    #[doc = " directly by the device, it can be read or written with much higher bandwidth"]
+    #[doc = " than pageable memory that has not been registered.  Page-locking excessive"]
+    #[doc = " amounts of memory may degrade system performance, since it reduces the amount"]
+    #[doc = " of memory available to the system for paging. As a result, this function is"]
+    #[doc = " best used sparingly to register staging areas for data exchange between"]
+    #[doc = " host and device."]
+    #[doc = ""]
+    #[doc = " ::cudaHostRegister is supported only on I/O coherent devices that have a non-zero"]
+    #[doc = " value for the device attribute ::cudaDevAttrHostRegisterSupported."]
+    #[doc = ""]
+    #[doc = " The \\p flags parameter enables different options to be specified that"]
+    #[doc = " affect the allocation, as follows."]
+    #[doc = ""]
+    #[doc = " - ::cudaHostRegisterDefault: On a system with unified virtual addressing,"]
+    #[doc = "   the memory will be both mapped and portable.  On a system with no unified"]
+    #[doc = "   virtual addressing, the memory will be neither mapped nor portable."]
+    #[doc = ""]
+    #[doc = " - ::cudaHostRegisterPortable: The memory returned by this call will be"]
+    #[doc = "   considered as pinned memory by all CUDA contexts, not just the one that"]
+    #[doc = "   performed the allocation."]
+    #[doc = ""]
+    #[doc = " - ::cudaHostRegisterMapped: Maps the allocation into the CUDA address"]
+    #[doc = "   space. The device pointer to the memory may be obtained by calling"]
+    #[doc = "   ::cudaHostGetDevicePointer()."]
+    #[doc = ""]
+    #[doc = " - ::cudaHostRegisterIoMemory: The passed memory pointer is treated as"]
+    #[doc = "   pointing to some memory-mapped I/O space, e.g. belonging to a"]
+    #[doc = "   third-party PCIe device, and it will marked as non cache-coherent and"]
+    #[doc = "   contiguous."]
+    #[doc = ""]
+    #[doc = " All of these flags are orthogonal to one another: a developer may page-lock"]
+    #[doc = " memory that is portable or mapped with no restrictions."]
+    #[doc = ""]
+    #[doc = " The CUDA context must have been created with the ::cudaMapHost flag in"]
+    #[doc = " order for the ::cudaHostRegisterMapped flag to have any effect."]
+    #[doc = ""]
+    #[doc = " The ::cudaHostRegisterMapped flag may be specified on CUDA contexts for"]
+    #[doc = " devices that do not support mapped pinned memory. The failure is deferred"]
+    #[doc = " to ::cudaHostGetDevicePointer() because the memory may be mapped into"]
+    #[doc = " other CUDA contexts via the ::cudaHostRegisterPortable flag."]
+    #[doc = ""]
+    #[doc = " For devices that have a non-zero value for the device attribute"]
+    #[doc = " ::cudaDevAttrCanUseHostPointerForRegisteredMem, the memory"]
+    #[doc = " can also be accessed from the device using the host pointer \\p ptr."]
+    #[doc = " The device pointer returned by ::cudaHostGetDevicePointer() may or may not"]
+    #[doc = " match the original host pointer \\p ptr and depends on the devices visible to the"]
+    #[doc = " application. If all devices visible to the application have a non-zero value for the"]
+    #[doc = " device attribute, the device pointer returned by ::cudaHostGetDevicePointer()"]
+    #[doc = " will match the original pointer \\p ptr. If any device visible to the application"]
+    #[doc = " has a zero value for the device attribute, the device pointer returned by"]
+    #[doc = " ::cudaHostGetDevicePointer() will not match the original host pointer \\p ptr,"]
+    #[doc = " but it will be suitable for use on all devices provided Unified Virtual Addressing"]
+    #[doc = " is enabled. In such systems, it is valid to access the memory using either pointer"]
+    #[doc = " on devices that have a non-zero value for the device attribute. Note however that"]
+    #[doc = " such devices should access the memory using only of the two pointers and not both."]
+    #[doc = ""]
+    #[doc = " The memory page-locked by this function must be unregistered with ::cudaHostUnregister()."]
+    #[doc = ""]
+    #[doc = " \\param ptr   - Host pointer to memory to page-lock"]
+    #[doc = " \\param size  - Size in bytes of the address range to page-lock in bytes"]
+    #[doc = " \\param flags - Flags for allocation request"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorMemoryAllocation,"]
+    #[doc = " ::cudaErrorHostMemoryAlreadyRegistered,"]
+    #[doc = " ::cudaErrorNotSupported"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaHostUnregister, ::cudaHostGetFlags, ::cudaHostGetDevicePointer,"]
+    #[doc = " ::cuMemHostRegister"]
+    pub fn cudaHostRegister(
+        ptr: *mut ::std::os::raw::c_void,
+        size: usize,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Unregisters a memory range that was registered with cudaHostRegister"]
+    #[doc = ""]
+    #[doc = " Unmaps the memory range whose base address is specified by \\p ptr, and makes"]
+    #[doc = " it pageable again."]
+    #[doc = ""]
+    #[doc = " The base address must be the same one specified to ::cudaHostRegister()."]
+    #[doc = ""]
+    #[doc = " \\param ptr - Host pointer to memory to unregister"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorHostMemoryNotRegistered"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaHostUnregister,"]
+    #[doc = " ::cuMemHostUnregister"]
+    pub fn cudaHostUnregister(ptr: *mut ::std::os::raw::c_void) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Passes back device pointer of mapped host memory allocated by"]
+    #[doc = " cudaHostAlloc or registered by cudaHostRegister"]
+    #[doc = ""]
+    #[doc = " Passes back the device pointer corresponding to the mapped, pinned host"]
+    #[doc = " buffer allocated by ::cudaHostAlloc() or registered by ::cudaHostRegister()."]
+    #[doc = ""]
+    #[doc = " ::cudaHostGetDevicePointer() will fail if the ::cudaDeviceMapHost flag was"]
+    #[doc = " not specified before deferred context creation occurred, or if called on a"]
+    #[doc = " device that does not support mapped, pinned memory."]
+    #[doc = ""]
+    #[doc = " For devices that have a non-zero value for the device attribute"]
+    #[doc = " ::cudaDevAttrCanUseHostPointerForRegisteredMem, the memory"]
+    #[doc = " can also be accessed from the device using the host pointer \\p pHost."]
+    #[doc = " The device pointer returned by ::cudaHostGetDevicePointer() may or may not"]
+    #[doc = " match the original host pointer \\p pHost and depends on the devices visible to the"]
+    #[doc = " application. If all devices visible to the application have a non-zero value for the"]
+    #[doc = " device attribute, the device pointer returned by ::cudaHostGetDevicePointer()"]
+    #[doc = " will match the original pointer \\p pHost. If any device visible to the application"]
+    #[doc = " has a zero value for the device attribute, the device pointer returned by"]
+    #[doc = " ::cudaHostGetDevicePointer() will not match the original host pointer \\p pHost,"]
+    #[doc = " but it will be suitable for use on all devices provided Unified Virtual Addressing"]
+    #[doc = " is enabled. In such systems, it is valid to access the memory using either pointer"]
+    #[doc = " on devices that have a non-zero value for the device attribute. Note however that"]
+    #[doc = " such devices should access the memory using only of the two pointers and not both."]
+    #[doc = ""]
+    #[doc = " \\p flags provides for future releases.  For now, it must be set to 0."]
+    #[doc = ""]
+    #[doc = " \\param pDevice - Returned device pointer for mapped memory"]
+    #[doc = " \\param pHost   - Requested host pointer mapping"]
+    #[doc = " \\param flags   - Flags for extensions (must be 0 for now)"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaSetDeviceFlags, ::cudaHostAlloc,"]
+    #[doc = " ::cuMemHostGetDevicePointer"]
+    pub fn cudaHostGetDevicePointer(
+        pDevice: *mut *mut ::std::os::raw::c_void,
+        pHost: *mut ::std::os::raw::c_void,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Passes back flags used to allocate pinned host memory allocated by"]
+    #[doc = " cudaHostAlloc"]
+    #[doc = ""]
+    #[doc = " ::cudaHostGetFlags() will fail if the input pointer does not"]
+    #[doc = " reside in an address range allocated by ::cudaHostAlloc()."]
+    #[doc = ""]
+    #[doc = " \\param pFlags - Returned flags word"]
+    #[doc = " \\param pHost - Host pointer"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaHostAlloc,"]
+    #[doc = " ::cuMemHostGetFlags"]
+    pub fn cudaHostGetFlags(
+        pFlags: *mut ::std::os::raw::c_uint,
+        pHost: *mut ::std::os::raw::c_void,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Allocate an array on the device"]
+    #[doc = ""]
+    #[doc = " Allocates a CUDA array according to the ::cudaChannelFormatDesc structure"]
+    #[doc = " \\p desc and returns a handle to the new CUDA array in \\p *array."]
+    #[doc = ""]
+    #[doc = " ::cudaMalloc3DArray() can allocate the following:"]
+    #[doc = ""]
+    #[doc = " - A 1D array is allocated if the height and depth extents are both zero."]
+    #[doc = " - A 2D array is allocated if only the depth extent is zero."]
+    #[doc = " - A 3D array is allocated if all three extents are non-zero."]
+    #[doc = " - A 1D layered CUDA array is allocated if only the height extent is zero and"]
+    #[doc = " the cudaArrayLayered flag is set. Each layer is a 1D array. The number of layers is"]
+    #[doc = " determined by the depth extent."]
+    #[doc = " - A 2D layered CUDA array is allocated if all three extents are non-zero and"]
+    #[doc = " the cudaArrayLayered flag is set. Each layer is a 2D array. The number of layers is"]
+    #[doc = " determined by the depth extent."]
+    #[doc = ""]
+    #[doc = " \\param array  - Pointer to allocated array in device memory"]
+    #[doc = " \\param desc   - Requested channel format"]
+    #[doc = " \\param extent - Requested allocation size (\\p width field in elements)"]
+    #[doc = " \\param flags  - Flags for extensions"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " \\notefnerr"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMalloc3D, ::cudaMalloc, ::cudaMallocPitch, ::cudaFree,"]
+    #[doc = " ::cudaFreeArray,"]
+    #[doc = " ::cudaMallocHost,"]
+    #[doc = " ::cudaFreeHost,"]
+    #[doc = " ::cudaHostAlloc,"]
+    #[doc = " ::make_cudaExtent,"]
+    #[doc = " ::cuArray3DCreate"]
+    pub fn cudaMalloc3DArray(
+        array: *mut cudaArray_t,
+        desc: *const cudaChannelFormatDesc,
+        extent: cudaExtent,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data between host and device"]
+    #[doc = ""]
+    #[doc = " Copies \\p count bytes from the memory area pointed to by \\p src to the"]
+    #[doc = " memory area pointed to by \\p dst, where \\p kind specifies the direction"]
+    #[doc = " of the copy, and must be one of ::cudaMemcpyHostToHost,"]
+    #[doc = " ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing. Calling"]
+    #[doc = " ::cudaMemcpy() with dst and src pointers that do not match the direction of"]
+    #[doc = " the copy results in an undefined behavior."]
+    #[doc = ""]
+    #[doc = " \\param dst   - Destination memory address"]
+    #[doc = " \\param src   - Source memory address"]
+    #[doc = " \\param count - Size in bytes to copy"]
+    #[doc = " \\param kind  - Type of transfer"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\note_sync"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
+    #[doc = " ::cuMemcpyDtoH,"]
+    #[doc = " ::cuMemcpyHtoD,"]
+    #[doc = " ::cuMemcpyDtoD,"]
+    #[doc = " ::cuMemcpy"]
+    pub fn cudaMemcpy(
+        dst: *mut ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+        count: usize,
+        kind: cudaMemcpyKind,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies memory between two devices"]
+    #[doc = ""]
+    #[doc = " Copies memory from one device to memory on another device.  \\p dst is the"]
+    #[doc = " base device pointer of the destination memory and \\p dstDevice is the"]
+    #[doc = " destination device.  \\p src is the base device pointer of the source memory"]
+    #[doc = " and \\p srcDevice is the source device.  \\p count specifies the number of bytes"]
+    #[doc = " to copy."]
+    #[doc = ""]
+    #[doc = " Note that this function is asynchronous with respect to the host, but"]
+    #[doc = " serialized with respect all pending and future asynchronous work in to the"]
+    #[doc = " current device, \\p srcDevice, and \\p dstDevice (use ::cudaMemcpyPeerAsync"]
+    #[doc = " to avoid this synchronization)."]
+    #[doc = ""]
+    #[doc = " \\param dst       - Destination device pointer"]
+    #[doc = " \\param dstDevice - Destination device"]
+    #[doc = " \\param src       - Source device pointer"]
+    #[doc = " \\param srcDevice - Source device"]
+    #[doc = " \\param count     - Size of memory copy in bytes"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_sync"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyAsync, ::cudaMemcpyPeerAsync,"]
+    #[doc = " ::cudaMemcpy3DPeerAsync,"]
+    #[doc = " ::cuMemcpyPeer"]
+    pub fn cudaMemcpyPeer(
+        dst: *mut ::std::os::raw::c_void,
+        dstDevice: ::std::os::raw::c_int,
+        src: *const ::std::os::raw::c_void,
+        srcDevice: ::std::os::raw::c_int,
+        count: usize,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data between host and device"]
+    #[doc = ""]
+    #[doc = " Copies a matrix (\\p height rows of \\p width bytes each) from the memory"]
+    #[doc = " area pointed to by \\p src to the memory area pointed to by \\p dst, where"]
+    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
+    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing. \\p dpitch and"]
+    #[doc = " \\p spitch are the widths in memory in bytes of the 2D arrays pointed to by"]
+    #[doc = " \\p dst and \\p src, including any padding added to the end of each row. The"]
+    #[doc = " memory areas may not overlap. \\p width must not exceed either \\p dpitch or"]
+    #[doc = " \\p spitch. Calling ::cudaMemcpy2D() with \\p dst and \\p src pointers that do"]
+    #[doc = " not match the direction of the copy results in an undefined behavior."]
+    #[doc = " ::cudaMemcpy2D() returns an error if \\p dpitch or \\p spitch exceeds"]
+    #[doc = " the maximum allowed."]
+    #[doc = ""]
+    #[doc = " \\param dst    - Destination memory address"]
+    #[doc = " \\param dpitch - Pitch of destination memory"]
+    #[doc = " \\param src    - Source memory address"]
+    #[doc = " \\param spitch - Pitch of source memory"]
+    #[doc = " \\param width  - Width of matrix transfer (columns in bytes)"]
+    #[doc = " \\param height - Height of matrix transfer (rows)"]
+    #[doc = " \\param kind   - Type of transfer"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidPitchValue,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
+    #[doc = " ::cuMemcpy2D,"]
+    #[doc = " ::cuMemcpy2DUnaligned"]
+    pub fn cudaMemcpy2D(
+        dst: *mut ::std::os::raw::c_void,
+        dpitch: usize,
+        src: *const ::std::os::raw::c_void,
+        spitch: usize,
+        width: usize,
+        height: usize,
+        kind: cudaMemcpyKind,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data between host and device"]
+    #[doc = ""]
+    #[doc = " Copies a matrix (\\p height rows of \\p width bytes each) from the memory"]
+    #[doc = " area pointed to by \\p src to the CUDA array \\p dst starting at the"]
+    #[doc = " upper left corner (\\p wOffset, \\p hOffset) where \\p kind specifies the"]
+    #[doc = " direction of the copy, and must be one of ::cudaMemcpyHostToHost,"]
+    #[doc = " ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = " \\p spitch is the width in memory in bytes of the 2D array pointed to by"]
+    #[doc = " \\p src, including any padding added to the end of each row. \\p wOffset +"]
+    #[doc = " \\p width must not exceed the width of the CUDA array \\p dst. \\p width must"]
+    #[doc = " not exceed \\p spitch. ::cudaMemcpy2DToArray() returns an error if \\p spitch"]
+    #[doc = " exceeds the maximum allowed."]
+    #[doc = ""]
+    #[doc = " \\param dst     - Destination memory address"]
+    #[doc = " \\param wOffset - Destination starting X offset"]
+    #[doc = " \\param hOffset - Destination starting Y offset"]
+    #[doc = " \\param src     - Source memory address"]
+    #[doc = " \\param spitch  - Pitch of source memory"]
+    #[doc = " \\param width   - Width of matrix transfer (columns in bytes)"]
+    #[doc = " \\param height  - Height of matrix transfer (rows)"]
+    #[doc = " \\param kind    - Type of transfer"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidDevicePointer,"]
+    #[doc = " ::cudaErrorInvalidPitchValue,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_sync"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
+    #[doc = " ::cuMemcpy2D,"]
+    #[doc = " ::cuMemcpy2DUnaligned"]
+    pub fn cudaMemcpy2DToArray(
+        dst: cudaArray_t,
+        wOffset: usize,
+        hOffset: usize,
+        src: *const ::std::os::raw::c_void,
+        spitch: usize,
+        width: usize,
+        height: usize,
+        kind: cudaMemcpyKind,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data between host and device"]
+    #[doc = ""]
+    #[doc = " Copies a matrix (\\p height rows of \\p width bytes each) from the CUDA"]
+    #[doc = " array \\p srcArray starting at the upper left corner"]
+    #[doc = " (\\p wOffset, \\p hOffset) to the memory area pointed to by \\p dst, where"]
+    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
+    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing. \\p dpitch is the"]
+    #[doc = " width in memory in bytes of the 2D array pointed to by \\p dst, including any"]
+    #[doc = " padding added to the end of each row. \\p wOffset + \\p width must not exceed"]
+    #[doc = " the width of the CUDA array \\p src. \\p width must not exceed \\p dpitch."]
+    #[doc = " ::cudaMemcpy2DFromArray() returns an error if \\p dpitch exceeds the maximum"]
+    #[doc = " allowed."]
+    #[doc = ""]
+    #[doc = " \\param dst     - Destination memory address"]
+    #[doc = " \\param dpitch  - Pitch of destination memory"]
+    #[doc = " \\param src     - Source memory address"]
+    #[doc = " \\param wOffset - Source starting X offset"]
+    #[doc = " \\param hOffset - Source starting Y offset"]
+    #[doc = " \\param width   - Width of matrix transfer (columns in bytes)"]
+    #[doc = " \\param height  - Height of matrix transfer (rows)"]
+    #[doc = " \\param kind    - Type of transfer"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidDevicePointer,"]
+    #[doc = " ::cudaErrorInvalidPitchValue,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_sync"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray, ::cudaMemcpy2DToArray,"]
+    #[doc = " ::cudaMemcpyFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
+    #[doc = " ::cuMemcpy2D,"]
+    #[doc = " ::cuMemcpy2DUnaligned"]
+    pub fn cudaMemcpy2DFromArray(
+        dst: *mut ::std::os::raw::c_void,
+        dpitch: usize,
+        src: cudaArray_const_t,
+        wOffset: usize,
+        hOffset: usize,
+        width: usize,
+        height: usize,
+        kind: cudaMemcpyKind,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data to the given symbol on the device"]
+    #[doc = ""]
+    #[doc = " Copies \\p count bytes from the memory area pointed to by \\p src"]
+    #[doc = " to the memory area pointed to by \\p offset bytes from the start of symbol"]
+    #[doc = " \\p symbol. The memory areas may not overlap. \\p symbol is a variable that"]
+    #[doc = " resides in global or constant memory space."]
+    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
+    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = ""]
+    #[doc = " \\param symbol - Device symbol address"]
+    #[doc = " \\param src    - Source memory address"]
+    #[doc = " \\param count  - Size in bytes to copy"]
+    #[doc = " \\param offset - Offset from start of symbol in bytes"]
+    #[doc = " \\param kind   - Type of transfer"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidSymbol,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection,"]
+    #[doc = " ::cudaErrorNoKernelImageForDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_sync"]
+    #[doc = " \\note_string_api_deprecation"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync"]
+    pub fn cudaMemcpyToSymbol(
+        symbol: *const ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+        count: usize,
+        offset: usize,
+        kind: cudaMemcpyKind,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data from the given symbol on the device"]
+    #[doc = ""]
+    #[doc = " Copies \\p count bytes from the memory area pointed to by \\p offset bytes"]
+    #[doc = " from the start of symbol \\p symbol to the memory area pointed to by \\p dst."]
+    #[doc = " The memory areas may not overlap. \\p symbol is a variable that"]
+    #[doc = " resides in global or constant memory space."]
+    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
+    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = ""]
+    #[doc = " \\param dst    - Destination memory address"]
+    #[doc = " \\param symbol - Device symbol address"]
+    #[doc = " \\param count  - Size in bytes to copy"]
+    #[doc = " \\param offset - Offset from start of symbol in bytes"]
+    #[doc = " \\param kind   - Type of transfer"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidSymbol,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection,"]
+    #[doc = " ::cudaErrorNoKernelImageForDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_sync"]
+    #[doc = " \\note_string_api_deprecation"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync"]
+    pub fn cudaMemcpyFromSymbol(
+        dst: *mut ::std::os::raw::c_void,
+        symbol: *const ::std::os::raw::c_void,
+        count: usize,
+        offset: usize,
+        kind: cudaMemcpyKind,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data between host and device"]
+    #[doc = ""]
+    #[doc = " Copies \\p count bytes from the memory area pointed to by \\p src to the"]
+    #[doc = " memory area pointed to by \\p dst, where \\p kind specifies the"]
+    #[doc = " direction of the copy, and must be one of ::cudaMemcpyHostToHost,"]
+    #[doc = " ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = ""]
+    #[doc = " The memory areas may not overlap. Calling ::cudaMemcpyAsync() with \\p dst and"]
+    #[doc = " \\p src pointers that do not match the direction of the copy results in an"]
+    #[doc = " undefined behavior."]
+    #[doc = ""]
+    #[doc = " ::cudaMemcpyAsync() is asynchronous with respect to the host, so the call"]
+    #[doc = " may return before the copy is complete. The copy can optionally be"]
+    #[doc = " associated to a stream by passing a non-zero \\p stream argument. If \\p kind"]
+    #[doc = " is ::cudaMemcpyHostToDevice or ::cudaMemcpyDeviceToHost and the \\p stream is"]
+    #[doc = " non-zero, the copy may overlap with operations in other streams."]
+    #[doc = ""]
+    #[doc = " The device version of this function only handles device to device copies and"]
+    #[doc = " cannot be given local or shared pointers."]
+    #[doc = ""]
+    #[doc = " \\param dst    - Destination memory address"]
+    #[doc = " \\param src    - Source memory address"]
+    #[doc = " \\param count  - Size in bytes to copy"]
+    #[doc = " \\param kind   - Type of transfer"]
+    #[doc = " \\param stream - Stream identifier"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync"]
+    #[doc = " ::cuMemcpyAsync,"]
+    #[doc = " ::cuMemcpyDtoHAsync,"]
+    #[doc = " ::cuMemcpyHtoDAsync,"]
+    #[doc = " ::cuMemcpyDtoDAsync"]
+    pub fn cudaMemcpyAsync(
+        dst: *mut ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+        count: usize,
+        kind: cudaMemcpyKind,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies memory between two devices asynchronously."]
+    #[doc = ""]
+    #[doc = " Copies memory from one device to memory on another device.  \\p dst is the"]
+    #[doc = " base device pointer of the destination memory and \\p dstDevice is the"]
+    #[doc = " destination device.  \\p src is the base device pointer of the source memory"]
+    #[doc = " and \\p srcDevice is the source device.  \\p count specifies the number of bytes"]
+    #[doc = " to copy."]
+    #[doc = ""]
+    #[doc = " Note that this function is asynchronous with respect to the host and all work"]
+    #[doc = " on other devices."]
+    #[doc = ""]
+    #[doc = " \\param dst       - Destination device pointer"]
+    #[doc = " \\param dstDevice - Destination device"]
+    #[doc = " \\param src       - Source device pointer"]
+    #[doc = " \\param srcDevice - Source device"]
+    #[doc = " \\param count     - Size of memory copy in bytes"]
+    #[doc = " \\param stream    - Stream identifier"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyPeer, ::cudaMemcpyAsync,"]
+    #[doc = " ::cudaMemcpy3DPeerAsync,"]
+    #[doc = " ::cuMemcpyPeerAsync"]
+    pub fn cudaMemcpyPeerAsync(
+        dst: *mut ::std::os::raw::c_void,
+        dstDevice: ::std::os::raw::c_int,
+        src: *const ::std::os::raw::c_void,
+        srcDevice: ::std::os::raw::c_int,
+        count: usize,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data between host and device"]
+    #[doc = ""]
+    #[doc = " Copies a matrix (\\p height rows of \\p width bytes each) from the memory"]
+    #[doc = " area pointed to by \\p src to the memory area pointed to by \\p dst, where"]
+    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
+    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = " \\p dpitch and \\p spitch are the widths in memory in bytes of the 2D arrays"]
+    #[doc = " pointed to by \\p dst and \\p src, including any padding added to the end of"]
+    #[doc = " each row. The memory areas may not overlap. \\p width must not exceed either"]
+    #[doc = " \\p dpitch or \\p spitch."]
+    #[doc = ""]
+    #[doc = " Calling ::cudaMemcpy2DAsync() with \\p dst and \\p src pointers that do not"]
+    #[doc = " match the direction of the copy results in an undefined behavior."]
+    #[doc = " ::cudaMemcpy2DAsync() returns an error if \\p dpitch or \\p spitch is greater"]
+    #[doc = " than the maximum allowed."]
+    #[doc = ""]
+    #[doc = " ::cudaMemcpy2DAsync() is asynchronous with respect to the host, so"]
+    #[doc = " the call may return before the copy is complete. The copy can optionally"]
+    #[doc = " be associated to a stream by passing a non-zero \\p stream argument. If"]
+    #[doc = " \\p kind is ::cudaMemcpyHostToDevice or ::cudaMemcpyDeviceToHost and"]
+    #[doc = " \\p stream is non-zero, the copy may overlap with operations in other"]
+    #[doc = " streams."]
+    #[doc = ""]
+    #[doc = " The device version of this function only handles device to device copies and"]
+    #[doc = " cannot be given local or shared pointers."]
+    #[doc = ""]
+    #[doc = " \\param dst    - Destination memory address"]
+    #[doc = " \\param dpitch - Pitch of destination memory"]
+    #[doc = " \\param src    - Source memory address"]
+    #[doc = " \\param spitch - Pitch of source memory"]
+    #[doc = " \\param width  - Width of matrix transfer (columns in bytes)"]
+    #[doc = " \\param height - Height of matrix transfer (rows)"]
+    #[doc = " \\param kind   - Type of transfer"]
+    #[doc = " \\param stream - Stream identifier"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidPitchValue,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
+    #[doc = " ::cuMemcpy2DAsync"]
+    pub fn cudaMemcpy2DAsync(
+        dst: *mut ::std::os::raw::c_void,
+        dpitch: usize,
+        src: *const ::std::os::raw::c_void,
+        spitch: usize,
+        width: usize,
+        height: usize,
+        kind: cudaMemcpyKind,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data between host and device"]
+    #[doc = ""]
+    #[doc = " Copies a matrix (\\p height rows of \\p width bytes each) from the memory"]
+    #[doc = " area pointed to by \\p src to the CUDA array \\p dst starting at the"]
+    #[doc = " upper left corner (\\p wOffset, \\p hOffset) where \\p kind specifies the"]
+    #[doc = " direction of the copy, and must be one of ::cudaMemcpyHostToHost,"]
+    #[doc = " ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = " \\p spitch is the width in memory in bytes of the 2D array pointed to by"]
+    #[doc = " \\p src, including any padding added to the end of each row. \\p wOffset +"]
+    #[doc = " \\p width must not exceed the width of the CUDA array \\p dst. \\p width must"]
+    #[doc = " not exceed \\p spitch. ::cudaMemcpy2DToArrayAsync() returns an error if"]
+    #[doc = " \\p spitch exceeds the maximum allowed."]
+    #[doc = ""]
+    #[doc = " ::cudaMemcpy2DToArrayAsync() is asynchronous with respect to the host, so"]
+    #[doc = " the call may return before the copy is complete. The copy can optionally"]
+    #[doc = " be associated to a stream by passing a non-zero \\p stream argument. If"]
+    #[doc = " \\p kind is ::cudaMemcpyHostToDevice or ::cudaMemcpyDeviceToHost and"]
+    #[doc = " \\p stream is non-zero, the copy may overlap with operations in other"]
+    #[doc = " streams."]
+    #[doc = ""]
+    #[doc = " \\param dst     - Destination memory address"]
+    #[doc = " \\param wOffset - Destination starting X offset"]
+    #[doc = " \\param hOffset - Destination starting Y offset"]
+    #[doc = " \\param src     - Source memory address"]
+    #[doc = " \\param spitch  - Pitch of source memory"]
+    #[doc = " \\param width   - Width of matrix transfer (columns in bytes)"]
+    #[doc = " \\param height  - Height of matrix transfer (rows)"]
+    #[doc = " \\param kind    - Type of transfer"]
+    #[doc = " \\param stream  - Stream identifier"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidDevicePointer,"]
+    #[doc = " ::cudaErrorInvalidPitchValue,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray, ::cudaMemcpy2DToArray,"]
+    #[doc = " ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
+    #[doc = " ::cuMemcpy2DAsync"]
+    pub fn cudaMemcpy2DToArrayAsync(
+        dst: cudaArray_t,
+        wOffset: usize,
+        hOffset: usize,
+        src: *const ::std::os::raw::c_void,
+        spitch: usize,
+        width: usize,
+        height: usize,
+        kind: cudaMemcpyKind,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data between host and device"]
+    #[doc = ""]
+    #[doc = " Copies a matrix (\\p height rows of \\p width bytes each) from the CUDA"]
+    #[doc = " array \\p srcArray starting at the upper left corner"]
+    #[doc = " (\\p wOffset, \\p hOffset) to the memory area pointed to by \\p dst, where"]
+    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
+    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = " \\p dpitch is the width in memory in bytes of the 2D"]
+    #[doc = " array pointed to by \\p dst, including any padding added to the end of each"]
+    #[doc = " row. \\p wOffset + \\p width must not exceed the width of the CUDA array"]
+    #[doc = " \\p src. \\p width must not exceed \\p dpitch. ::cudaMemcpy2DFromArrayAsync()"]
+    #[doc = " returns an error if \\p dpitch exceeds the maximum allowed."]
+    #[doc = ""]
+    #[doc = " ::cudaMemcpy2DFromArrayAsync() is asynchronous with respect to the host, so"]
+    #[doc = " the call may return before the copy is complete. The copy can optionally be"]
+    #[doc = " associated to a stream by passing a non-zero \\p stream argument. If \\p kind"]
+    #[doc = " is ::cudaMemcpyHostToDevice or ::cudaMemcpyDeviceToHost and \\p stream is"]
+    #[doc = " non-zero, the copy may overlap with operations in other streams."]
+    #[doc = ""]
+    #[doc = " \\param dst     - Destination memory address"]
+    #[doc = " \\param dpitch  - Pitch of destination memory"]
+    #[doc = " \\param src     - Source memory address"]
+    #[doc = " \\param wOffset - Source starting X offset"]
+    #[doc = " \\param hOffset - Source starting Y offset"]
+    #[doc = " \\param width   - Width of matrix transfer (columns in bytes)"]
+    #[doc = " \\param height  - Height of matrix transfer (rows)"]
+    #[doc = " \\param kind    - Type of transfer"]
+    #[doc = " \\param stream  - Stream identifier"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidDevicePointer,"]
+    #[doc = " ::cudaErrorInvalidPitchValue,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray, ::cudaMemcpy2DToArray,"]
+    #[doc = " ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
+    #[doc = " ::cuMemcpy2DAsync"]
+    pub fn cudaMemcpy2DFromArrayAsync(
+        dst: *mut ::std::os::raw::c_void,
+        dpitch: usize,
+        src: cudaArray_const_t,
+        wOffset: usize,
+        hOffset: usize,
+        width: usize,
+        height: usize,
+        kind: cudaMemcpyKind,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data to the given symbol on the device"]
+    #[doc = ""]
+    #[doc = " Copies \\p count bytes from the memory area pointed to by \\p src"]
+    #[doc = " to the memory area pointed to by \\p offset bytes from the start of symbol"]
+    #[doc = " \\p symbol. The memory areas may not overlap. \\p symbol is a variable that"]
+    #[doc = " resides in global or constant memory space."]
+    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
+    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = ""]
+    #[doc = " ::cudaMemcpyToSymbolAsync() is asynchronous with respect to the host, so"]
+    #[doc = " the call may return before the copy is complete. The copy can optionally"]
+    #[doc = " be associated to a stream by passing a non-zero \\p stream argument. If"]
+    #[doc = " \\p kind is ::cudaMemcpyHostToDevice and \\p stream is non-zero, the copy"]
+    #[doc = " may overlap with operations in other streams."]
+    #[doc = ""]
+    #[doc = " \\param symbol - Device symbol address"]
+    #[doc = " \\param src    - Source memory address"]
+    #[doc = " \\param count  - Size in bytes to copy"]
+    #[doc = " \\param offset - Offset from start of symbol in bytes"]
+    #[doc = " \\param kind   - Type of transfer"]
+    #[doc = " \\param stream - Stream identifier"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidSymbol,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection,"]
+    #[doc = " ::cudaErrorNoKernelImageForDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\note_string_api_deprecation"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync"]
+    pub fn cudaMemcpyToSymbolAsync(
+        symbol: *const ::std::os::raw::c_void,
+        src: *const ::std::os::raw::c_void,
+        count: usize,
+        offset: usize,
+        kind: cudaMemcpyKind,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Copies data from the given symbol on the device"]
+    #[doc = ""]
+    #[doc = " Copies \\p count bytes from the memory area pointed to by \\p offset bytes"]
+    #[doc = " from the start of symbol \\p symbol to the memory area pointed to by \\p dst."]
+    #[doc = " The memory areas may not overlap. \\p symbol is a variable that resides in"]
+    #[doc = " global or constant memory space."]
+    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
+    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
+    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
+    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
+    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
+    #[doc = " allowed on systems that support unified virtual addressing."]
+    #[doc = ""]
+    #[doc = " ::cudaMemcpyFromSymbolAsync() is asynchronous with respect to the host, so"]
+    #[doc = " the call may return before the copy is complete. The copy can optionally be"]
+    #[doc = " associated to a stream by passing a non-zero \\p stream argument. If \\p kind"]
+    #[doc = " is ::cudaMemcpyDeviceToHost and \\p stream is non-zero, the copy may overlap"]
+    #[doc = " with operations in other streams."]
+    #[doc = ""]
+    #[doc = " \\param dst    - Destination memory address"]
+    #[doc = " \\param symbol - Device symbol address"]
+    #[doc = " \\param count  - Size in bytes to copy"]
+    #[doc = " \\param offset - Offset from start of symbol in bytes"]
+    #[doc = " \\param kind   - Type of transfer"]
+    #[doc = " \\param stream - Stream identifier"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidSymbol,"]
+    #[doc = " ::cudaErrorInvalidMemcpyDirection,"]
+    #[doc = " ::cudaErrorNoKernelImageForDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\note_string_api_deprecation"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
+    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
+    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
+    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync, ::cudaMemcpy2DAsync,"]
+    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
+    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
+    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync"]
+    pub fn cudaMemcpyFromSymbolAsync(
+        dst: *mut ::std::os::raw::c_void,
+        symbol: *const ::std::os::raw::c_void,
+        count: usize,
+        offset: usize,
+        kind: cudaMemcpyKind,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Initializes or sets device memory to a value"]
+    #[doc = ""]
+    #[doc = " Fills the first \\p count bytes of the memory area pointed to by \\p devPtr"]
+    #[doc = " with the constant byte value \\p value."]
+    #[doc = ""]
+    #[doc = " Note that this function is asynchronous with respect to the host unless"]
+    #[doc = " \\p devPtr refers to pinned host memory."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Pointer to device memory"]
+    #[doc = " \\param value  - Value to set for each byte of specified memory"]
+    #[doc = " \\param count  - Size in bytes to set"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_memset"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cuMemsetD8,"]
+    #[doc = " ::cuMemsetD16,"]
+    #[doc = " ::cuMemsetD32"]
+    pub fn cudaMemset(
+        devPtr: *mut ::std::os::raw::c_void,
+        value: ::std::os::raw::c_int,
+        count: usize,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Initializes or sets device memory to a value"]
+    #[doc = ""]
+    #[doc = " Fills the first \\p count bytes of the memory area pointed to by \\p devPtr"]
+    #[doc = " with the constant byte value \\p value."]
+    #[doc = ""]
+    #[doc = " ::cudaMemsetAsync() is asynchronous with respect to the host, so"]
+    #[doc = " the call may return before the memset is complete. The operation can optionally"]
+    #[doc = " be associated to a stream by passing a non-zero \\p stream argument."]
+    #[doc = " If \\p stream is non-zero, the operation may overlap with operations in other streams."]
+    #[doc = ""]
+    #[doc = " The device version of this function only handles device to device copies and"]
+    #[doc = " cannot be given local or shared pointers."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Pointer to device memory"]
+    #[doc = " \\param value  - Value to set for each byte of specified memory"]
+    #[doc = " \\param count  - Size in bytes to set"]
+    #[doc = " \\param stream - Stream identifier"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_memset"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemset, ::cudaMemset2D, ::cudaMemset3D,"]
+    #[doc = " ::cudaMemset2DAsync, ::cudaMemset3DAsync,"]
+    #[doc = " ::cuMemsetD8Async,"]
+    #[doc = " ::cuMemsetD16Async,"]
+    #[doc = " ::cuMemsetD32Async"]
+    pub fn cudaMemsetAsync(
+        devPtr: *mut ::std::os::raw::c_void,
+        value: ::std::os::raw::c_int,
+        count: usize,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Finds the address associated with a CUDA symbol"]
+    #[doc = ""]
+    #[doc = " Returns in \\p *devPtr the address of symbol \\p symbol on the device."]
+    #[doc = " \\p symbol is a variable that resides in global or constant memory space."]
+    #[doc = " If \\p symbol cannot be found, or if \\p symbol is not declared in the"]
+    #[doc = " global or constant memory space, \\p *devPtr is unchanged and the error"]
+    #[doc = " ::cudaErrorInvalidSymbol is returned."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Return device pointer associated with symbol"]
+    #[doc = " \\param symbol - Device symbol address"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidSymbol,"]
+    #[doc = " ::cudaErrorNoKernelImageForDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_string_api_deprecation"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " \\ref ::cudaGetSymbolAddress(void**, const T&) \"cudaGetSymbolAddress (C++ API)\","]
+    #[doc = " \\ref ::cudaGetSymbolSize(size_t*, const void*) \"cudaGetSymbolSize (C API)\","]
+    #[doc = " ::cuModuleGetGlobal"]
+    pub fn cudaGetSymbolAddress(
+        devPtr: *mut *mut ::std::os::raw::c_void,
+        symbol: *const ::std::os::raw::c_void,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Finds the size of the object associated with a CUDA symbol"]
+    #[doc = ""]
+    #[doc = " Returns in \\p *size the size of symbol \\p symbol. \\p symbol is a variable that"]
+    #[doc = " resides in global or constant memory space. If \\p symbol cannot be found, or"]
+    #[doc = " if \\p symbol is not declared in global or constant memory space, \\p *size is"]
+    #[doc = " unchanged and the error ::cudaErrorInvalidSymbol is returned."]
+    #[doc = ""]
+    #[doc = " \\param size   - Size of object associated with symbol"]
+    #[doc = " \\param symbol - Device symbol address"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidSymbol,"]
+    #[doc = " ::cudaErrorNoKernelImageForDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_string_api_deprecation"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " \\ref ::cudaGetSymbolAddress(void**, const void*) \"cudaGetSymbolAddress (C API)\","]
+    #[doc = " \\ref ::cudaGetSymbolSize(size_t*, const T&) \"cudaGetSymbolSize (C++ API)\","]
+    #[doc = " ::cuModuleGetGlobal"]
+    pub fn cudaGetSymbolSize(
+        size: *mut usize,
+        symbol: *const ::std::os::raw::c_void,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Prefetches memory to the specified destination device"]
+    #[doc = ""]
+    #[doc = " Prefetches memory to the specified destination device.  \\p devPtr is the"]
+    #[doc = " base device pointer of the memory to be prefetched and \\p dstDevice is the"]
+    #[doc = " destination device. \\p count specifies the number of bytes to copy. \\p stream"]
+    #[doc = " is the stream in which the operation is enqueued. The memory range must refer"]
+    #[doc = " to managed memory allocated via ::cudaMallocManaged or declared via __managed__ variables."]
+    #[doc = ""]
+    #[doc = " Passing in cudaCpuDeviceId for \\p dstDevice will prefetch the data to host memory. If"]
+    #[doc = " \\p dstDevice is a GPU, then the device attribute ::cudaDevAttrConcurrentManagedAccess"]
+    #[doc = " must be non-zero. Additionally, \\p stream must be associated with a device that has a"]
+    #[doc = " non-zero value for the device attribute ::cudaDevAttrConcurrentManagedAccess."]
+    #[doc = ""]
+    #[doc = " The start address and end address of the memory range will be rounded down and rounded up"]
+    #[doc = " respectively to be aligned to CPU page size before the prefetch operation is enqueued"]
+    #[doc = " in the stream."]
+    #[doc = ""]
+    #[doc = " If no physical memory has been allocated for this region, then this memory region"]
+    #[doc = " will be populated and mapped on the destination device. If there\'s insufficient"]
+    #[doc = " memory to prefetch the desired region, the Unified Memory driver may evict pages from other"]
+    #[doc = " ::cudaMallocManaged allocations to host memory in order to make room. Device memory"]
+    #[doc = " allocated using ::cudaMalloc or ::cudaMallocArray will not be evicted."]
+    #[doc = ""]
+    #[doc = " By default, any mappings to the previous location of the migrated pages are removed and"]
+    #[doc = " mappings for the new location are only setup on \\p dstDevice. The exact behavior however"]
+    #[doc = " also depends on the settings applied to this memory range via ::cudaMemAdvise as described"]
+    #[doc = " below:"]
+    #[doc = ""]
+    #[doc = " If ::cudaMemAdviseSetReadMostly was set on any subset of this memory range,"]
+    #[doc = " then that subset will create a read-only copy of the pages on \\p dstDevice."]
+    #[doc = ""]
+    #[doc = " If ::cudaMemAdviseSetPreferredLocation was called on any subset of this memory"]
+    #[doc = " range, then the pages will be migrated to \\p dstDevice even if \\p dstDevice is not the"]
+    #[doc = " preferred location of any pages in the memory range."]
+    #[doc = ""]
+    #[doc = " If ::cudaMemAdviseSetAccessedBy was called on any subset of this memory range,"]
+    #[doc = " then mappings to those pages from all the appropriate processors are updated to"]
+    #[doc = " refer to the new location if establishing such a mapping is possible. Otherwise,"]
+    #[doc = " those mappings are cleared."]
+    #[doc = ""]
+    #[doc = " Note that this API is not required for functionality and only serves to improve performance"]
+    #[doc = " by allowing the application to migrate data to a suitable location before it is accessed."]
+    #[doc = " Memory accesses to this range are always coherent and are allowed even when the data is"]
+    #[doc = " actively being migrated."]
+    #[doc = ""]
+    #[doc = " Note that this function is asynchronous with respect to the host and all work"]
+    #[doc = " on other devices."]
+    #[doc = ""]
+    #[doc = " \\param devPtr    - Pointer to be prefetched"]
+    #[doc = " \\param count     - Size in bytes"]
+    #[doc = " \\param dstDevice - Destination device to prefetch to"]
+    #[doc = " \\param stream    - Stream to enqueue prefetch operation"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyPeer, ::cudaMemcpyAsync,"]
+    #[doc = " ::cudaMemcpy3DPeerAsync, ::cudaMemAdvise,"]
+    #[doc = " ::cuMemPrefetchAsync"]
+    pub fn cudaMemPrefetchAsync(
+        devPtr: *const ::std::os::raw::c_void,
+        count: usize,
+        dstDevice: ::std::os::raw::c_int,
+        stream: cudaStream_t,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Advise about the usage of a given memory range"]
+    #[doc = ""]
+    #[doc = " Advise the Unified Memory subsystem about the usage pattern for the memory range"]
+    #[doc = " starting at \\p devPtr with a size of \\p count bytes. The start address and end address of the memory"]
+    #[doc = " range will be rounded down and rounded up respectively to be aligned to CPU page size before the"]
+    #[doc = " advice is applied. The memory range must refer to managed memory allocated via ::cudaMallocManaged"]
+    #[doc = " or declared via __managed__ variables. The memory range could also refer to system-allocated pageable"]
+    #[doc = " memory provided it represents a valid, host-accessible region of memory and all additional constraints"]
+    #[doc = " imposed by \\p advice as outlined below are also satisfied. Specifying an invalid system-allocated pageable"]
+    #[doc = " memory range results in an error being returned."]
+    #[doc = ""]
+    #[doc = " The \\p advice parameter can take the following values:"]
+    #[doc = " - ::cudaMemAdviseSetReadMostly: This implies that the data is mostly going to be read"]
+    #[doc = " from and only occasionally written to. Any read accesses from any processor to this region will create a"]
+    #[doc = " read-only copy of at least the accessed pages in that processor\'s memory. Additionally, if ::cudaMemPrefetchAsync"]
+    #[doc = " is called on this region, it will create a read-only copy of the data on the destination processor."]
+    #[doc = " If any processor writes to this region, all copies of the corresponding page will be invalidated"]
+    #[doc = " except for the one where the write occurred. The \\p device argument is ignored for this advice."]
+    #[doc = " Note that for a page to be read-duplicated, the accessing processor must either be the CPU or a GPU"]
+    #[doc = " that has a non-zero value for the device attribute ::cudaDevAttrConcurrentManagedAccess."]
+    #[doc = " Also, if a context is created on a device that does not have the device attribute"]
+    #[doc = " ::cudaDevAttrConcurrentManagedAccess set, then read-duplication will not occur until"]
+    #[doc = " all such contexts are destroyed."]
+    #[doc = " If the memory region refers to valid system-allocated pageable memory, then the accessing device must"]
+    #[doc = " have a non-zero value for the device attribute ::cudaDevAttrPageableMemoryAccess for a read-only"]
+    #[doc = " copy to be created on that device. Note however that if the accessing device also has a non-zero value for the"]
+    #[doc = " device attribute ::cudaDevAttrPageableMemoryAccessUsesHostPageTables, then setting this advice"]
+    #[doc = " will not create a read-only copy when that device accesses this memory region."]
+    #[doc = ""]
+    #[doc = " - ::cudaMemAdviceUnsetReadMostly: Undoes the effect of ::cudaMemAdviceReadMostly and also prevents the"]
+    #[doc = " Unified Memory driver from attempting heuristic read-duplication on the memory range. Any read-duplicated"]
+    #[doc = " copies of the data will be collapsed into a single copy. The location for the collapsed"]
+    #[doc = " copy will be the preferred location if the page has a preferred location and one of the read-duplicated"]
+    #[doc = " copies was resident at that location. Otherwise, the location chosen is arbitrary."]
+    #[doc = ""]
+    #[doc = " - ::cudaMemAdviseSetPreferredLocation: This advice sets the preferred location for the"]
+    #[doc = " data to be the memory belonging to \\p device. Passing in cudaCpuDeviceId for \\p device sets the"]
+    #[doc = " preferred location as host memory. If \\p device is a GPU, then it must have a non-zero value for the"]
+    #[doc = " device attribute ::cudaDevAttrConcurrentManagedAccess. Setting the preferred location"]
+    #[doc = " does not cause data to migrate to that location immediately. Instead, it guides the migration policy"]
+    #[doc = " when a fault occurs on that memory region. If the data is already in its preferred location and the"]
+    #[doc = " faulting processor can establish a mapping without requiring the data to be migrated, then"]
+    #[doc = " data migration will be avoided. On the other hand, if the data is not in its preferred location"]
+    #[doc = " or if a direct mapping cannot be established, then it will be migrated to the processor accessing"]
+    #[doc = " it. It is important to note that setting the preferred location does not prevent data prefetching"]
+    #[doc = " done using ::cudaMemPrefetchAsync."]
+    #[doc = " Having a preferred location can override the page thrash detection and resolution logic in the Unified"]
+    #[doc = " Memory driver. Normally, if a page is detected to be constantly thrashing between for example host and device"]
+    #[doc = " memory, the page may eventually be pinned to host memory by the Unified Memory driver. But"]
+    #[doc = " if the preferred location is set as device memory, then the page will continue to thrash indefinitely."]
+    #[doc = " If ::cudaMemAdviseSetReadMostly is also set on this memory region or any subset of it, then the"]
+    #[doc = " policies associated with that advice will override the policies of this advice, unless read accesses from"]
+    #[doc = " \\p device will not result in a read-only copy being created on that device as outlined in description for"]
+    #[doc = " the advice ::cudaMemAdviseSetReadMostly."]
+    #[doc = " If the memory region refers to valid system-allocated pageable memory, then \\p device must have a non-zero"]
+    #[doc = " value for the device attribute ::cudaDevAttrPageableMemoryAccess. Additionally, if \\p device has"]
+    #[doc = " a non-zero value for the device attribute ::cudaDevAttrPageableMemoryAccessUsesHostPageTables,"]
+    #[doc = " then this call has no effect. Note however that this behavior may change in the future."]
+    #[doc = ""]
+    #[doc = " - ::cudaMemAdviseUnsetPreferredLocation: Undoes the effect of ::cudaMemAdviseSetPreferredLocation"]
+    #[doc = " and changes the preferred location to none."]
+    #[doc = ""]
+    #[doc = " - ::cudaMemAdviseSetAccessedBy: This advice implies that the data will be accessed by \\p device."]
+    #[doc = " Passing in ::cudaCpuDeviceId for \\p device will set the advice for the CPU. If \\p device is a GPU, then"]
+    #[doc = " the device attribute ::cudaDevAttrConcurrentManagedAccess must be non-zero."]
+    #[doc = " This advice does not cause data migration and has no impact on the location of the data per se. Instead,"]
+    #[doc = " it causes the data to always be mapped in the specified processor\'s page tables, as long as the"]
+    #[doc = " location of the data permits a mapping to be established. If the data gets migrated for any reason,"]
+    #[doc = " the mappings are updated accordingly."]
+    #[doc = " This advice is recommended in scenarios where data locality is not important, but avoiding faults is."]
+    #[doc = " Consider for example a system containing multiple GPUs with peer-to-peer access enabled, where the"]
+    #[doc = " data located on one GPU is occasionally accessed by peer GPUs. In such scenarios, migrating data"]
+    #[doc = " over to the other GPUs is not as important because the accesses are infrequent and the overhead of"]
+    #[doc = " migration may be too high. But preventing faults can still help improve performance, and so having"]
+    #[doc = " a mapping set up in advance is useful. Note that on CPU access of this data, the data may be migrated"]
+    #[doc = " to host memory because the CPU typically cannot access device memory directly. Any GPU that had the"]
+    #[doc = " ::cudaMemAdviceSetAccessedBy flag set for this data will now have its mapping updated to point to the"]
+    #[doc = " page in host memory."]
+    #[doc = " If ::cudaMemAdviseSetReadMostly is also set on this memory region or any subset of it, then the"]
+    #[doc = " policies associated with that advice will override the policies of this advice. Additionally, if the"]
+    #[doc = " preferred location of this memory region or any subset of it is also \\p device, then the policies"]
+    #[doc = " associated with ::cudaMemAdviseSetPreferredLocation will override the policies of this advice."]
+    #[doc = " If the memory region refers to valid system-allocated pageable memory, then \\p device must have a non-zero"]
+    #[doc = " value for the device attribute ::cudaDevAttrPageableMemoryAccess. Additionally, if \\p device has"]
+    #[doc = " a non-zero value for the device attribute ::cudaDevAttrPageableMemoryAccessUsesHostPageTables,"]
+    #[doc = " then this call has no effect."]
+    #[doc = ""]
+    #[doc = " - ::cudaMemAdviseUnsetAccessedBy: Undoes the effect of ::cudaMemAdviseSetAccessedBy. Any mappings to"]
+    #[doc = " the data from \\p device may be removed at any time causing accesses to result in non-fatal page faults."]
+    #[doc = " If the memory region refers to valid system-allocated pageable memory, then \\p device must have a non-zero"]
+    #[doc = " value for the device attribute ::cudaDevAttrPageableMemoryAccess. Additionally, if \\p device has"]
+    #[doc = " a non-zero value for the device attribute ::cudaDevAttrPageableMemoryAccessUsesHostPageTables,"]
+    #[doc = " then this call has no effect."]
+    #[doc = ""]
+    #[doc = " \\param devPtr - Pointer to memory to set the advice for"]
+    #[doc = " \\param count  - Size in bytes of the memory range"]
+    #[doc = " \\param advice - Advice to be applied for the specified memory range"]
+    #[doc = " \\param device - Device to apply the advice for"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
     #[doc = " ::cudaErrorInvalidDevice"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_async"]
@@ -2001,783 +3955,1250 @@ extern "C" {
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyPeer, ::cudaMemcpyAsync,"]
-    #[doc = " ::cudaMemcpy3DPeerAsync,"]
-    #[doc = " ::cuMemcpyPeerAsync"]
-    pub fn cudaMemcpyPeerAsync(
-        dst: *mut ::std::os::raw::c_void,
-        dstDevice: ::std::os::raw::c_int,
-        src: *const ::std::os::raw::c_void,
-        srcDevice: ::std::os::raw::c_int,
-        count: usize,
+    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyPeer, ::cudaMemcpyAsync,"]
+    #[doc = " ::cudaMemcpy3DPeerAsync, ::cudaMemPrefetchAsync,"]
+    #[doc = " ::cuMemAdvise"]
+    pub fn cudaMemAdvise(
+        devPtr: *const ::std::os::raw::c_void,
+        count: usize,
+        advice: cudaMemoryAdvise,
+        device: ::std::os::raw::c_int,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Query an attribute of a given memory range"]
+    #[doc = ""]
+    #[doc = " Query an attribute about the memory range starting at \\p devPtr with a size of \\p count bytes. The"]
+    #[doc = " memory range must refer to managed memory allocated via ::cudaMallocManaged or declared via"]
+    #[doc = " __managed__ variables."]
+    #[doc = ""]
+    #[doc = " The \\p attribute parameter can take the following values:"]
+    #[doc = " - ::cudaMemRangeAttributeReadMostly: If this attribute is specified, \\p data will be interpreted"]
+    #[doc = " as a 32-bit integer, and \\p dataSize must be 4. The result returned will be 1 if all pages in the given"]
+    #[doc = " memory range have read-duplication enabled, or 0 otherwise."]
+    #[doc = " - ::cudaMemRangeAttributePreferredLocation: If this attribute is specified, \\p data will be"]
+    #[doc = " interpreted as a 32-bit integer, and \\p dataSize must be 4. The result returned will be a GPU device"]
+    #[doc = " id if all pages in the memory range have that GPU as their preferred location, or it will be cudaCpuDeviceId"]
+    #[doc = " if all pages in the memory range have the CPU as their preferred location, or it will be cudaInvalidDeviceId"]
+    #[doc = " if either all the pages don\'t have the same preferred location or some of the pages don\'t have a"]
+    #[doc = " preferred location at all. Note that the actual location of the pages in the memory range at the time of"]
+    #[doc = " the query may be different from the preferred location."]
+    #[doc = " - ::cudaMemRangeAttributeAccessedBy: If this attribute is specified, \\p data will be interpreted"]
+    #[doc = " as an array of 32-bit integers, and \\p dataSize must be a non-zero multiple of 4. The result returned"]
+    #[doc = " will be a list of device ids that had ::cudaMemAdviceSetAccessedBy set for that entire memory range."]
+    #[doc = " If any device does not have that advice set for the entire memory range, that device will not be included."]
+    #[doc = " If \\p data is larger than the number of devices that have that advice set for that memory range,"]
+    #[doc = " cudaInvalidDeviceId will be returned in all the extra space provided. For ex., if \\p dataSize is 12"]
+    #[doc = " (i.e. \\p data has 3 elements) and only device 0 has the advice set, then the result returned will be"]
+    #[doc = " { 0, cudaInvalidDeviceId, cudaInvalidDeviceId }. If \\p data is smaller than the number of devices that have"]
+    #[doc = " that advice set, then only as many devices will be returned as can fit in the array. There is no"]
+    #[doc = " guarantee on which specific devices will be returned, however."]
+    #[doc = " - ::cudaMemRangeAttributeLastPrefetchLocation: If this attribute is specified, \\p data will be"]
+    #[doc = " interpreted as a 32-bit integer, and \\p dataSize must be 4. The result returned will be the last location"]
+    #[doc = " to which all pages in the memory range were prefetched explicitly via ::cudaMemPrefetchAsync. This will either be"]
+    #[doc = " a GPU id or cudaCpuDeviceId depending on whether the last location for prefetch was a GPU or the CPU"]
+    #[doc = " respectively. If any page in the memory range was never explicitly prefetched or if all pages were not"]
+    #[doc = " prefetched to the same location, cudaInvalidDeviceId will be returned. Note that this simply returns the"]
+    #[doc = " last location that the applicaton requested to prefetch the memory range to. It gives no indication as to"]
+    #[doc = " whether the prefetch operation to that location has completed or even begun."]
+    #[doc = ""]
+    #[doc = " \\param data      - A pointers to a memory location where the result"]
+    #[doc = "                    of each attribute query will be written to."]
+    #[doc = " \\param dataSize  - Array containing the size of data"]
+    #[doc = " \\param attribute - The attribute to query"]
+    #[doc = " \\param devPtr    - Start of the range to query"]
+    #[doc = " \\param count     - Size of the range to query"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_async"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemRangeGetAttributes, ::cudaMemPrefetchAsync,"]
+    #[doc = " ::cudaMemAdvise,"]
+    #[doc = " ::cuMemRangeGetAttribute"]
+    pub fn cudaMemRangeGetAttribute(
+        data: *mut ::std::os::raw::c_void,
+        dataSize: usize,
+        attribute: cudaMemRangeAttribute,
+        devPtr: *const ::std::os::raw::c_void,
+        count: usize,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Query attributes of a given memory range."]
+    #[doc = ""]
+    #[doc = " Query attributes of the memory range starting at \\p devPtr with a size of \\p count bytes. The"]
+    #[doc = " memory range must refer to managed memory allocated via ::cudaMallocManaged or declared via"]
+    #[doc = " __managed__ variables. The \\p attributes array will be interpreted to have \\p numAttributes"]
+    #[doc = " entries. The \\p dataSizes array will also be interpreted to have \\p numAttributes entries."]
+    #[doc = " The results of the query will be stored in \\p data."]
+    #[doc = ""]
+    #[doc = " The list of supported attributes are given below. Please refer to ::cudaMemRangeGetAttribute for"]
+    #[doc = " attribute descriptions and restrictions."]
+    #[doc = ""]
+    #[doc = " - ::cudaMemRangeAttributeReadMostly"]
+    #[doc = " - ::cudaMemRangeAttributePreferredLocation"]
+    #[doc = " - ::cudaMemRangeAttributeAccessedBy"]
+    #[doc = " - ::cudaMemRangeAttributeLastPrefetchLocation"]
+    #[doc = ""]
+    #[doc = " \\param data          - A two-dimensional array containing pointers to memory"]
+    #[doc = "                        locations where the result of each attribute query will be written to."]
+    #[doc = " \\param dataSizes     - Array containing the sizes of each result"]
+    #[doc = " \\param attributes    - An array of attributes to query"]
+    #[doc = "                        (numAttributes and the number of attributes in this array should match)"]
+    #[doc = " \\param numAttributes - Number of attributes to query"]
+    #[doc = " \\param devPtr        - Start of the range to query"]
+    #[doc = " \\param count         - Size of the range to query"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaMemRangeGetAttribute, ::cudaMemAdvise"]
+    #[doc = " ::cudaMemPrefetchAsync,"]
+    #[doc = " ::cuMemRangeGetAttributes"]
+    pub fn cudaMemRangeGetAttributes(
+        data: *mut *mut ::std::os::raw::c_void,
+        dataSizes: *mut usize,
+        attributes: *mut cudaMemRangeAttribute,
+        numAttributes: usize,
+        devPtr: *const ::std::os::raw::c_void,
+        count: usize,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Queries if a device may directly access a peer device\'s memory."]
+    #[doc = ""]
+    #[doc = " Returns in \\p *canAccessPeer a value of 1 if device \\p device is capable of"]
+    #[doc = " directly accessing memory from \\p peerDevice and 0 otherwise.  If direct"]
+    #[doc = " access of \\p peerDevice from \\p device is possible, then access may be"]
+    #[doc = " enabled by calling ::cudaDeviceEnablePeerAccess()."]
+    #[doc = ""]
+    #[doc = " \\param canAccessPeer - Returned access capability"]
+    #[doc = " \\param device        - Device from which allocations on \\p peerDevice are to"]
+    #[doc = "                        be directly accessed."]
+    #[doc = " \\param peerDevice    - Device on which the allocations to be directly accessed"]
+    #[doc = "                        by \\p device reside."]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDeviceEnablePeerAccess,"]
+    #[doc = " ::cudaDeviceDisablePeerAccess,"]
+    #[doc = " ::cuDeviceCanAccessPeer"]
+    pub fn cudaDeviceCanAccessPeer(
+        canAccessPeer: *mut ::std::os::raw::c_int,
+        device: ::std::os::raw::c_int,
+        peerDevice: ::std::os::raw::c_int,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Enables direct access to memory allocations on a peer device."]
+    #[doc = ""]
+    #[doc = " On success, all allocations from \\p peerDevice will immediately be accessible by"]
+    #[doc = " the current device.  They will remain accessible until access is explicitly"]
+    #[doc = " disabled using ::cudaDeviceDisablePeerAccess() or either device is reset using"]
+    #[doc = " ::cudaDeviceReset()."]
+    #[doc = ""]
+    #[doc = " Note that access granted by this call is unidirectional and that in order to access"]
+    #[doc = " memory on the current device from \\p peerDevice, a separate symmetric call"]
+    #[doc = " to ::cudaDeviceEnablePeerAccess() is required."]
+    #[doc = ""]
+    #[doc = " Each device can support a system-wide maximum of eight peer connections."]
+    #[doc = ""]
+    #[doc = " Peer access is not supported in 32 bit applications."]
+    #[doc = ""]
+    #[doc = " Returns ::cudaErrorInvalidDevice if ::cudaDeviceCanAccessPeer() indicates"]
+    #[doc = " that the current device cannot directly access memory from \\p peerDevice."]
+    #[doc = ""]
+    #[doc = " Returns ::cudaErrorPeerAccessAlreadyEnabled if direct access of"]
+    #[doc = " \\p peerDevice from the current device has already been enabled."]
+    #[doc = ""]
+    #[doc = " Returns ::cudaErrorInvalidValue if \\p flags is not 0."]
+    #[doc = ""]
+    #[doc = " \\param peerDevice  - Peer device to enable direct access to from the current device"]
+    #[doc = " \\param flags       - Reserved for future use and must be set to 0"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidDevice,"]
+    #[doc = " ::cudaErrorPeerAccessAlreadyEnabled,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDeviceCanAccessPeer,"]
+    #[doc = " ::cudaDeviceDisablePeerAccess,"]
+    #[doc = " ::cuCtxEnablePeerAccess"]
+    pub fn cudaDeviceEnablePeerAccess(
+        peerDevice: ::std::os::raw::c_int,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Disables direct access to memory allocations on a peer device."]
+    #[doc = ""]
+    #[doc = " Returns ::cudaErrorPeerAccessNotEnabled if direct access to memory on"]
+    #[doc = " \\p peerDevice has not yet been enabled from the current device."]
+    #[doc = ""]
+    #[doc = " \\param peerDevice - Peer device to disable direct access to"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorPeerAccessNotEnabled,"]
+    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDeviceCanAccessPeer,"]
+    #[doc = " ::cudaDeviceEnablePeerAccess,"]
+    #[doc = " ::cuCtxDisablePeerAccess"]
+    pub fn cudaDeviceDisablePeerAccess(peerDevice: ::std::os::raw::c_int) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Unregisters a graphics resource for access by CUDA"]
+    #[doc = ""]
+    #[doc = " Unregisters the graphics resource \\p resource so it is not accessible by"]
+    #[doc = " CUDA unless registered again."]
+    #[doc = ""]
+    #[doc = " If \\p resource is invalid then ::cudaErrorInvalidResourceHandle is"]
+    #[doc = " returned."]
+    #[doc = ""]
+    #[doc = " \\param resource - Resource to unregister"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorUnknown"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphicsD3D9RegisterResource,"]
+    #[doc = " ::cudaGraphicsD3D10RegisterResource,"]
+    #[doc = " ::cudaGraphicsD3D11RegisterResource,"]
+    #[doc = " ::cudaGraphicsGLRegisterBuffer,"]
+    #[doc = " ::cudaGraphicsGLRegisterImage,"]
+    #[doc = " ::cuGraphicsUnregisterResource"]
+    pub fn cudaGraphicsUnregisterResource(resource: cudaGraphicsResource_t) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Set usage flags for mapping a graphics resource"]
+    #[doc = ""]
+    #[doc = " Set \\p flags for mapping the graphics resource \\p resource."]
+    #[doc = ""]
+    #[doc = " Changes to \\p flags will take effect the next time \\p resource is mapped."]
+    #[doc = " The \\p flags argument may be any of the following:"]
+    #[doc = " - ::cudaGraphicsMapFlagsNone: Specifies no hints about how \\p resource will"]
+    #[doc = "     be used. It is therefore assumed that CUDA may read from or write to \\p resource."]
+    #[doc = " - ::cudaGraphicsMapFlagsReadOnly: Specifies that CUDA will not write to \\p resource."]
+    #[doc = " - ::cudaGraphicsMapFlagsWriteDiscard: Specifies CUDA will not read from \\p resource and will"]
+    #[doc = "   write over the entire contents of \\p resource, so none of the data"]
+    #[doc = "   previously stored in \\p resource will be preserved."]
+    #[doc = ""]
+    #[doc = " If \\p resource is presently mapped for access by CUDA then ::cudaErrorUnknown is returned."]
+    #[doc = " If \\p flags is not one of the above values then ::cudaErrorInvalidValue is returned."]
+    #[doc = ""]
+    #[doc = " \\param resource - Registered resource to set flags for"]
+    #[doc = " \\param flags    - Parameters for resource mapping"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorUnknown,"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphicsMapResources,"]
+    #[doc = " ::cuGraphicsResourceSetMapFlags"]
+    pub fn cudaGraphicsResourceSetMapFlags(
+        resource: cudaGraphicsResource_t,
+        flags: ::std::os::raw::c_uint,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Map graphics resources for access by CUDA"]
+    #[doc = ""]
+    #[doc = " Maps the \\p count graphics resources in \\p resources for access by CUDA."]
+    #[doc = ""]
+    #[doc = " The resources in \\p resources may be accessed by CUDA until they"]
+    #[doc = " are unmapped. The graphics API from which \\p resources were registered"]
+    #[doc = " should not access any resources while they are mapped by CUDA. If an"]
+    #[doc = " application does so, the results are undefined."]
+    #[doc = ""]
+    #[doc = " This function provides the synchronization guarantee that any graphics calls"]
+    #[doc = " issued before ::cudaGraphicsMapResources() will complete before any subsequent CUDA"]
+    #[doc = " work issued in \\p stream begins."]
+    #[doc = ""]
+    #[doc = " If \\p resources contains any duplicate entries then ::cudaErrorInvalidResourceHandle"]
+    #[doc = " is returned. If any of \\p resources are presently mapped for access by"]
+    #[doc = " CUDA then ::cudaErrorUnknown is returned."]
+    #[doc = ""]
+    #[doc = " \\param count     - Number of resources to map"]
+    #[doc = " \\param resources - Resources to map for CUDA"]
+    #[doc = " \\param stream    - Stream for synchronization"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorUnknown"]
+    #[doc = " \\note_null_stream"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphicsResourceGetMappedPointer,"]
+    #[doc = " ::cudaGraphicsSubResourceGetMappedArray,"]
+    #[doc = " ::cudaGraphicsUnmapResources,"]
+    #[doc = " ::cuGraphicsMapResources"]
+    pub fn cudaGraphicsMapResources(
+        count: ::std::os::raw::c_int,
+        resources: *mut cudaGraphicsResource_t,
         stream: cudaStream_t,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Copies data between host and device"]
+    #[doc = " \\brief Unmap graphics resources."]
     #[doc = ""]
-    #[doc = " Copies a matrix (\\p height rows of \\p width bytes each) from the memory"]
-    #[doc = " area pointed to by \\p src to the memory area pointed to by \\p dst, where"]
-    #[doc = " \\p kind specifies the direction of the copy, and must be one of"]
-    #[doc = " ::cudaMemcpyHostToHost, ::cudaMemcpyHostToDevice, ::cudaMemcpyDeviceToHost,"]
-    #[doc = " ::cudaMemcpyDeviceToDevice, or ::cudaMemcpyDefault. Passing"]
-    #[doc = " ::cudaMemcpyDefault is recommended, in which case the type of transfer is"]
-    #[doc = " inferred from the pointer values. However, ::cudaMemcpyDefault is only"]
-    #[doc = " allowed on systems that support unified virtual addressing."]
-    #[doc = " \\p dpitch and \\p spitch are the widths in memory in bytes of the 2D arrays"]
-    #[doc = " pointed to by \\p dst and \\p src, including any padding added to the end of"]
-    #[doc = " each row. The memory areas may not overlap. \\p width must not exceed either"]
-    #[doc = " \\p dpitch or \\p spitch."]
+    #[doc = " Unmaps the \\p count graphics resources in \\p resources."]
     #[doc = ""]
-    #[doc = " Calling ::cudaMemcpy2DAsync() with \\p dst and \\p src pointers that do not"]
-    #[doc = " match the direction of the copy results in an undefined behavior."]
-    #[doc = " ::cudaMemcpy2DAsync() returns an error if \\p dpitch or \\p spitch is greater"]
-    #[doc = " than the maximum allowed."]
+    #[doc = " Once unmapped, the resources in \\p resources may not be accessed by CUDA"]
+    #[doc = " until they are mapped again."]
     #[doc = ""]
-    #[doc = " ::cudaMemcpy2DAsync() is asynchronous with respect to the host, so"]
-    #[doc = " the call may return before the copy is complete. The copy can optionally"]
-    #[doc = " be associated to a stream by passing a non-zero \\p stream argument. If"]
-    #[doc = " \\p kind is ::cudaMemcpyHostToDevice or ::cudaMemcpyDeviceToHost and"]
-    #[doc = " \\p stream is non-zero, the copy may overlap with operations in other"]
-    #[doc = " streams."]
+    #[doc = " This function provides the synchronization guarantee that any CUDA work issued"]
+    #[doc = " in \\p stream before ::cudaGraphicsUnmapResources() will complete before any"]
+    #[doc = " subsequently issued graphics work begins."]
     #[doc = ""]
-    #[doc = " The device version of this function only handles device to device copies and"]
-    #[doc = " cannot be given local or shared pointers."]
+    #[doc = " If \\p resources contains any duplicate entries then ::cudaErrorInvalidResourceHandle"]
+    #[doc = " is returned. If any of \\p resources are not presently mapped for access by"]
+    #[doc = " CUDA then ::cudaErrorUnknown is returned."]
     #[doc = ""]
-    #[doc = " \\param dst    - Destination memory address"]
-    #[doc = " \\param dpitch - Pitch of destination memory"]
-    #[doc = " \\param src    - Source memory address"]
-    #[doc = " \\param spitch - Pitch of source memory"]
-    #[doc = " \\param width  - Width of matrix transfer (columns in bytes)"]
-    #[doc = " \\param height - Height of matrix transfer (rows)"]
-    #[doc = " \\param kind   - Type of transfer"]
-    #[doc = " \\param stream - Stream identifier"]
+    #[doc = " \\param count     - Number of resources to unmap"]
+    #[doc = " \\param resources - Resources to unmap"]
+    #[doc = " \\param stream    - Stream for synchronization"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidPitchValue,"]
-    #[doc = " ::cudaErrorInvalidMemcpyDirection"]
-    #[doc = " \\notefnerr"]
-    #[doc = " \\note_async"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorUnknown"]
     #[doc = " \\note_null_stream"]
+    #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpy2D, ::cudaMemcpyToArray,"]
-    #[doc = " ::cudaMemcpy2DToArray, ::cudaMemcpyFromArray, ::cudaMemcpy2DFromArray,"]
-    #[doc = " ::cudaMemcpyArrayToArray, ::cudaMemcpy2DArrayToArray, ::cudaMemcpyToSymbol,"]
-    #[doc = " ::cudaMemcpyFromSymbol, ::cudaMemcpyAsync,"]
-    #[doc = " ::cudaMemcpyToArrayAsync, ::cudaMemcpy2DToArrayAsync,"]
-    #[doc = " ::cudaMemcpyFromArrayAsync, ::cudaMemcpy2DFromArrayAsync,"]
-    #[doc = " ::cudaMemcpyToSymbolAsync, ::cudaMemcpyFromSymbolAsync,"]
-    #[doc = " ::cuMemcpy2DAsync"]
-    pub fn cudaMemcpy2DAsync(
-        dst: *mut ::std::os::raw::c_void,
-        dpitch: usize,
-        src: *const ::std::os::raw::c_void,
-        spitch: usize,
-        width: usize,
-        height: usize,
-        kind: cudaMemcpyKind,
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphicsMapResources,"]
+    #[doc = " ::cuGraphicsUnmapResources"]
+    pub fn cudaGraphicsUnmapResources(
+        count: ::std::os::raw::c_int,
+        resources: *mut cudaGraphicsResource_t,
         stream: cudaStream_t,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Initializes or sets device memory to a value"]
-    #[doc = ""]
-    #[doc = " Fills the first \\p count bytes of the memory area pointed to by \\p devPtr"]
-    #[doc = " with the constant byte value \\p value."]
+    #[doc = " \\brief Get an device pointer through which to access a mapped graphics resource."]
     #[doc = ""]
-    #[doc = " Note that this function is asynchronous with respect to the host unless"]
-    #[doc = " \\p devPtr refers to pinned host memory."]
+    #[doc = " Returns in \\p *devPtr a pointer through which the mapped graphics resource"]
+    #[doc = " \\p resource may be accessed."]
+    #[doc = " Returns in \\p *size the size of the memory in bytes which may be accessed from that pointer."]
+    #[doc = " The value set in \\p devPtr may change every time that \\p resource is mapped."]
     #[doc = ""]
-    #[doc = " \\param devPtr - Pointer to device memory"]
-    #[doc = " \\param value  - Value to set for each byte of specified memory"]
-    #[doc = " \\param count  - Size in bytes to set"]
+    #[doc = " If \\p resource is not a buffer then it cannot be accessed via a pointer and"]
+    #[doc = " ::cudaErrorUnknown is returned."]
+    #[doc = " If \\p resource is not mapped then ::cudaErrorUnknown is returned."]
+    #[doc = " *"]
+    #[doc = " \\param devPtr     - Returned pointer through which \\p resource may be accessed"]
+    #[doc = " \\param size       - Returned size of the buffer accessible starting at \\p *devPtr"]
+    #[doc = " \\param resource   - Mapped resource to access"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
     #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorInvalidResourceHandle,"]
+    #[doc = " ::cudaErrorUnknown"]
     #[doc = " \\notefnerr"]
-    #[doc = " \\note_memset"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
     #[doc = " \\sa"]
-    #[doc = " ::cuMemsetD8,"]
-    #[doc = " ::cuMemsetD16,"]
-    #[doc = " ::cuMemsetD32"]
-    pub fn cudaMemset(
-        devPtr: *mut ::std::os::raw::c_void,
-        value: ::std::os::raw::c_int,
-        count: usize,
+    #[doc = " ::cudaGraphicsMapResources,"]
+    #[doc = " ::cudaGraphicsSubResourceGetMappedArray,"]
+    #[doc = " ::cuGraphicsResourceGetMappedPointer"]
+    pub fn cudaGraphicsResourceGetMappedPointer(
+        devPtr: *mut *mut ::std::os::raw::c_void,
+        size: *mut usize,
+        resource: cudaGraphicsResource_t,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Initializes or sets device memory to a value"]
+    #[doc = " \\brief Returns a channel descriptor using the specified format"]
     #[doc = ""]
-    #[doc = " Fills the first \\p count bytes of the memory area pointed to by \\p devPtr"]
-    #[doc = " with the constant byte value \\p value."]
+    #[doc = " Returns a channel descriptor with format \\p f and number of bits of each"]
+    #[doc = " component \\p x, \\p y, \\p z, and \\p w.  The ::cudaChannelFormatDesc is"]
+    #[doc = " defined as:"]
+    #[doc = " \\code"]
+    #[doc = " struct cudaChannelFormatDesc {"]
+    #[doc = " int x, y, z, w;"]
+    #[doc = " enum cudaChannelFormatKind f;"]
+    #[doc = " };"]
+    #[doc = " \\endcode"]
     #[doc = ""]
-    #[doc = " ::cudaMemsetAsync() is asynchronous with respect to the host, so"]
-    #[doc = " the call may return before the memset is complete. The operation can optionally"]
-    #[doc = " be associated to a stream by passing a non-zero \\p stream argument."]
-    #[doc = " If \\p stream is non-zero, the operation may overlap with operations in other streams."]
+    #[doc = " where ::cudaChannelFormatKind is one of ::cudaChannelFormatKindSigned,"]
+    #[doc = " ::cudaChannelFormatKindUnsigned, or ::cudaChannelFormatKindFloat."]
     #[doc = ""]
-    #[doc = " The device version of this function only handles device to device copies and"]
-    #[doc = " cannot be given local or shared pointers."]
+    #[doc = " \\param x - X component"]
+    #[doc = " \\param y - Y component"]
+    #[doc = " \\param z - Z component"]
+    #[doc = " \\param w - W component"]
+    #[doc = " \\param f - Channel format"]
     #[doc = ""]
-    #[doc = " \\param devPtr - Pointer to device memory"]
-    #[doc = " \\param value  - Value to set for each byte of specified memory"]
-    #[doc = " \\param count  - Size in bytes to set"]
-    #[doc = " \\param stream - Stream identifier"]
+    #[doc = " \\return"]
+    #[doc = " Channel descriptor with format \\p f"]
+    #[doc = ""]
+    #[doc = " \\sa \\ref ::cudaCreateChannelDesc(void) \"cudaCreateChannelDesc (C++ API)\","]
+    #[doc = " ::cudaGetChannelDesc,"]
+    #[doc = " ::cuTexRefSetFormat"]
+    pub fn cudaCreateChannelDesc(
+        x: ::std::os::raw::c_int,
+        y: ::std::os::raw::c_int,
+        z: ::std::os::raw::c_int,
+        w: ::std::os::raw::c_int,
+        f: cudaChannelFormatKind,
+    ) -> cudaChannelFormatDesc;
+}
+extern "C" {
+    #[doc = " \\brief Creates a texture object"]
+    #[doc = ""]
+    #[doc = " Creates a texture object and returns it in \\p pTexObject. \\p pResDesc describes"]
+    #[doc = " the data to texture from. \\p pTexDesc describes how the data should be sampled."]
+    #[doc = " \\p pResViewDesc is an optional argument that specifies an alternate format for"]
+    #[doc = " the data described by \\p pResDesc, and also describes the subresource region"]
+    #[doc = " to restrict access to when texturing. \\p pResViewDesc can only be specified if"]
+    #[doc = " the type of resource is a CUDA array or a CUDA mipmapped array."]
+    #[doc = ""]
+    #[doc = " Texture objects are only supported on devices of compute capability 3.0 or higher."]
+    #[doc = " Additionally, a texture object is an opaque value, and, as such, should only be"]
+    #[doc = " accessed through CUDA API calls."]
+    #[doc = ""]
+    #[doc = " The ::cudaResourceDesc structure is defined as:"]
+    #[doc = " \\code"]
+    #[doc = " struct cudaResourceDesc {"]
+    #[doc = " enum cudaResourceType resType;"]
+    #[doc = ""]
+    #[doc = " union {"]
+    #[doc = " struct {"]
+    #[doc = " cudaArray_t array;"]
+    #[doc = " } array;"]
+    #[doc = " struct {"]
+    #[doc = " cudaMipmappedArray_t mipmap;"]
+    #[doc = " } mipmap;"]
+    #[doc = " struct {"]
+    #[doc = " void *devPtr;"]
+    #[doc = " struct cudaChannelFormatDesc desc;"]
+    #[doc = " size_t sizeInBytes;"]
+    #[doc = " } linear;"]
+    #[doc = " struct {"]
+    #[doc = " void *devPtr;"]
+    #[doc = " struct cudaChannelFormatDesc desc;"]
+    #[doc = " size_t width;"]
+    #[doc = " size_t height;"]
+    #[doc = " size_t pitchInBytes;"]
+    #[doc = " } pitch2D;"]
+    #[doc = " } res;"]
+    #[doc = " };"]
+    #[doc = " \\endcode"]
+    #[doc = " where:"]
+    #[doc = " - ::cudaResourceDesc::resType specifies the type of resource to texture from."]
+    #[doc = " CUresourceType is defined as:"]
+    #[doc = " \\code"]
+    #[doc = " enum cudaResourceType {"]
+    #[doc = " cudaResourceTypeArray          = 0x00,"]
+    #[doc = " cudaResourceTypeMipmappedArray = 0x01,"]
+    #[doc = " cudaResourceTypeLinear         = 0x02,"]
+    #[doc = " cudaResourceTypePitch2D        = 0x03"]
+    #[doc = " };"]
+    #[doc = " \\endcode"]
+    #[doc = ""]
+    #[doc = " \\param pTexObject   - Texture object to create"]
+    #[doc = " \\param pResDesc     - Resource descriptor"]
+    #[doc = " \\param pTexDesc     - Texture descriptor"]
+    #[doc = " \\param pResViewDesc - Resource view descriptor"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " \\notefnerr"]
-    #[doc = " \\note_memset"]
-    #[doc = " \\note_null_stream"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = ""]
+    #[doc = " \\sa ::cudaDestroyTextureObject,"]
+    #[doc = " ::cuTexObjectCreate"]
+    pub fn cudaCreateTextureObject(
+        pTexObject: *mut cudaTextureObject_t,
+        pResDesc: *const cudaResourceDesc,
+        pTexDesc: *const cudaTextureDesc,
+        pResViewDesc: *const cudaResourceViewDesc,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Destroys a texture object"]
+    #[doc = ""]
+    #[doc = " Destroys the texture object specified by \\p texObject."]
+    #[doc = ""]
+    #[doc = " \\param texObject - Texture object to destroy"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemset, ::cudaMemset2D, ::cudaMemset3D,"]
-    #[doc = " ::cudaMemset2DAsync, ::cudaMemset3DAsync,"]
-    #[doc = " ::cuMemsetD8Async,"]
-    #[doc = " ::cuMemsetD16Async,"]
-    #[doc = " ::cuMemsetD32Async"]
-    pub fn cudaMemsetAsync(
-        devPtr: *mut ::std::os::raw::c_void,
-        value: ::std::os::raw::c_int,
-        count: usize,
-        stream: cudaStream_t,
+    #[doc = " \\sa"]
+    #[doc = " ::cudaCreateTextureObject,"]
+    #[doc = " ::cuTexObjectDestroy"]
+    pub fn cudaDestroyTextureObject(texObject: cudaTextureObject_t) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Creates a surface object"]
+    #[doc = ""]
+    #[doc = " Creates a surface object and returns it in \\p pSurfObject. \\p pResDesc describes"]
+    #[doc = " the data to perform surface load/stores on. ::cudaResourceDesc::resType must be"]
+    #[doc = " ::cudaResourceTypeArray and  ::cudaResourceDesc::res::array::array"]
+    #[doc = " must be set to a valid CUDA array handle."]
+    #[doc = ""]
+    #[doc = " Surface objects are only supported on devices of compute capability 3.0 or higher."]
+    #[doc = " Additionally, a surface object is an opaque value, and, as such, should only be"]
+    #[doc = " accessed through CUDA API calls."]
+    #[doc = ""]
+    #[doc = " \\param pSurfObject - Surface object to create"]
+    #[doc = " \\param pResDesc    - Resource descriptor"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaDestroySurfaceObject,"]
+    #[doc = " ::cuSurfObjectCreate"]
+    pub fn cudaCreateSurfaceObject(
+        pSurfObject: *mut cudaSurfaceObject_t,
+        pResDesc: *const cudaResourceDesc,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Prefetches memory to the specified destination device"]
+    #[doc = " \\brief Destroys a surface object"]
     #[doc = ""]
-    #[doc = " Prefetches memory to the specified destination device.  \\p devPtr is the"]
-    #[doc = " base device pointer of the memory to be prefetched and \\p dstDevice is the"]
-    #[doc = " destination device. \\p count specifies the number of bytes to copy. \\p stream"]
-    #[doc = " is the stream in which the operation is enqueued. The memory range must refer"]
-    #[doc = " to managed memory allocated via ::cudaMallocManaged or declared via __managed__ variables."]
+    #[doc = " Destroys the surface object specified by \\p surfObject."]
     #[doc = ""]
-    #[doc = " Passing in cudaCpuDeviceId for \\p dstDevice will prefetch the data to host memory. If"]
-    #[doc = " \\p dstDevice is a GPU, then the device attribute ::cudaDevAttrConcurrentManagedAccess"]
-    #[doc = " must be non-zero. Additionally, \\p stream must be associated with a device that has a"]
-    #[doc = " non-zero value for the device attribute ::cudaDevAttrConcurrentManagedAccess."]
+    #[doc = " \\param surfObject - Surface object to destroy"]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaCreateSurfaceObject,"]
+    #[doc = " ::cuSurfObjectDestroy"]
+    pub fn cudaDestroySurfaceObject(surfObject: cudaSurfaceObject_t) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Returns the latest version of CUDA supported by the driver"]
+    #[doc = ""]
+    #[doc = " Returns in \\p *driverVersion the latest version of CUDA supported by"]
+    #[doc = " the driver. The version is returned as (1000 &times; major + 10 &times; minor)."]
+    #[doc = " For example, CUDA 9.2 would be represented by 9020. If no driver is installed,"]
+    #[doc = " then 0 is returned as the driver version."]
+    #[doc = ""]
+    #[doc = " This function automatically returns ::cudaErrorInvalidValue"]
+    #[doc = " if \\p driverVersion is NULL."]
+    #[doc = ""]
+    #[doc = " \\param driverVersion - Returns the CUDA driver version."]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaRuntimeGetVersion,"]
+    #[doc = " ::cuDriverGetVersion"]
+    pub fn cudaDriverGetVersion(driverVersion: *mut ::std::os::raw::c_int) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Returns the CUDA Runtime version"]
+    #[doc = ""]
+    #[doc = " Returns in \\p *runtimeVersion the version number of the current CUDA"]
+    #[doc = " Runtime instance. The version is returned as"]
+    #[doc = " (1000 &times; major + 10 &times; minor). For example,"]
+    #[doc = " CUDA 9.2 would be represented by 9020."]
+    #[doc = ""]
+    #[doc = " This function automatically returns ::cudaErrorInvalidValue if"]
+    #[doc = " the \\p runtimeVersion argument is NULL."]
+    #[doc = ""]
+    #[doc = " \\param runtimeVersion - Returns the CUDA Runtime version."]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " The start address and end address of the memory range will be rounded down and rounded up"]
-    #[doc = " respectively to be aligned to CPU page size before the prefetch operation is enqueued"]
-    #[doc = " in the stream."]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaDriverGetVersion,"]
+    #[doc = " ::cuDriverGetVersion"]
+    pub fn cudaRuntimeGetVersion(runtimeVersion: *mut ::std::os::raw::c_int) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Creates a graph"]
     #[doc = ""]
-    #[doc = " If no physical memory has been allocated for this region, then this memory region"]
-    #[doc = " will be populated and mapped on the destination device. If there\'s insufficient"]
-    #[doc = " memory to prefetch the desired region, the Unified Memory driver may evict pages from other"]
-    #[doc = " ::cudaMallocManaged allocations to host memory in order to make room. Device memory"]
-    #[doc = " allocated using ::cudaMalloc or ::cudaMallocArray will not be evicted."]
+    #[doc = " Creates an empty graph, which is returned via \\p pGraph."]
     #[doc = ""]
-    #[doc = " By default, any mappings to the previous location of the migrated pages are removed and"]
-    #[doc = " mappings for the new location are only setup on \\p dstDevice. The exact behavior however"]
-    #[doc = " also depends on the settings applied to this memory range via ::cudaMemAdvise as described"]
-    #[doc = " below:"]
+    #[doc = " \\param pGraph - Returns newly created graph"]
+    #[doc = " \\param flags   - Graph creation flags, must be 0"]
     #[doc = ""]
-    #[doc = " If ::cudaMemAdviseSetReadMostly was set on any subset of this memory range,"]
-    #[doc = " then that subset will create a read-only copy of the pages on \\p dstDevice."]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " \\note_graph_thread_safety"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " If ::cudaMemAdviseSetPreferredLocation was called on any subset of this memory"]
-    #[doc = " range, then the pages will be migrated to \\p dstDevice even if \\p dstDevice is not the"]
-    #[doc = " preferred location of any pages in the memory range."]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphAddChildGraphNode,"]
+    #[doc = " ::cudaGraphAddEmptyNode,"]
+    #[doc = " ::cudaGraphAddKernelNode,"]
+    #[doc = " ::cudaGraphAddHostNode,"]
+    #[doc = " ::cudaGraphAddMemcpyNode,"]
+    #[doc = " ::cudaGraphAddMemsetNode,"]
+    #[doc = " ::cudaGraphInstantiate,"]
+    #[doc = " ::cudaGraphDestroy,"]
+    #[doc = " ::cudaGraphGetNodes,"]
+    #[doc = " ::cudaGraphGetRootNodes,"]
+    #[doc = " ::cudaGraphGetEdges,"]
+    #[doc = " ::cudaGraphClone"]
+    pub fn cudaGraphCreate(pGraph: *mut cudaGraph_t, flags: ::std::os::raw::c_uint) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Creates a kernel execution node and adds it to a graph"]
     #[doc = ""]
-    #[doc = " If ::cudaMemAdviseSetAccessedBy was called on any subset of this memory range,"]
-    #[doc = " then mappings to those pages from all the appropriate processors are updated to"]
-    #[doc = " refer to the new location if establishing such a mapping is possible. Otherwise,"]
-    #[doc = " those mappings are cleared."]
+    #[doc = " Creates a new kernel execution node and adds it to \\p graph with \\p numDependencies"]
+    #[doc = " dependencies specified via \\p pDependencies and arguments specified in \\p pNodeParams."]
     #[doc = ""]
-    #[doc = " Note that this API is not required for functionality and only serves to improve performance"]
-    #[doc = " by allowing the application to migrate data to a suitable location before it is accessed."]
-    #[doc = " Memory accesses to this range are always coherent and are allowed even when the data is"]
-    #[doc = " actively being migrated."]
+    #[doc = " Kernel parameters can be specified in one of two ways: through \\p kernelParams or"]
+    #[doc = " \\p extra. The parameters are copied when the node is created."]
     #[doc = ""]
-    #[doc = " Note that this function is asynchronous with respect to the host and all work"]
-    #[doc = " on other devices."]
+    #[doc = " The node will be placed at the root of \\p graph if \\p numDependencies is 0."]
+    #[doc = " \\p pDependencies may not have any duplicate entries."]
+    #[doc = " A handle to the new node will be returned in \\p pGraphNode."]
     #[doc = ""]
-    #[doc = " \\param devPtr    - Pointer to be prefetched"]
-    #[doc = " \\param count     - Size in bytes"]
-    #[doc = " \\param dstDevice - Destination device to prefetch to"]
-    #[doc = " \\param stream    - Stream to enqueue prefetch operation"]
+    #[doc = " \\param pGraphNode      - Returns newly created node"]
+    #[doc = " \\param graph           - Graph to which to add the node"]
+    #[doc = " \\param pDependencies   - Dependencies of the node"]
+    #[doc = " \\param numDependencies - Number of dependencies"]
+    #[doc = " \\param pNodeParams     - Parameters for the GPU execution node"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
-    #[doc = " \\note_async"]
-    #[doc = " \\note_null_stream"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyPeer, ::cudaMemcpyAsync,"]
-    #[doc = " ::cudaMemcpy3DPeerAsync, ::cudaMemAdvise,"]
-    #[doc = " ::cuMemPrefetchAsync"]
-    pub fn cudaMemPrefetchAsync(
-        devPtr: *const ::std::os::raw::c_void,
-        count: usize,
-        dstDevice: ::std::os::raw::c_int,
-        stream: cudaStream_t,
+    #[doc = " \\sa"]
+    #[doc = " ::cudaLaunchKernel,"]
+    #[doc = " ::cudaGraphKernelNodeGetParams,"]
+    #[doc = " ::cudaGraphKernelNodeSetParams,"]
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphDestroyNode,"]
+    #[doc = " ::cudaGraphAddChildGraphNode,"]
+    #[doc = " ::cudaGraphAddEmptyNode,"]
+    #[doc = " ::cudaGraphAddHostNode,"]
+    #[doc = " ::cudaGraphAddMemcpyNode,"]
+    #[doc = " ::cudaGraphAddMemsetNode"]
+    pub fn cudaGraphAddKernelNode(
+        pGraphNode: *mut cudaGraphNode_t,
+        graph: cudaGraph_t,
+        pDependencies: *const cudaGraphNode_t,
+        numDependencies: usize,
+        pNodeParams: *const cudaKernelNodeParams,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Advise about the usage of a given memory range"]
+    #[doc = " \\brief Creates a memcpy node and adds it to a graph"]
     #[doc = ""]
-    #[doc = " Advise the Unified Memory subsystem about the usage pattern for the memory range"]
-    #[doc = " starting at \\p devPtr with a size of \\p count bytes. The start address and end address of the memory"]
-    #[doc = " range will be rounded down and rounded up respectively to be aligned to CPU page size before the"]
-    #[doc = " advice is applied. The memory range must refer to managed memory allocated via ::cudaMallocManaged"]
-    #[doc = " or declared via __managed__ variables. The memory range could also refer to system-allocated pageable"]
-    #[doc = " memory provided it represents a valid, host-accessible region of memory and all additional constraints"]
-    #[doc = " imposed by \\p advice as outlined below are also satisfied. Specifying an invalid system-allocated pageable"]
-    #[doc = " memory range results in an error being returned."]
+    #[doc = " Creates a new memcpy node and adds it to \\p graph with \\p numDependencies"]
+    #[doc = " dependencies specified via \\p pDependencies."]
     #[doc = ""]
-    #[doc = " The \\p advice parameter can take the following values:"]
-    #[doc = " - ::cudaMemAdviseSetReadMostly: This implies that the data is mostly going to be read"]
-    #[doc = " from and only occasionally written to. Any read accesses from any processor to this region will create a"]
-    #[doc = " read-only copy of at least the accessed pages in that processor\'s memory. Additionally, if ::cudaMemPrefetchAsync"]
-    #[doc = " is called on this region, it will create a read-only copy of the data on the destination processor."]
-    #[doc = " If any processor writes to this region, all copies of the corresponding page will be invalidated"]
-    #[doc = " except for the one where the write occurred. The \\p device argument is ignored for this advice."]
-    #[doc = " Note that for a page to be read-duplicated, the accessing processor must either be the CPU or a GPU"]
-    #[doc = " that has a non-zero value for the device attribute ::cudaDevAttrConcurrentManagedAccess."]
-    #[doc = " Also, if a context is created on a device that does not have the device attribute"]
-    #[doc = " ::cudaDevAttrConcurrentManagedAccess set, then read-duplication will not occur until"]
-    #[doc = " all such contexts are destroyed."]
-    #[doc = " If the memory region refers to valid system-allocated pageable memory, then the accessing device must"]
-    #[doc = " have a non-zero value for the device attribute ::cudaDevAttrPageableMemoryAccess for a read-only"]
-    #[doc = " copy to be created on that device. Note however that if the accessing device also has a non-zero value for the"]
-    #[doc = " device attribute ::cudaDevAttrPageableMemoryAccessUsesHostPageTables, then setting this advice"]
-    #[doc = " will not create a read-only copy when that device accesses this memory region."]
+    #[doc = " When the graph is launched, the node will perform the memcpy described by \\p pCopyParams."]
+    #[doc = " See ::cudaMemcpy3D() for a description of the structure and its restrictions."]
     #[doc = ""]
-    #[doc = " - ::cudaMemAdviceUnsetReadMostly: Undoes the effect of ::cudaMemAdviceReadMostly and also prevents the"]
-    #[doc = " Unified Memory driver from attempting heuristic read-duplication on the memory range. Any read-duplicated"]
-    #[doc = " copies of the data will be collapsed into a single copy. The location for the collapsed"]
-    #[doc = " copy will be the preferred location if the page has a preferred location and one of the read-duplicated"]
-    #[doc = " copies was resident at that location. Otherwise, the location chosen is arbitrary."]
+    #[doc = " The node will be placed at the root of \\p graph if \\p numDependencies is 0."]
+    #[doc = " \\p pDependencies may not have any duplicate entries."]
+    #[doc = " A handle to the new node will be returned in \\p pGraphNode."]
     #[doc = ""]
-    #[doc = " - ::cudaMemAdviseSetPreferredLocation: This advice sets the preferred location for the"]
-    #[doc = " data to be the memory belonging to \\p device. Passing in cudaCpuDeviceId for \\p device sets the"]
-    #[doc = " preferred location as host memory. If \\p device is a GPU, then it must have a non-zero value for the"]
-    #[doc = " device attribute ::cudaDevAttrConcurrentManagedAccess. Setting the preferred location"]
-    #[doc = " does not cause data to migrate to that location immediately. Instead, it guides the migration policy"]
-    #[doc = " when a fault occurs on that memory region. If the data is already in its preferred location and the"]
-    #[doc = " faulting processor can establish a mapping without requiring the data to be migrated, then"]
-    #[doc = " data migration will be avoided. On the other hand, if the data is not in its preferred location"]
-    #[doc = " or if a direct mapping cannot be established, then it will be migrated to the processor accessing"]
-    #[doc = " it. It is important to note that setting the preferred location does not prevent data prefetching"]
-    #[doc = " done using ::cudaMemPrefetchAsync."]
-    #[doc = " Having a preferred location can override the page thrash detection and resolution logic in the Unified"]
-    #[doc = " Memory driver. Normally, if a page is detected to be constantly thrashing between for example host and device"]
-    #[doc = " memory, the page may eventually be pinned to host memory by the Unified Memory driver. But"]
-    #[doc = " if the preferred location is set as device memory, then the page will continue to thrash indefinitely."]
-    #[doc = " If ::cudaMemAdviseSetReadMostly is also set on this memory region or any subset of it, then the"]
-    #[doc = " policies associated with that advice will override the policies of this advice, unless read accesses from"]
-    #[doc = " \\p device will not result in a read-only copy being created on that device as outlined in description for"]
-    #[doc = " the advice ::cudaMemAdviseSetReadMostly."]
-    #[doc = " If the memory region refers to valid system-allocated pageable memory, then \\p device must have a non-zero"]
-    #[doc = " value for the device attribute ::cudaDevAttrPageableMemoryAccess. Additionally, if \\p device has"]
-    #[doc = " a non-zero value for the device attribute ::cudaDevAttrPageableMemoryAccessUsesHostPageTables,"]
-    #[doc = " then this call has no effect. Note however that this behavior may change in the future."]
+    #[doc = " \\param pGraphNode      - Returns newly created node"]
+    #[doc = " \\param graph           - Graph to which to add the node"]
+    #[doc = " \\param pDependencies   - Dependencies of the node"]
+    #[doc = " \\param numDependencies - Number of dependencies"]
+    #[doc = " \\param pCopyParams     - Parameters for the memory copy"]
     #[doc = ""]
-    #[doc = " - ::cudaMemAdviseUnsetPreferredLocation: Undoes the effect of ::cudaMemAdviseSetPreferredLocation"]
-    #[doc = " and changes the preferred location to none."]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " - ::cudaMemAdviseSetAccessedBy: This advice implies that the data will be accessed by \\p device."]
-    #[doc = " Passing in ::cudaCpuDeviceId for \\p device will set the advice for the CPU. If \\p device is a GPU, then"]
-    #[doc = " the device attribute ::cudaDevAttrConcurrentManagedAccess must be non-zero."]
-    #[doc = " This advice does not cause data migration and has no impact on the location of the data per se. Instead,"]
-    #[doc = " it causes the data to always be mapped in the specified processor\'s page tables, as long as the"]
-    #[doc = " location of the data permits a mapping to be established. If the data gets migrated for any reason,"]
-    #[doc = " the mappings are updated accordingly."]
-    #[doc = " This advice is recommended in scenarios where data locality is not important, but avoiding faults is."]
-    #[doc = " Consider for example a system containing multiple GPUs with peer-to-peer access enabled, where the"]
-    #[doc = " data located on one GPU is occasionally accessed by peer GPUs. In such scenarios, migrating data"]
-    #[doc = " over to the other GPUs is not as important because the accesses are infrequent and the overhead of"]
-    #[doc = " migration may be too high. But preventing faults can still help improve performance, and so having"]
-    #[doc = " a mapping set up in advance is useful. Note that on CPU access of this data, the data may be migrated"]
-    #[doc = " to host memory because the CPU typically cannot access device memory directly. Any GPU that had the"]
-    #[doc = " ::cudaMemAdviceSetAccessedBy flag set for this data will now have its mapping updated to point to the"]
-    #[doc = " page in host memory."]
-    #[doc = " If ::cudaMemAdviseSetReadMostly is also set on this memory region or any subset of it, then the"]
-    #[doc = " policies associated with that advice will override the policies of this advice. Additionally, if the"]
-    #[doc = " preferred location of this memory region or any subset of it is also \\p device, then the policies"]
-    #[doc = " associated with ::cudaMemAdviseSetPreferredLocation will override the policies of this advice."]
-    #[doc = " If the memory region refers to valid system-allocated pageable memory, then \\p device must have a non-zero"]
-    #[doc = " value for the device attribute ::cudaDevAttrPageableMemoryAccess. Additionally, if \\p device has"]
-    #[doc = " a non-zero value for the device attribute ::cudaDevAttrPageableMemoryAccessUsesHostPageTables,"]
-    #[doc = " then this call has no effect."]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaMemcpy3D,"]
+    #[doc = " ::cudaGraphMemcpyNodeGetParams,"]
+    #[doc = " ::cudaGraphMemcpyNodeSetParams,"]
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphDestroyNode,"]
+    #[doc = " ::cudaGraphAddChildGraphNode,"]
+    #[doc = " ::cudaGraphAddEmptyNode,"]
+    #[doc = " ::cudaGraphAddKernelNode,"]
+    #[doc = " ::cudaGraphAddHostNode,"]
+    #[doc = " ::cudaGraphAddMemsetNode"]
+    pub fn cudaGraphAddMemcpyNode(
+        pGraphNode: *mut cudaGraphNode_t,
+        graph: cudaGraph_t,
+        pDependencies: *const cudaGraphNode_t,
+        numDependencies: usize,
+        pCopyParams: *const cudaMemcpy3DParms,
+    ) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Creates a memset node and adds it to a graph"]
     #[doc = ""]
-    #[doc = " - ::cudaMemAdviseUnsetAccessedBy: Undoes the effect of ::cudaMemAdviseSetAccessedBy. Any mappings to"]
-    #[doc = " the data from \\p device may be removed at any time causing accesses to result in non-fatal page faults."]
-    #[doc = " If the memory region refers to valid system-allocated pageable memory, then \\p device must have a non-zero"]
-    #[doc = " value for the device attribute ::cudaDevAttrPageableMemoryAccess. Additionally, if \\p device has"]
-    #[doc = " a non-zero value for the device attribute ::cudaDevAttrPageableMemoryAccessUsesHostPageTables,"]
-    #[doc = " then this call has no effect."]
+    #[doc = " Creates a new memset node and adds it to \\p graph with \\p numDependencies"]
+    #[doc = " dependencies specified via \\p pDependencies."]
     #[doc = ""]
-    #[doc = " \\param devPtr - Pointer to memory to set the advice for"]
-    #[doc = " \\param count  - Size in bytes of the memory range"]
-    #[doc = " \\param advice - Advice to be applied for the specified memory range"]
-    #[doc = " \\param device - Device to apply the advice for"]
+    #[doc = " The element size must be 1, 2, or 4 bytes."]
+    #[doc = " When the graph is launched, the node will perform the memset described by \\p pMemsetParams."]
+    #[doc = ""]
+    #[doc = " The node will be placed at the root of \\p graph if \\p numDependencies is 0."]
+    #[doc = " \\p pDependencies may not have any duplicate entries."]
+    #[doc = " A handle to the new node will be returned in \\p pGraphNode."]
+    #[doc = ""]
+    #[doc = " \\param pGraphNode      - Returns newly created node"]
+    #[doc = " \\param graph           - Graph to which to add the node"]
+    #[doc = " \\param pDependencies   - Dependencies of the node"]
+    #[doc = " \\param numDependencies - Number of dependencies"]
+    #[doc = " \\param pMemsetParams   - Parameters for the memory set"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
-    #[doc = " \\note_async"]
-    #[doc = " \\note_null_stream"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemcpy, ::cudaMemcpyPeer, ::cudaMemcpyAsync,"]
-    #[doc = " ::cudaMemcpy3DPeerAsync, ::cudaMemPrefetchAsync,"]
-    #[doc = " ::cuMemAdvise"]
-    pub fn cudaMemAdvise(
-        devPtr: *const ::std::os::raw::c_void,
-        count: usize,
-        advice: cudaMemoryAdvise,
-        device: ::std::os::raw::c_int,
+    #[doc = " \\sa"]
+    #[doc = " ::cudaMemset2D,"]
+    #[doc = " ::cudaGraphMemsetNodeGetParams,"]
+    #[doc = " ::cudaGraphMemsetNodeSetParams,"]
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphDestroyNode,"]
+    #[doc = " ::cudaGraphAddChildGraphNode,"]
+    #[doc = " ::cudaGraphAddEmptyNode,"]
+    #[doc = " ::cudaGraphAddKernelNode,"]
+    #[doc = " ::cudaGraphAddHostNode,"]
+    #[doc = " ::cudaGraphAddMemcpyNode"]
+    pub fn cudaGraphAddMemsetNode(
+        pGraphNode: *mut cudaGraphNode_t,
+        graph: cudaGraph_t,
+        pDependencies: *const cudaGraphNode_t,
+        numDependencies: usize,
+        pMemsetParams: *const cudaMemsetParams,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Query an attribute of a given memory range"]
+    #[doc = " \\brief Creates a host execution node and adds it to a graph"]
     #[doc = ""]
-    #[doc = " Query an attribute about the memory range starting at \\p devPtr with a size of \\p count bytes. The"]
-    #[doc = " memory range must refer to managed memory allocated via ::cudaMallocManaged or declared via"]
-    #[doc = " __managed__ variables."]
+    #[doc = " Creates a new CPU execution node and adds it to \\p graph with \\p numDependencies"]
+    #[doc = " dependencies specified via \\p pDependencies and arguments specified in \\p pNodeParams."]
     #[doc = ""]
-    #[doc = " The \\p attribute parameter can take the following values:"]
-    #[doc = " - ::cudaMemRangeAttributeReadMostly: If this attribute is specified, \\p data will be interpreted"]
-    #[doc = " as a 32-bit integer, and \\p dataSize must be 4. The result returned will be 1 if all pages in the given"]
-    #[doc = " memory range have read-duplication enabled, or 0 otherwise."]
-    #[doc = " - ::cudaMemRangeAttributePreferredLocation: If this attribute is specified, \\p data will be"]
-    #[doc = " interpreted as a 32-bit integer, and \\p dataSize must be 4. The result returned will be a GPU device"]
-    #[doc = " id if all pages in the memory range have that GPU as their preferred location, or it will be cudaCpuDeviceId"]
-    #[doc = " if all pages in the memory range have the CPU as their preferred location, or it will be cudaInvalidDeviceId"]
-    #[doc = " if either all the pages don\'t have the same preferred location or some of the pages don\'t have a"]
-    #[doc = " preferred location at all. Note that the actual location of the pages in the memory range at the time of"]
-    #[doc = " the query may be different from the preferred location."]
-    #[doc = " - ::cudaMemRangeAttributeAccessedBy: If this attribute is specified, \\p data will be interpreted"]
-    #[doc = " as an array of 32-bit integers, and \\p dataSize must be a non-zero multiple of 4. The result returned"]
-    #[doc = " will be a list of device ids that had ::cudaMemAdviceSetAccessedBy set for that entire memory range."]
-    #[doc = " If any device does not have that advice set for the entire memory range, that device will not be included."]
-    #[doc = " If \\p data is larger than the number of devices that have that advice set for that memory range,"]
-    #[doc = " cudaInvalidDeviceId will be returned in all the extra space provided. For ex., if \\p dataSize is 12"]
-    #[doc = " (i.e. \\p data has 3 elements) and only device 0 has the advice set, then the result returned will be"]
-    #[doc = " { 0, cudaInvalidDeviceId, cudaInvalidDeviceId }. If \\p data is smaller than the number of devices that have"]
-    #[doc = " that advice set, then only as many devices will be returned as can fit in the array. There is no"]
-    #[doc = " guarantee on which specific devices will be returned, however."]
-    #[doc = " - ::cudaMemRangeAttributeLastPrefetchLocation: If this attribute is specified, \\p data will be"]
-    #[doc = " interpreted as a 32-bit integer, and \\p dataSize must be 4. The result returned will be the last location"]
-    #[doc = " to which all pages in the memory range were prefetched explicitly via ::cudaMemPrefetchAsync. This will either be"]
-    #[doc = " a GPU id or cudaCpuDeviceId depending on whether the last location for prefetch was a GPU or the CPU"]
-    #[doc = " respectively. If any page in the memory range was never explicitly prefetched or if all pages were not"]
-    #[doc = " prefetched to the same location, cudaInvalidDeviceId will be returned. Note that this simply returns the"]
-    #[doc = " last location that the applicaton requested to prefetch the memory range to. It gives no indication as to"]
-    #[doc = " whether the prefetch operation to that location has completed or even begun."]
+    #[doc = " When the graph is launched, the node will invoke the specified CPU function."]
     #[doc = ""]
-    #[doc = " \\param data      - A pointers to a memory location where the result"]
-    #[doc = "                    of each attribute query will be written to."]
-    #[doc = " \\param dataSize  - Array containing the size of data"]
-    #[doc = " \\param attribute - The attribute to query"]
-    #[doc = " \\param devPtr    - Start of the range to query"]
-    #[doc = " \\param count     - Size of the range to query"]
+    #[doc = " The node will be placed at the root of \\p graph if \\p numDependencies is 0."]
+    #[doc = " \\p pDependencies may not have any duplicate entries."]
+    #[doc = " A handle to the new node will be returned in \\p pGraphNode."]
+    #[doc = ""]
+    #[doc = " \\param pGraphNode      - Returns newly created node"]
+    #[doc = " \\param graph           - Graph to which to add the node"]
+    #[doc = " \\param pDependencies   - Dependencies of the node"]
+    #[doc = " \\param numDependencies - Number of dependencies"]
+    #[doc = " \\param pNodeParams     - Parameters for the host node"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
     #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
-    #[doc = " \\note_async"]
-    #[doc = " \\note_null_stream"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemRangeGetAttributes, ::cudaMemPrefetchAsync,"]
-    #[doc = " ::cudaMemAdvise,"]
-    #[doc = " ::cuMemRangeGetAttribute"]
-    pub fn cudaMemRangeGetAttribute(
-        data: *mut ::std::os::raw::c_void,
-        dataSize: usize,
-        attribute: cudaMemRangeAttribute,
-        devPtr: *const ::std::os::raw::c_void,
-        count: usize,
+    #[doc = " \\sa"]
+    #[doc = " ::cudaLaunchHostFunc,"]
+    #[doc = " ::cudaGraphHostNodeGetParams,"]
+    #[doc = " ::cudaGraphHostNodeSetParams,"]
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphDestroyNode,"]
+    #[doc = " ::cudaGraphAddChildGraphNode,"]
+    #[doc = " ::cudaGraphAddEmptyNode,"]
+    #[doc = " ::cudaGraphAddKernelNode,"]
+    #[doc = " ::cudaGraphAddMemcpyNode,"]
+    #[doc = " ::cudaGraphAddMemsetNode"]
+    pub fn cudaGraphAddHostNode(
+        pGraphNode: *mut cudaGraphNode_t,
+        graph: cudaGraph_t,
+        pDependencies: *const cudaGraphNode_t,
+        numDependencies: usize,
+        pNodeParams: *const cudaHostNodeParams,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Query attributes of a given memory range."]
-    #[doc = ""]
-    #[doc = " Query attributes of the memory range starting at \\p devPtr with a size of \\p count bytes. The"]
-    #[doc = " memory range must refer to managed memory allocated via ::cudaMallocManaged or declared via"]
-    #[doc = " __managed__ variables. The \\p attributes array will be interpreted to have \\p numAttributes"]
-    #[doc = " entries. The \\p dataSizes array will also be interpreted to have \\p numAttributes entries."]
-    #[doc = " The results of the query will be stored in \\p data."]
-    #[doc = ""]
-    #[doc = " The list of supported attributes are given below. Please refer to ::cudaMemRangeGetAttribute for"]
-    #[doc = " attribute descriptions and restrictions."]
+    #[doc = " \\brief Creates a child graph node and adds it to a graph"]
     #[doc = ""]
-    #[doc = " - ::cudaMemRangeAttributeReadMostly"]
-    #[doc = " - ::cudaMemRangeAttributePreferredLocation"]
-    #[doc = " - ::cudaMemRangeAttributeAccessedBy"]
-    #[doc = " - ::cudaMemRangeAttributeLastPrefetchLocation"]
+    #[doc = " Creates a new node which executes an embedded graph, and adds it to \\p graph with"]
+    #[doc = " \\p numDependencies dependencies specified via \\p pDependencies."]
     #[doc = ""]
-    #[doc = " \\param data          - A two-dimensional array containing pointers to memory"]
-    #[doc = "                        locations where the result of each attribute query will be written to."]
-    #[doc = " \\param dataSizes     - Array containing the sizes of each result"]
-    #[doc = " \\param attributes    - An array of attributes to query"]
-    #[doc = "                        (numAttributes and the number of attributes in this array should match)"]
-    #[doc = " \\param numAttributes - Number of attributes to query"]
-    #[doc = " \\param devPtr        - Start of the range to query"]
-    #[doc = " \\param count         - Size of the range to query"]
+    #[doc = " The node executes an embedded child graph. The child graph is cloned in this call."]
+    #[doc = ""]
+    #[doc = " The node will be placed at the root of \\p graph if \\p numDependencies is 0."]
+    #[doc = " \\p pDependencies may not have any duplicate entries."]
+    #[doc = " A handle to the new node will be returned in \\p pGraphNode."]
+    #[doc = ""]
+    #[doc = " \\param pGraphNode      - Returns newly created node"]
+    #[doc = " \\param graph           - Graph to which to add the node"]
+    #[doc = " \\param pDependencies   - Dependencies of the node"]
+    #[doc = " \\param numDependencies - Number of dependencies"]
+    #[doc = " \\param childGraph      - The graph to clone into this node"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
     #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaMemRangeGetAttribute, ::cudaMemAdvise"]
-    #[doc = " ::cudaMemPrefetchAsync,"]
-    #[doc = " ::cuMemRangeGetAttributes"]
-    pub fn cudaMemRangeGetAttributes(
-        data: *mut *mut ::std::os::raw::c_void,
-        dataSizes: *mut usize,
-        attributes: *mut cudaMemRangeAttribute,
-        numAttributes: usize,
-        devPtr: *const ::std::os::raw::c_void,
-        count: usize,
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphChildGraphNodeGetGraph,"]
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphDestroyNode,"]
+    #[doc = " ::cudaGraphAddEmptyNode,"]
+    #[doc = " ::cudaGraphAddKernelNode,"]
+    #[doc = " ::cudaGraphAddHostNode,"]
+    #[doc = " ::cudaGraphAddMemcpyNode,"]
+    #[doc = " ::cudaGraphAddMemsetNode,"]
+    #[doc = " ::cudaGraphClone"]
+    pub fn cudaGraphAddChildGraphNode(
+        pGraphNode: *mut cudaGraphNode_t,
+        graph: cudaGraph_t,
+        pDependencies: *const cudaGraphNode_t,
+        numDependencies: usize,
+        childGraph: cudaGraph_t,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Queries if a device may directly access a peer device\'s memory."]
+    #[doc = " \\brief Creates an empty node and adds it to a graph"]
     #[doc = ""]
-    #[doc = " Returns in \\p *canAccessPeer a value of 1 if device \\p device is capable of"]
-    #[doc = " directly accessing memory from \\p peerDevice and 0 otherwise.  If direct"]
-    #[doc = " access of \\p peerDevice from \\p device is possible, then access may be"]
-    #[doc = " enabled by calling ::cudaDeviceEnablePeerAccess()."]
+    #[doc = " Creates a new node which performs no operation, and adds it to \\p graph with"]
+    #[doc = " \\p numDependencies dependencies specified via \\p pDependencies."]
     #[doc = ""]
-    #[doc = " \\param canAccessPeer - Returned access capability"]
-    #[doc = " \\param device        - Device from which allocations on \\p peerDevice are to"]
-    #[doc = "                        be directly accessed."]
-    #[doc = " \\param peerDevice    - Device on which the allocations to be directly accessed"]
-    #[doc = "                        by \\p device reside."]
+    #[doc = " An empty node performs no operation during execution, but can be used for"]
+    #[doc = " transitive ordering. For example, a phased execution graph with 2 groups of n"]
+    #[doc = " nodes with a barrier between them can be represented using an empty node and"]
+    #[doc = " 2*n dependency edges, rather than no empty node and n^2 dependency edges."]
+    #[doc = ""]
+    #[doc = " The node will be placed at the root of \\p graph if \\p numDependencies is 0."]
+    #[doc = " \\p pDependencies may not have any duplicate entries."]
+    #[doc = " A handle to the new node will be returned in \\p pGraphNode."]
+    #[doc = ""]
+    #[doc = " \\param pGraphNode      - Returns newly created node"]
+    #[doc = " \\param graph           - Graph to which to add the node"]
+    #[doc = " \\param pDependencies   - Dependencies of the node"]
+    #[doc = " \\param numDependencies - Number of dependencies"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaDeviceEnablePeerAccess,"]
-    #[doc = " ::cudaDeviceDisablePeerAccess,"]
-    #[doc = " ::cuDeviceCanAccessPeer"]
-    pub fn cudaDeviceCanAccessPeer(
-        canAccessPeer: *mut ::std::os::raw::c_int,
-        device: ::std::os::raw::c_int,
-        peerDevice: ::std::os::raw::c_int,
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphDestroyNode,"]
+    #[doc = " ::cudaGraphAddChildGraphNode,"]
+    #[doc = " ::cudaGraphAddKernelNode,"]
+    #[doc = " ::cudaGraphAddHostNode,"]
+    #[doc = " ::cudaGraphAddMemcpyNode,"]
+    #[doc = " ::cudaGraphAddMemsetNode"]
+    pub fn cudaGraphAddEmptyNode(
+        pGraphNode: *mut cudaGraphNode_t,
+        graph: cudaGraph_t,
+        pDependencies: *const cudaGraphNode_t,
+        numDependencies: usize,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Enables direct access to memory allocations on a peer device."]
+    #[doc = " \\brief Clones a graph"]
     #[doc = ""]
-    #[doc = " On success, all allocations from \\p peerDevice will immediately be accessible by"]
-    #[doc = " the current device.  They will remain accessible until access is explicitly"]
-    #[doc = " disabled using ::cudaDeviceDisablePeerAccess() or either device is reset using"]
-    #[doc = " ::cudaDeviceReset()."]
+    #[doc = " This function creates a copy of \\p originalGraph and returns it in \\p pGraphClone."]
+    #[doc = " All parameters are copied into the cloned graph. The original graph may be modified"]
+    #[doc = " after this call without affecting the clone."]
     #[doc = ""]
-    #[doc = " Note that access granted by this call is unidirectional and that in order to access"]
-    #[doc = " memory on the current device from \\p peerDevice, a separate symmetric call"]
-    #[doc = " to ::cudaDeviceEnablePeerAccess() is required."]
+    #[doc = " Child graph nodes in the original graph are recursively copied into the clone."]
     #[doc = ""]
-    #[doc = " Each device can support a system-wide maximum of eight peer connections."]
+    #[doc = " \\param pGraphClone  - Returns newly created cloned graph"]
+    #[doc = " \\param originalGraph - Graph to clone"]
     #[doc = ""]
-    #[doc = " Peer access is not supported in 32 bit applications."]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess,"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " ::cudaErrorMemoryAllocation"]
+    #[doc = " \\note_graph_thread_safety"]
+    #[doc = " \\notefnerr"]
+    #[doc = " \\note_init_rt"]
+    #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " Returns ::cudaErrorInvalidDevice if ::cudaDeviceCanAccessPeer() indicates"]
-    #[doc = " that the current device cannot directly access memory from \\p peerDevice."]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphNodeFindInClone"]
+    pub fn cudaGraphClone(pGraphClone: *mut cudaGraph_t, originalGraph: cudaGraph_t)
+        -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Finds a cloned version of a node"]
     #[doc = ""]
-    #[doc = " Returns ::cudaErrorPeerAccessAlreadyEnabled if direct access of"]
-    #[doc = " \\p peerDevice from the current device has already been enabled."]
+    #[doc = " This function returns the node in \\p clonedGraph corresponding to \\p originalNode"]
+    #[doc = " in the original graph."]
     #[doc = ""]
-    #[doc = " Returns ::cudaErrorInvalidValue if \\p flags is not 0."]
+    #[doc = " \\p clonedGraph must have been cloned from \\p originalGraph via ::cudaGraphClone."]
+    #[doc = " \\p originalNode must have been in \\p originalGraph at the time of the call to"]
+    #[doc = " ::cudaGraphClone, and the corresponding cloned node in \\p clonedGraph must not have"]
+    #[doc = " been removed. The cloned node is then returned via \\p pClonedNode."]
     #[doc = ""]
-    #[doc = " \\param peerDevice  - Peer device to enable direct access to from the current device"]
-    #[doc = " \\param flags       - Reserved for future use and must be set to 0"]
+    #[doc = " \\param pNode  - Returns handle to the cloned node"]
+    #[doc = " \\param originalNode - Handle to the original node"]
+    #[doc = " \\param clonedGraph - Cloned graph to query"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidDevice,"]
-    #[doc = " ::cudaErrorPeerAccessAlreadyEnabled,"]
-    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " ::cudaErrorInvalidValue,"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaDeviceCanAccessPeer,"]
-    #[doc = " ::cudaDeviceDisablePeerAccess,"]
-    #[doc = " ::cuCtxEnablePeerAccess"]
-    pub fn cudaDeviceEnablePeerAccess(
-        peerDevice: ::std::os::raw::c_int,
-        flags: ::std::os::raw::c_uint,
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphClone"]
+    pub fn cudaGraphNodeFindInClone(
+        pNode: *mut cudaGraphNode_t,
+        originalNode: cudaGraphNode_t,
+        clonedGraph: cudaGraph_t,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Disables direct access to memory allocations on a peer device."]
+    #[doc = " \\brief Returns a node\'s type"]
     #[doc = ""]
-    #[doc = " Returns ::cudaErrorPeerAccessNotEnabled if direct access to memory on"]
-    #[doc = " \\p peerDevice has not yet been enabled from the current device."]
+    #[doc = " Returns the node type of \\p node in \\p pType."]
     #[doc = ""]
-    #[doc = " \\param peerDevice - Peer device to disable direct access to"]
+    #[doc = " \\param node - Node to query"]
+    #[doc = " \\param pType  - Pointer to return the node type"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorPeerAccessNotEnabled,"]
-    #[doc = " ::cudaErrorInvalidDevice"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
-    #[doc = " \\sa ::cudaDeviceCanAccessPeer,"]
-    #[doc = " ::cudaDeviceEnablePeerAccess,"]
-    #[doc = " ::cuCtxDisablePeerAccess"]
-    pub fn cudaDeviceDisablePeerAccess(peerDevice: ::std::os::raw::c_int) -> cudaError_t;
+    #[doc = " \\sa"]
+    #[doc = " ::cudaGraphGetNodes,"]
+    #[doc = " ::cudaGraphGetRootNodes"]
+    pub fn cudaGraphNodeGetType(
+        node: cudaGraphNode_t,
+        pType: *mut cudaGraphNodeType,
+    ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Unregisters a graphics resource for access by CUDA"]
-    #[doc = ""]
-    #[doc = " Unregisters the graphics resource \\p resource so it is not accessible by"]
-    #[doc = " CUDA unless registered again."]
+    #[doc = " \\brief Returns a graph\'s nodes"]
     #[doc = ""]
-    #[doc = " If \\p resource is invalid then ::cudaErrorInvalidResourceHandle is"]
-    #[doc = " returned."]
+    #[doc = " Returns a list of \\p graph\'s nodes. \\p nodes may be NULL, in which case this"]
+    #[doc = " function will return the number of nodes in \\p numNodes. Otherwise,"]
+    #[doc = " \\p numNodes entries will be filled in. If \\p numNodes is higher than the actual"]
+    #[doc = " number of nodes, the remaining entries in \\p nodes will be set to NULL, and the"]
+    #[doc = " number of nodes actually obtained will be returned in \\p numNodes."]
     #[doc = ""]
-    #[doc = " \\param resource - Resource to unregister"]
+    #[doc = " \\param graph    - Graph to query"]
+    #[doc = " \\param nodes    - Pointer to return the nodes"]
+    #[doc = " \\param numNodes - See description"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidResourceHandle,"]
-    #[doc = " ::cudaErrorUnknown"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
     #[doc = " \\sa"]
-    #[doc = " ::cudaGraphicsD3D9RegisterResource,"]
-    #[doc = " ::cudaGraphicsD3D10RegisterResource,"]
-    #[doc = " ::cudaGraphicsD3D11RegisterResource,"]
-    #[doc = " ::cudaGraphicsGLRegisterBuffer,"]
-    #[doc = " ::cudaGraphicsGLRegisterImage,"]
-    #[doc = " ::cuGraphicsUnregisterResource"]
-    pub fn cudaGraphicsUnregisterResource(resource: cudaGraphicsResource_t) -> cudaError_t;
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphGetRootNodes,"]
+    #[doc = " ::cudaGraphGetEdges,"]
+    #[doc = " ::cudaGraphNodeGetType"]
+    pub fn cudaGraphGetNodes(
+        graph: cudaGraph_t,
+        nodes: *mut cudaGraphNode_t,
+        numNodes: *mut usize,
+    ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Set usage flags for mapping a graphics resource"]
-    #[doc = ""]
-    #[doc = " Set \\p flags for mapping the graphics resource \\p resource."]
-    #[doc = ""]
-    #[doc = " Changes to \\p flags will take effect the next time \\p resource is mapped."]
-    #[doc = " The \\p flags argument may be any of the following:"]
-    #[doc = " - ::cudaGraphicsMapFlagsNone: Specifies no hints about how \\p resource will"]
-    #[doc = "     be used. It is therefore assumed that CUDA may read from or write to \\p resource."]
-    #[doc = " - ::cudaGraphicsMapFlagsReadOnly: Specifies that CUDA will not write to \\p resource."]
-    #[doc = " - ::cudaGraphicsMapFlagsWriteDiscard: Specifies CUDA will not read from \\p resource and will"]
-    #[doc = "   write over the entire contents of \\p resource, so none of the data"]
-    #[doc = "   previously stored in \\p resource will be preserved."]
+    #[doc = " \\brief Returns a graph\'s dependency edges"]
     #[doc = ""]
-    #[doc = " If \\p resource is presently mapped for access by CUDA then ::cudaErrorUnknown is returned."]
-    #[doc = " If \\p flags is not one of the above values then ::cudaErrorInvalidValue is returned."]
+    #[doc = " Returns a list of \\p graph\'s dependency edges. Edges are returned via corresponding"]
+    #[doc = " indices in \\p from and \\p to; that is, the node in \\p to[i] has a dependency on the"]
+    #[doc = " node in \\p from[i]. \\p from and \\p to may both be NULL, in which"]
+    #[doc = " case this function only returns the number of edges in \\p numEdges. Otherwise,"]
+    #[doc = " \\p numEdges entries will be filled in. If \\p numEdges is higher than the actual"]
+    #[doc = " number of edges, the remaining entries in \\p from and \\p to will be set to NULL, and"]
+    #[doc = " the number of edges actually returned will be written to \\p numEdges."]
     #[doc = ""]
-    #[doc = " \\param resource - Registered resource to set flags for"]
-    #[doc = " \\param flags    - Parameters for resource mapping"]
+    #[doc = " \\param graph    - Graph to get the edges from"]
+    #[doc = " \\param from     - Location to return edge endpoints"]
+    #[doc = " \\param to       - Location to return edge endpoints"]
+    #[doc = " \\param numEdges - See description"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidResourceHandle,"]
-    #[doc = " ::cudaErrorUnknown,"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
     #[doc = " \\sa"]
-    #[doc = " ::cudaGraphicsMapResources,"]
-    #[doc = " ::cuGraphicsResourceSetMapFlags"]
-    pub fn cudaGraphicsResourceSetMapFlags(
-        resource: cudaGraphicsResource_t,
-        flags: ::std::os::raw::c_uint,
+    #[doc = " ::cudaGraphGetNodes,"]
+    #[doc = " ::cudaGraphGetRootNodes,"]
+    #[doc = " ::cudaGraphAddDependencies"]
+    pub fn cudaGraphGetEdges(
+        graph: cudaGraph_t,
+        from: *mut cudaGraphNode_t,
+        to: *mut cudaGraphNode_t,
+        numEdges: *mut usize,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Map graphics resources for access by CUDA"]
-    #[doc = ""]
-    #[doc = " Maps the \\p count graphics resources in \\p resources for access by CUDA."]
-    #[doc = ""]
-    #[doc = " The resources in \\p resources may be accessed by CUDA until they"]
-    #[doc = " are unmapped. The graphics API from which \\p resources were registered"]
-    #[doc = " should not access any resources while they are mapped by CUDA. If an"]
-    #[doc = " application does so, the results are undefined."]
+    #[doc = " \\brief Adds dependency edges to a graph."]
     #[doc = ""]
-    #[doc = " This function provides the synchronization guarantee that any graphics calls"]
-    #[doc = " issued before ::cudaGraphicsMapResources() will complete before any subsequent CUDA"]
-    #[doc = " work issued in \\p stream begins."]
+    #[doc = " The number of dependencies to be added is defined by \\p numDependencies"]
+    #[doc = " Elements in \\p pFrom and \\p pTo at corresponding indices define a dependency."]
+    #[doc = " Each node in \\p pFrom and \\p pTo must belong to \\p graph."]
     #[doc = ""]
-    #[doc = " If \\p resources contains any duplicate entries then ::cudaErrorInvalidResourceHandle"]
-    #[doc = " is returned. If any of \\p resources are presently mapped for access by"]
-    #[doc = " CUDA then ::cudaErrorUnknown is returned."]
+    #[doc = " If \\p numDependencies is 0, elements in \\p pFrom and \\p pTo will be ignored."]
+    #[doc = " Specifying an existing dependency will return an error."]
     #[doc = ""]
-    #[doc = " \\param count     - Number of resources to map"]
-    #[doc = " \\param resources - Resources to map for CUDA"]
-    #[doc = " \\param stream    - Stream for synchronization"]
+    #[doc = " \\param graph - Graph to which dependencies are added"]
+    #[doc = " \\param from - Array of nodes that provide the dependencies"]
+    #[doc = " \\param to - Array of dependent nodes"]
+    #[doc = " \\param numDependencies - Number of dependencies to be added"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidResourceHandle,"]
-    #[doc = " ::cudaErrorUnknown"]
-    #[doc = " \\note_null_stream"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
     #[doc = " \\sa"]
-    #[doc = " ::cudaGraphicsResourceGetMappedPointer,"]
-    #[doc = " ::cudaGraphicsSubResourceGetMappedArray,"]
-    #[doc = " ::cudaGraphicsUnmapResources,"]
-    #[doc = " ::cuGraphicsMapResources"]
-    pub fn cudaGraphicsMapResources(
-        count: ::std::os::raw::c_int,
-        resources: *mut cudaGraphicsResource_t,
-        stream: cudaStream_t,
+    #[doc = " ::cudaGraphGetEdges"]
+    pub fn cudaGraphAddDependencies(
+        graph: cudaGraph_t,
+        from: *const cudaGraphNode_t,
+        to: *const cudaGraphNode_t,
+        numDependencies: usize,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Unmap graphics resources."]
-    #[doc = ""]
-    #[doc = " Unmaps the \\p count graphics resources in \\p resources."]
-    #[doc = ""]
-    #[doc = " Once unmapped, the resources in \\p resources may not be accessed by CUDA"]
-    #[doc = " until they are mapped again."]
-    #[doc = ""]
-    #[doc = " This function provides the synchronization guarantee that any CUDA work issued"]
-    #[doc = " in \\p stream before ::cudaGraphicsUnmapResources() will complete before any"]
-    #[doc = " subsequently issued graphics work begins."]
-    #[doc = ""]
-    #[doc = " If \\p resources contains any duplicate entries then ::cudaErrorInvalidResourceHandle"]
-    #[doc = " is returned. If any of \\p resources are not presently mapped for access by"]
-    #[doc = " CUDA then ::cudaErrorUnknown is returned."]
-    #[doc = ""]
-    #[doc = " \\param count     - Number of resources to unmap"]
-    #[doc = " \\param resources - Resources to unmap"]
-    #[doc = " \\param stream    - Stream for synchronization"]
+    #[doc = " \\brief Creates an executable graph from a graph"]
+    #[doc = ""]
+    #[doc = " Instantiates \\p graph as an executable graph. The graph is validated for any"]
+    #[doc = " structural constraints or intra-node constraints which were not previously"]
+    #[doc = " validated. If instantiation is successful, a handle to the instantiated graph"]
+    #[doc = " is returned in \\p pGraphExec."]
+    #[doc = ""]
+    #[doc = " If there are any errors, diagnostic information may be returned in \\p pErrorNode and"]
+    #[doc = " \\p pLogBuffer. This is the primary way to inspect instantiation errors. The output"]
+    #[doc = " will be null terminated unless the diagnostics overflow"]
+    #[doc = " the buffer. In this case, they will be truncated, and the last byte can be"]
+    #[doc = " inspected to determine if truncation occurred."]
+    #[doc = ""]
+    #[doc = " \\param pGraphExec - Returns instantiated graph"]
+    #[doc = " \\param graph      - Graph to instantiate"]
+    #[doc = " \\param pErrorNode - In case of an instantiation error, this may be modified to"]
+    #[doc = "                      indicate a node contributing to the error"]
+    #[doc = " \\param pLogBuffer   - A character buffer to store diagnostic messages"]
+    #[doc = " \\param bufferSize  - Size of the log buffer in bytes"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidResourceHandle,"]
-    #[doc = " ::cudaErrorUnknown"]
-    #[doc = " \\note_null_stream"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
     #[doc = " \\sa"]
-    #[doc = " ::cudaGraphicsMapResources,"]
-    #[doc = " ::cuGraphicsUnmapResources"]
-    pub fn cudaGraphicsUnmapResources(
-        count: ::std::os::raw::c_int,
-        resources: *mut cudaGraphicsResource_t,
-        stream: cudaStream_t,
+    #[doc = " ::cudaGraphCreate,"]
+    #[doc = " ::cudaGraphLaunch,"]
+    #[doc = " ::cudaGraphExecDestroy"]
+    pub fn cudaGraphInstantiate(
+        pGraphExec: *mut cudaGraphExec_t,
+        graph: cudaGraph_t,
+        pErrorNode: *mut cudaGraphNode_t,
+        pLogBuffer: *mut ::std::os::raw::c_char,
+        bufferSize: usize,
     ) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Get an device pointer through which to access a mapped graphics resource."]
+    #[doc = " \\brief Launches an executable graph in a stream"]
     #[doc = ""]
-    #[doc = " Returns in \\p *devPtr a pointer through which the mapped graphics resource"]
-    #[doc = " \\p resource may be accessed."]
-    #[doc = " Returns in \\p *size the size of the memory in bytes which may be accessed from that pointer."]
-    #[doc = " The value set in \\p devPtr may change every time that \\p resource is mapped."]
+    #[doc = " Executes \\p graphExec in \\p stream. Only one instance of \\p graphExec may be executing"]
+    #[doc = " at a time. Each launch is ordered behind both any previous work in \\p stream"]
+    #[doc = " and any previous launches of \\p graphExec. To execute a graph concurrently, it must be"]
+    #[doc = " instantiated multiple times into multiple executable graphs."]
     #[doc = ""]
-    #[doc = " If \\p resource is not a buffer then it cannot be accessed via a pointer and"]
-    #[doc = " ::cudaErrorUnknown is returned."]
-    #[doc = " If \\p resource is not mapped then ::cudaErrorUnknown is returned."]
-    #[doc = " *"]
-    #[doc = " \\param devPtr     - Returned pointer through which \\p resource may be accessed"]
-    #[doc = " \\param size       - Returned size of the buffer accessible starting at \\p *devPtr"]
-    #[doc = " \\param resource   - Mapped resource to access"]
+    #[doc = " \\param graphExec - Executable graph to launch"]
+    #[doc = " \\param stream    - Stream in which to launch the graph"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
-    #[doc = " ::cudaErrorInvalidValue,"]
-    #[doc = " ::cudaErrorInvalidResourceHandle,"]
-    #[doc = " ::cudaErrorUnknown"]
+    #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
     #[doc = ""]
     #[doc = " \\sa"]
-    #[doc = " ::cudaGraphicsMapResources,"]
-    #[doc = " ::cudaGraphicsSubResourceGetMappedArray,"]
-    #[doc = " ::cuGraphicsResourceGetMappedPointer"]
-    pub fn cudaGraphicsResourceGetMappedPointer(
-        devPtr: *mut *mut ::std::os::raw::c_void,
-        size: *mut usize,
-        resource: cudaGraphicsResource_t,
-    ) -> cudaError_t;
+    #[doc = " ::cudaGraphInstantiate,"]
+    #[doc = " ::cudaGraphExecDestroy"]
+    pub fn cudaGraphLaunch(graphExec: cudaGraphExec_t, stream: cudaStream_t) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Returns the latest version of CUDA supported by the driver"]
-    #[doc = ""]
-    #[doc = " Returns in \\p *driverVersion the latest version of CUDA supported by"]
-    #[doc = " the driver. The version is returned as (1000 &times; major + 10 &times; minor)."]
-    #[doc = " For example, CUDA 9.2 would be represented by 9020. If no driver is installed,"]
-    #[doc = " then 0 is returned as the driver version."]
+    #[doc = " \\brief Destroys an executable graph"]
     #[doc = ""]
-    #[doc = " This function automatically returns ::cudaErrorInvalidValue"]
-    #[doc = " if \\p driverVersion is NULL."]
+    #[doc = " Destroys the executable graph specified by \\p graphExec."]
     #[doc = ""]
-    #[doc = " \\param driverVersion - Returns the CUDA driver version."]
+    #[doc = " \\param graphExec - Executable graph to destroy"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
     #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
     #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
+    #[doc = " \\note_destroy_ub"]
     #[doc = ""]
     #[doc = " \\sa"]
-    #[doc = " ::cudaRuntimeGetVersion,"]
-    #[doc = " ::cuDriverGetVersion"]
-    pub fn cudaDriverGetVersion(driverVersion: *mut ::std::os::raw::c_int) -> cudaError_t;
+    #[doc = " ::cudaGraphInstantiate,"]
+    #[doc = " ::cudaGraphLaunch"]
+    pub fn cudaGraphExecDestroy(graphExec: cudaGraphExec_t) -> cudaError_t;
 }
 extern "C" {
-    #[doc = " \\brief Returns the CUDA Runtime version"]
-    #[doc = ""]
-    #[doc = " Returns in \\p *runtimeVersion the version number of the current CUDA"]
-    #[doc = " Runtime instance. The version is returned as"]
-    #[doc = " (1000 &times; major + 10 &times; minor). For example,"]
-    #[doc = " CUDA 9.2 would be represented by 9020."]
+    #[doc = " \\brief Destroys a graph"]
     #[doc = ""]
-    #[doc = " This function automatically returns ::cudaErrorInvalidValue if"]
-    #[doc = " the \\p runtimeVersion argument is NULL."]
+    #[doc = " Destroys the graph specified by \\p graph, as well as all of its nodes."]
     #[doc = ""]
-    #[doc = " \\param runtimeVersion - Returns the CUDA Runtime version."]
+    #[doc = " \\param graph - Graph to destroy"]
     #[doc = ""]
     #[doc = " \\return"]
     #[doc = " ::cudaSuccess,"]
     #[doc = " ::cudaErrorInvalidValue"]
+    #[doc = " \\note_graph_thread_safety"]
+    #[doc = " \\notefnerr"]
     #[doc = " \\note_init_rt"]
     #[doc = " \\note_callback"]
+    #[doc = " \\note_destroy_ub"]
     #[doc = ""]
     #[doc = " \\sa"]
-    #[doc = " ::cudaDriverGetVersion,"]
-    #[doc = " ::cuDriverGetVersion"]
-    pub fn cudaRuntimeGetVersion(runtimeVersion: *mut ::std::os::raw::c_int) -> cudaError_t;
+    #[doc = " ::cudaGraphCreate"]
+    pub fn cudaGraphDestroy(graph: cudaGraph_t) -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Enable profiling."]
+    #[doc = ""]
+    #[doc = " Enables profile collection by the active profiling tool for the"]
+    #[doc = " current context. If profiling is already enabled, then"]
+    #[doc = " cudaProfilerStart() has no effect."]
+    #[doc = ""]
+    #[doc = " cudaProfilerStart and cudaProfilerStop APIs are used to"]
+    #[doc = " programmatically control the profiling granularity by allowing"]
+    #[doc = " profiling to be done only on selective pieces of code."]
+    #[doc = ""]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess"]
+    #[doc = " \\notefnerr"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaProfilerInitialize,"]
+    #[doc = " ::cudaProfilerStop,"]
+    #[doc = " ::cuProfilerStart"]
+    pub fn cudaProfilerStart() -> cudaError_t;
+}
+extern "C" {
+    #[doc = " \\brief Disable profiling."]
+    #[doc = ""]
+    #[doc = " Disables profile collection by the active profiling tool for the"]
+    #[doc = " current context. If profiling is already disabled, then"]
+    #[doc = " cudaProfilerStop() has no effect."]
+    #[doc = ""]
+    #[doc = " cudaProfilerStart and cudaProfilerStop APIs are used to"]
+    #[doc = " programmatically control the profiling granularity by allowing"]
+    #[doc = " profiling to be done only on selective pieces of code."]
+    #[doc = ""]
+    #[doc = " \\return"]
+    #[doc = " ::cudaSuccess"]
+    #[doc = " \\notefnerr"]
+    #[doc = ""]
+    #[doc = " \\sa"]
+    #[doc = " ::cudaProfilerInitialize,"]
+    #[doc = " ::cudaProfilerStart,"]
+    #[doc = " ::cuProfilerStop"]
+    pub fn cudaProfilerStop() -> cudaError_t;
 }
@@ -0,0 +1,624 @@
+//! CUDA graphs (CUDA 10.0 and later).
+
+use crate::extent;
+use crate::ffi::*;
+use crate::launch::{CudaFunction, LaunchConfig, to_dim3};
+use crate::runtime::{CudaDevice, CudaError, CudaMemcpyKind, CudaResult, CudaStream, check_drop, checked_bytes};
+use crate::timeline;
+
+use cuda_api_types::driver_types::*;
+
+use std::collections::{HashMap};
+use std::convert::{TryFrom};
+use std::fmt::{Write};
+use std::mem::{zeroed};
+use std::os::raw::{c_uint, c_void};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::process::{abort};
+use std::ptr::{null_mut};
+use std::sync::{Arc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Which API calls from other threads are prohibited while a stream is
+/// capturing, see `cudaStreamCaptureMode`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CaptureMode {
+  /// Potentially unsafe calls are prohibited in all threads.
+  Global,
+  /// Potentially unsafe calls are prohibited only in the capturing thread.
+  ThreadLocal,
+  /// Potentially unsafe calls are not prohibited.
+  Relaxed,
+}
+
+#[cfg(cuda_gte_10_1)]
+impl CaptureMode {
+  fn to_raw(self) -> cudaStreamCaptureMode {
+    match self {
+      CaptureMode::Global       => cudaStreamCaptureMode_cudaStreamCaptureModeGlobal,
+      CaptureMode::ThreadLocal  => cudaStreamCaptureMode_cudaStreamCaptureModeThreadLocal,
+      CaptureMode::Relaxed      => cudaStreamCaptureMode_cudaStreamCaptureModeRelaxed,
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CaptureStatus {
+  /// The stream is not capturing.
+  None,
+  /// The stream is capturing.
+  Active,
+  /// The stream is capturing, but an error has invalidated the capture; it
+  /// must still be ended with `end_capture`.
+  Invalidated,
+}
+
+impl CudaStream {
+  /// Begin capturing the work enqueued on the stream into a graph, instead
+  /// of executing it. The 10.0 runtime only supports `CaptureMode::Global`;
+  /// later runtimes take the mode as a parameter.
+  ///
+  /// Corresponds to `cudaStreamBeginCapture`.
+  pub fn begin_capture(&mut self, mode: CaptureMode) -> CudaResult<()> {
+    #[cfg(not(cuda_gte_10_1))]
+    {
+      if mode != CaptureMode::Global {
+        return Err(CudaError::Runtime(cudaError_cudaErrorNotSupported));
+      }
+    }
+    #[cfg(not(cuda_gte_10_1))]
+    let ret = unsafe { cudaStreamBeginCapture(self.as_mut_ptr()) };
+    #[cfg(cuda_gte_10_1)]
+    let ret = unsafe { cudaStreamBeginCapture(self.as_mut_ptr(), mode.to_raw()) };
+    match ret {
+      cudaError_cudaSuccess => Ok(()),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// End capturing on the stream, returning the captured graph.
+  ///
+  /// Corresponds to `cudaStreamEndCapture`.
+  pub fn end_capture(&mut self) -> CudaResult<CudaGraph> {
+    let mut ptr: cudaGraph_t = null_mut();
+    match unsafe { cudaStreamEndCapture(self.as_mut_ptr(), &mut ptr as *mut cudaGraph_t) } {
+      cudaError_cudaSuccess => {
+        if ptr.is_null() {
+          return Err(CudaError::Runtime(cudaError_cudaErrorStreamCaptureInvalidated));
+        }
+        CudaGraph::from_raw(ptr)
+      }
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Query the capture status of the stream.
+  ///
+  /// Corresponds to `cudaStreamIsCapturing`.
+  pub fn capture_status(&mut self) -> CudaResult<CaptureStatus> {
+    let mut status: cudaStreamCaptureStatus = cudaStreamCaptureStatus_cudaStreamCaptureStatusNone;
+    match unsafe { cudaStreamIsCapturing(self.as_mut_ptr(), &mut status as *mut cudaStreamCaptureStatus) } {
+      cudaError_cudaSuccess => match status {
+        cudaStreamCaptureStatus_cudaStreamCaptureStatusNone => Ok(CaptureStatus::None),
+        cudaStreamCaptureStatus_cudaStreamCaptureStatusActive => Ok(CaptureStatus::Active),
+        cudaStreamCaptureStatus_cudaStreamCaptureStatusInvalidated => Ok(CaptureStatus::Invalidated),
+        _ => Err(CudaError::Runtime(cudaError_cudaErrorUnknown)),
+      },
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  pub fn is_capturing(&mut self) -> CudaResult<bool> {
+    Ok(self.capture_status()? != CaptureStatus::None)
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphNodeKind {
+  Kernel,
+  Memcpy,
+  Memset,
+  Host,
+  ChildGraph,
+  Empty,
+  /// A node type without a builder here, e.g. the event record and wait
+  /// nodes or memory alloc and free nodes that stream capture can produce
+  /// on later CUDA versions.
+  Other(cudaGraphNodeType),
+}
+
+impl GraphNodeKind {
+  fn from_raw(raw: cudaGraphNodeType) -> GraphNodeKind {
+    match raw {
+      cudaGraphNodeType_cudaGraphNodeTypeKernel => GraphNodeKind::Kernel,
+      cudaGraphNodeType_cudaGraphNodeTypeMemcpy => GraphNodeKind::Memcpy,
+      cudaGraphNodeType_cudaGraphNodeTypeMemset => GraphNodeKind::Memset,
+      cudaGraphNodeType_cudaGraphNodeTypeHost => GraphNodeKind::Host,
+      cudaGraphNodeType_cudaGraphNodeTypeGraph => GraphNodeKind::ChildGraph,
+      cudaGraphNodeType_cudaGraphNodeTypeEmpty => GraphNodeKind::Empty,
+      raw => GraphNodeKind::Other(raw),
+    }
+  }
+
+  pub fn name(&self) -> &'static str {
+    match *self {
+      GraphNodeKind::Kernel     => "kernel",
+      GraphNodeKind::Memcpy     => "memcpy",
+      GraphNodeKind::Memset     => "memset",
+      GraphNodeKind::Host       => "host",
+      GraphNodeKind::ChildGraph => "graph",
+      GraphNodeKind::Empty      => "empty",
+      GraphNodeKind::Other(_)   => "other",
+    }
+  }
+}
+
+/// A node of a `CudaGraph`, identified by its index in the graph.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GraphNode {
+  graph:    usize,
+  index:    usize,
+}
+
+impl GraphNode {
+  pub fn index(&self) -> usize {
+    self.index
+  }
+}
+
+type HostFn = Box<dyn Fn() + Send + Sync>;
+
+extern "C" fn host_node_callback(user_data: *mut c_void) {
+  let f = unsafe { &*(user_data as *const HostFn) };
+  if catch_unwind(AssertUnwindSafe(|| f())).is_err() {
+    // NB: Unwinding into the runtime is undefined behavior.
+    abort();
+  }
+}
+
+struct NodeEntry {
+  raw:          cudaGraphNode_t,
+  kind:         GraphNodeKind,
+  deps:         Vec<usize>,
+  dependents:   Vec<usize>,
+}
+
+static NEXT_GRAPH_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A graph of work, either captured from a stream or built explicitly.
+///
+/// The graph keeps its own record of nodes and dependencies, so that nodes
+/// can be validated and cycles rejected before calling into the runtime.
+pub struct CudaGraph {
+  ptr:          cudaGraph_t,
+  id:           usize,
+  nodes:        Vec<NodeEntry>,
+  host_fns:     Vec<Arc<HostFn>>,
+}
+
+unsafe impl Send for CudaGraph {}
+
+impl Drop for CudaGraph {
+  fn drop(&mut self) {
+    check_drop("CudaGraph", unsafe { cudaGraphDestroy(self.ptr) });
+  }
+}
+
+impl CudaGraph {
+  /// Create an empty graph.
+  ///
+  /// Corresponds to `cudaGraphCreate`.
+  pub fn new() -> CudaResult<CudaGraph> {
+    let mut ptr: cudaGraph_t = null_mut();
+    match unsafe { cudaGraphCreate(&mut ptr as *mut cudaGraph_t, 0) } {
+      cudaError_cudaSuccess => Ok(CudaGraph::wrap(ptr)),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  fn wrap(ptr: cudaGraph_t) -> CudaGraph {
+    CudaGraph{
+      ptr,
+      id:       NEXT_GRAPH_ID.fetch_add(1, Ordering::Relaxed),
+      nodes:    Vec::new(),
+      host_fns: Vec::new(),
+    }
+  }
+
+  /// Take ownership of a graph built by the runtime, e.g. by stream
+  /// capture, and record its nodes and dependencies.
+  fn from_raw(ptr: cudaGraph_t) -> CudaResult<CudaGraph> {
+    let mut graph = CudaGraph::wrap(ptr);
+    let mut num_nodes: usize = 0;
+    match unsafe { cudaGraphGetNodes(ptr, null_mut(), &mut num_nodes as *mut usize) } {
+      cudaError_cudaSuccess => {}
+      e => return Err(CudaError::from_raw(e)),
+    }
+    let mut raw_nodes: Vec<cudaGraphNode_t> = vec![null_mut(); num_nodes];
+    match unsafe { cudaGraphGetNodes(ptr, raw_nodes.as_mut_ptr(), &mut num_nodes as *mut usize) } {
+      cudaError_cudaSuccess => {}
+      e => return Err(CudaError::from_raw(e)),
+    }
+    raw_nodes.truncate(num_nodes);
+    let mut index = HashMap::new();
+    for raw in raw_nodes {
+      index.insert(raw as usize, graph.nodes.len());
+      graph.nodes.push(NodeEntry{
+        raw,
+        kind:       node_kind(raw)?,
+        deps:       Vec::new(),
+        dependents: Vec::new(),
+      });
+    }
+    let mut num_edges: usize = 0;
+    match unsafe { cudaGraphGetEdges(ptr, null_mut(), null_mut(), &mut num_edges as *mut usize) } {
+      cudaError_cudaSuccess => {}
+      e => return Err(CudaError::from_raw(e)),
+    }
+    let mut from: Vec<cudaGraphNode_t> = vec![null_mut(); num_edges];
+    let mut to: Vec<cudaGraphNode_t> = vec![null_mut(); num_edges];
+    match unsafe { cudaGraphGetEdges(ptr, from.as_mut_ptr(), to.as_mut_ptr(), &mut num_edges as *mut usize) } {
+      cudaError_cudaSuccess => {}
+      e => return Err(CudaError::from_raw(e)),
+    }
+    for (&f, &t) in from.iter().zip(to.iter()).take(num_edges) {
+      match (index.get(&(f as usize)), index.get(&(t as usize))) {
+        (Some(&f), Some(&t)) => graph.insert_edge(f, t),
+        _ => return Err(CudaError::Runtime(cudaError_cudaErrorUnknown)),
+      }
+    }
+    Ok(graph)
+  }
+
+  pub unsafe fn as_mut_ptr(&mut self) -> cudaGraph_t {
+    self.ptr
+  }
+
+  fn node_index(&self, node: GraphNode) -> CudaResult<usize> {
+    if node.graph != self.id || node.index >= self.nodes.len() {
+      return Err(CudaError::UnknownGraphNode{index: node.index});
+    }
+    Ok(node.index)
+  }
+
+  fn insert_edge(&mut self, from: usize, to: usize) {
+    self.nodes[from].dependents.push(to);
+    self.nodes[to].deps.push(from);
+  }
+
+  fn add_node<F>(&mut self, deps: &[GraphNode], kind: GraphNodeKind, add: F) -> CudaResult<GraphNode>
+  where F: FnOnce(*mut cudaGraphNode_t, cudaGraph_t, *const cudaGraphNode_t, usize) -> cudaError_t
+  {
+    let mut dep_indices = Vec::with_capacity(deps.len());
+    for &dep in deps {
+      dep_indices.push(self.node_index(dep)?);
+    }
+    let raw_deps: Vec<_> = dep_indices.iter().map(|&idx| self.nodes[idx].raw).collect();
+    let mut raw: cudaGraphNode_t = null_mut();
+    match add(&mut raw as *mut cudaGraphNode_t, self.ptr, raw_deps.as_ptr(), raw_deps.len()) {
+      cudaError_cudaSuccess => {}
+      e => return Err(CudaError::from_raw(e)),
+    }
+    let index = self.nodes.len();
+    self.nodes.push(NodeEntry{
+      raw,
+      kind,
+      deps:       Vec::new(),
+      dependents: Vec::new(),
+    });
+    for dep in dep_indices {
+      self.insert_edge(dep, index);
+    }
+    Ok(GraphNode{graph: self.id, index})
+  }
+
+  /// Add a node which does nothing, e.g. to join several dependencies.
+  ///
+  /// Corresponds to `cudaGraphAddEmptyNode`.
+  pub fn add_empty_node(&mut self, deps: &[GraphNode]) -> CudaResult<GraphNode> {
+    self.add_node(deps, GraphNodeKind::Empty, |node, graph, raw_deps, num_deps| unsafe {
+      cudaGraphAddEmptyNode(node, graph, raw_deps, num_deps)
+    })
+  }
+
+  /// Add a kernel node, after checking `config` against the limits of the
+  /// current device. `args` is as for `cuda_launch_kernel`; the argument
+  /// values are copied when the node is added.
+  ///
+  /// Corresponds to `cudaGraphAddKernelNode`.
+  pub unsafe fn add_kernel_node(
+      &mut self,
+      deps: &[GraphNode],
+      func: CudaFunction,
+      config: &LaunchConfig,
+      args: &mut [*mut c_void]) -> CudaResult<GraphNode>
+  {
+    config.check(CudaDevice::get_current()?)?;
+    let shared_mem_bytes = c_uint::try_from(config.shared_mem_bytes)
+      .map_err(|_| CudaError::Runtime(cudaError_cudaErrorInvalidValue))?;
+    let params = cudaKernelNodeParams{
+      func:             func.0 as *mut c_void,
+      gridDim:          to_dim3(config.grid),
+      blockDim:         to_dim3(config.block),
+      sharedMemBytes:   shared_mem_bytes,
+      kernelParams:     args.as_mut_ptr(),
+      extra:            null_mut(),
+    };
+    self.add_node(deps, GraphNodeKind::Kernel, |node, graph, raw_deps, num_deps| {
+      cudaGraphAddKernelNode(node, graph, raw_deps, num_deps, &params as *const cudaKernelNodeParams)
+    })
+  }
+
+  /// Add a node copying `len` elements from `src` to `dst`.
+  ///
+  /// Corresponds to `cudaGraphAddMemcpyNode`.
+  pub unsafe fn add_memcpy_node<T>(
+      &mut self,
+      deps: &[GraphNode],
+      dst: *mut T,
+      src: *const T,
+      len: usize,
+      kind: CudaMemcpyKind) -> CudaResult<GraphNode>
+  where T: Copy + 'static
+  {
+    let size = checked_bytes::<T>(len)?;
+    extent::check(dst as *const u8, size)?;
+    extent::check(src as *const u8, size)?;
+    let mut params: cudaMemcpy3DParms = zeroed();
+    params.srcPtr = cudaPitchedPtr{ptr: src as *mut c_void, pitch: size, xsize: size, ysize: 1};
+    params.dstPtr = cudaPitchedPtr{ptr: dst as *mut c_void, pitch: size, xsize: size, ysize: 1};
+    params.extent = cudaExtent{width: size, height: 1, depth: 1};
+    params.kind = kind.to_raw();
+    self.add_node(deps, GraphNodeKind::Memcpy, |node, graph, raw_deps, num_deps| {
+      cudaGraphAddMemcpyNode(node, graph, raw_deps, num_deps, &params as *const cudaMemcpy3DParms)
+    })
+  }
+
+  /// Add a node setting `size` bytes at `dptr` to `value`.
+  ///
+  /// Corresponds to `cudaGraphAddMemsetNode`.
+  pub unsafe fn add_memset_node(&mut self, deps: &[GraphNode], dptr: *mut u8, value: i32, size: usize) -> CudaResult<GraphNode> {
+    extent::check(dptr, size)?;
+    let params = cudaMemsetParams{
+      dst:          dptr as *mut c_void,
+      pitch:        0,
+      value:        value as u8 as c_uint,
+      elementSize:  1,
+      width:        size,
+      height:       1,
+    };
+    self.add_node(deps, GraphNodeKind::Memset, |node, graph, raw_deps, num_deps| {
+      cudaGraphAddMemsetNode(node, graph, raw_deps, num_deps, &params as *const cudaMemsetParams)
+    })
+  }
+
+  /// Add a node calling `f` on a runtime thread. `f` must not call into the
+  /// runtime, and is kept alive by the graph, its clones, and the executable
+  /// graphs instantiated from them. A panic in `f` aborts the process.
+  ///
+  /// Corresponds to `cudaGraphAddHostNode`.
+  pub fn add_host_node<F>(&mut self, deps: &[GraphNode], f: F) -> CudaResult<GraphNode>
+  where F: Fn() + Send + Sync + 'static
+  {
+    let f: Arc<HostFn> = Arc::new(Box::new(f));
+    let params = cudaHostNodeParams{
+      fn_:      Some(host_node_callback),
+      userData: &*f as *const HostFn as *mut c_void,
+    };
+    let node = self.add_node(deps, GraphNodeKind::Host, |node, graph, raw_deps, num_deps| unsafe {
+      cudaGraphAddHostNode(node, graph, raw_deps, num_deps, &params as *const cudaHostNodeParams)
+    })?;
+    self.host_fns.push(f);
+    Ok(node)
+  }
+
+  /// Add a node running a copy of `child`.
+  ///
+  /// Corresponds to `cudaGraphAddChildGraphNode`.
+  pub fn add_child_graph(&mut self, deps: &[GraphNode], child: &CudaGraph) -> CudaResult<GraphNode> {
+    let node = self.add_node(deps, GraphNodeKind::ChildGraph, |node, graph, raw_deps, num_deps| unsafe {
+      cudaGraphAddChildGraphNode(node, graph, raw_deps, num_deps, child.ptr)
+    })?;
+    self.host_fns.extend(child.host_fns.iter().cloned());
+    Ok(node)
+  }
+
+  /// Add dependencies between existing nodes; for each `(from, to)` pair,
+  /// `to` runs after `from`. Fails without changing the graph if any
+  /// dependency would create a cycle.
+  ///
+  /// Corresponds to `cudaGraphAddDependencies`.
+  pub fn add_dependencies(&mut self, deps: &[(GraphNode, GraphNode)]) -> CudaResult<()> {
+    let mut dependents: Vec<Vec<usize>> = self.nodes.iter().map(|n| n.dependents.clone()).collect();
+    let mut edges = Vec::with_capacity(deps.len());
+    for &(from, to) in deps {
+      let (from, to) = (self.node_index(from)?, self.node_index(to)?);
+      if reaches(&dependents, to, from) {
+        return Err(CudaError::GraphCycle{from, to});
+      }
+      dependents[from].push(to);
+      edges.push((from, to));
+    }
+    let raw_from: Vec<_> = edges.iter().map(|&(from, _)| self.nodes[from].raw).collect();
+    let raw_to: Vec<_> = edges.iter().map(|&(_, to)| self.nodes[to].raw).collect();
+    match unsafe { cudaGraphAddDependencies(self.ptr, raw_from.as_ptr(), raw_to.as_ptr(), edges.len()) } {
+      cudaError_cudaSuccess => {}
+      e => return Err(CudaError::from_raw(e)),
+    }
+    for (from, to) in edges {
+      self.insert_edge(from, to);
+    }
+    Ok(())
+  }
+
+  /// Copy the graph. Nodes of the clone have the same indices as the
+  /// corresponding nodes of the original, see `node`.
+  ///
+  /// Corresponds to `cudaGraphClone`.
+  pub fn try_clone(&self) -> CudaResult<CudaGraph> {
+    let mut ptr: cudaGraph_t = null_mut();
+    match unsafe { cudaGraphClone(&mut ptr as *mut cudaGraph_t, self.ptr) } {
+      cudaError_cudaSuccess => {}
+      e => return Err(CudaError::from_raw(e)),
+    }
+    let mut clone = CudaGraph::wrap(ptr);
+    clone.host_fns = self.host_fns.clone();
+    for entry in self.nodes.iter() {
+      let mut raw: cudaGraphNode_t = null_mut();
+      match unsafe { cudaGraphNodeFindInClone(&mut raw as *mut cudaGraphNode_t, entry.raw, ptr) } {
+        cudaError_cudaSuccess => {}
+        e => return Err(CudaError::from_raw(e)),
+      }
+      clone.nodes.push(NodeEntry{
+        raw,
+        kind:       entry.kind,
+        deps:       entry.deps.clone(),
+        dependents: entry.dependents.clone(),
+      });
+    }
+    Ok(clone)
+  }
+
+  pub fn num_nodes(&self) -> usize {
+    self.nodes.len()
+  }
+
+  /// The node with the given index, if any.
+  pub fn node(&self, index: usize) -> Option<GraphNode> {
+    if index < self.nodes.len() {
+      Some(GraphNode{graph: self.id, index})
+    } else {
+      None
+    }
+  }
+
+  /// All nodes, in index order.
+  pub fn nodes(&self) -> Vec<GraphNode> {
+    (0 .. self.nodes.len()).map(|index| GraphNode{graph: self.id, index}).collect()
+  }
+
+  /// Nodes without dependencies.
+  pub fn root_nodes(&self) -> Vec<GraphNode> {
+    self.nodes().into_iter().filter(|node| self.nodes[node.index].deps.is_empty()).collect()
+  }
+
+  /// All dependencies, as `(from, to)` pairs.
+  pub fn edges(&self) -> Vec<(GraphNode, GraphNode)> {
+    let mut edges = Vec::new();
+    for (from, entry) in self.nodes.iter().enumerate() {
+      for &to in entry.dependents.iter() {
+        edges.push((GraphNode{graph: self.id, index: from}, GraphNode{graph: self.id, index: to}));
+      }
+    }
+    edges
+  }
+
+  pub fn node_kind(&self, node: GraphNode) -> CudaResult<GraphNodeKind> {
+    Ok(self.nodes[self.node_index(node)?].kind)
+  }
+
+  /// The nodes which `node` depends on.
+  pub fn dependencies(&self, node: GraphNode) -> CudaResult<Vec<GraphNode>> {
+    let index = self.node_index(node)?;
+    Ok(self.nodes[index].deps.iter().map(|&index| GraphNode{graph: self.id, index}).collect())
+  }
+
+  /// The nodes which depend on `node`.
+  pub fn dependents(&self, node: GraphNode) -> CudaResult<Vec<GraphNode>> {
+    let index = self.node_index(node)?;
+    Ok(self.nodes[index].dependents.iter().map(|&index| GraphNode{graph: self.id, index}).collect())
+  }
+
+  /// The dependency structure in Graphviz dot format, with nodes labeled by
+  /// index and kind.
+  pub fn to_dot(&self) -> String {
+    let mut dot = String::new();
+    writeln!(dot, "digraph CudaGraph {{").unwrap();
+    for (index, entry) in self.nodes.iter().enumerate() {
+      writeln!(dot, "  n{} [label=\"{}: {}\"];", index, index, entry.kind.name()).unwrap();
+    }
+    for (from, entry) in self.nodes.iter().enumerate() {
+      for &to in entry.dependents.iter() {
+        writeln!(dot, "  n{} -> n{};", from, to).unwrap();
+      }
+    }
+    writeln!(dot, "}}").unwrap();
+    dot
+  }
+
+  /// Instantiate the graph as an executable graph. The executable graph is
+  /// independent of the graph, which may be changed or dropped afterwards.
+  ///
+  /// Corresponds to `cudaGraphInstantiate`.
+  pub fn instantiate(&self) -> CudaResult<CudaGraphExec> {
+    let mut ptr: cudaGraphExec_t = null_mut();
+    #[cfg(not(cuda_gte_12_0))]
+    let ret = unsafe { cudaGraphInstantiate(&mut ptr as *mut cudaGraphExec_t, self.ptr, null_mut(), null_mut(), 0) };
+    #[cfg(cuda_gte_12_0)]
+    let ret = unsafe { cudaGraphInstantiate(&mut ptr as *mut cudaGraphExec_t, self.ptr, 0) };
+    match ret {
+      cudaError_cudaSuccess => Ok(CudaGraphExec{ptr, _host_fns: self.host_fns.clone()}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
+
+fn node_kind(raw: cudaGraphNode_t) -> CudaResult<GraphNodeKind> {
+  let mut kind: cudaGraphNodeType = cudaGraphNodeType_cudaGraphNodeTypeEmpty;
+  match unsafe { cudaGraphNodeGetType(raw, &mut kind as *mut cudaGraphNodeType) } {
+    cudaError_cudaSuccess => Ok(GraphNodeKind::from_raw(kind)),
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Whether `to` can be reached from `from` by following `dependents`.
+fn reaches(dependents: &[Vec<usize>], from: usize, to: usize) -> bool {
+  let mut visited = vec![false; dependents.len()];
+  let mut stack = vec![from];
+  while let Some(index) = stack.pop() {
+    if index == to {
+      return true;
+    }
+    if visited[index] {
+      continue;
+    }
+    visited[index] = true;
+    stack.extend(dependents[index].iter().cloned());
+  }
+  false
+}
+
+/// An executable graph, instantiated from a `CudaGraph`.
+pub struct CudaGraphExec {
+  ptr:          cudaGraphExec_t,
+  _host_fns:    Vec<Arc<HostFn>>,
+}
+
+unsafe impl Send for CudaGraphExec {}
+
+impl Drop for CudaGraphExec {
+  fn drop(&mut self) {
+    check_drop("CudaGraphExec", unsafe { cudaGraphExecDestroy(self.ptr) });
+  }
+}
+
+impl CudaGraphExec {
+  pub unsafe fn as_mut_ptr(&mut self) -> cudaGraphExec_t {
+    self.ptr
+  }
+
+  /// Launch the graph on `stream`. Launches of the same executable graph
+  /// are serialized.
+  ///
+  /// Corresponds to `cudaGraphLaunch`.
+  pub fn launch(&mut self, stream: &mut CudaStream) -> CudaResult<()> {
+    let op = timeline::begin(stream);
+    match unsafe { cudaGraphLaunch(self.ptr, stream.as_mut_ptr()) } {
+      cudaError_cudaSuccess => {
+        timeline::end(op, "cudaGraphLaunch", None, stream);
+        Ok(())
+      }
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
@@ -0,0 +1,227 @@
+//! Sharing device memory and events between processes.
+//!
+//! Handles are opaque 64 byte blobs which can be sent to another process by
+//! any means, and opened there.
+
+#[cfg(not(feature = "cuda_sys"))]
+use crate::ffi::*;
+use crate::launch::{KernelArg};
+use crate::runtime::{
+  CudaDeviceBuffer,
+  CudaError,
+  CudaEvent,
+  CudaMemcpyKind,
+  CudaResult,
+  CudaStream,
+  check_copy_len,
+  check_drop,
+  checked_bytes,
+  cuda_memcpy,
+  cuda_memcpy_async,
+};
+
+#[cfg(not(feature = "cuda_sys"))]
+use cuda_api_types::driver_types::*;
+#[cfg(feature = "cuda_sys")]
+use cuda_sys::cudart::*;
+
+use std::fmt;
+use std::marker::{PhantomData};
+use std::mem::{size_of};
+use std::os::raw::{c_void};
+use std::ptr::{null_mut};
+
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaSuccess: cudaError_t = cudaError_t::Success;
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaErrorCudartUnloading: cudaError_t = cudaError_t::CudartUnloading;
+
+pub const IPC_HANDLE_SIZE: usize = 64;
+
+const IPC_MEM_LAZY_ENABLE_PEER_ACCESS: u32 = 0x01;
+
+fn handle_to_bytes<H: Copy>(handle: &H) -> [u8; IPC_HANDLE_SIZE] {
+  assert_eq!(size_of::<H>(), IPC_HANDLE_SIZE);
+  let mut bytes = [0; IPC_HANDLE_SIZE];
+  unsafe { (handle as *const H as *const u8).copy_to_nonoverlapping(bytes.as_mut_ptr(), IPC_HANDLE_SIZE) };
+  bytes
+}
+
+fn handle_from_bytes<H: Copy>(bytes: &[u8; IPC_HANDLE_SIZE]) -> H {
+  assert_eq!(size_of::<H>(), IPC_HANDLE_SIZE);
+  unsafe { (bytes.as_ptr() as *const H).read_unaligned() }
+}
+
+fn fmt_handle(name: &str, bytes: &[u8], f: &mut fmt::Formatter) -> fmt::Result {
+  write!(f, "{}(", name)?;
+  for b in bytes {
+    write!(f, "{:02x}", b)?;
+  }
+  write!(f, ")")
+}
+
+/// An interprocess handle to a device memory allocation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CudaIpcMemHandle {
+  bytes:    [u8; IPC_HANDLE_SIZE],
+}
+
+impl fmt::Debug for CudaIpcMemHandle {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt_handle("CudaIpcMemHandle", &self.bytes, f)
+  }
+}
+
+impl CudaIpcMemHandle {
+  pub fn from_bytes(bytes: [u8; IPC_HANDLE_SIZE]) -> CudaIpcMemHandle {
+    CudaIpcMemHandle{bytes}
+  }
+
+  pub fn as_bytes(&self) -> &[u8; IPC_HANDLE_SIZE] {
+    &self.bytes
+  }
+
+  /// Export a device buffer. The buffer must stay allocated while other
+  /// processes have it open.
+  pub fn get<T: Copy + 'static>(buf: &CudaDeviceBuffer<T>) -> CudaResult<CudaIpcMemHandle> {
+    unsafe { CudaIpcMemHandle::get_raw(buf.as_ptr() as *mut u8) }
+  }
+
+  /// Export the device allocation starting at `dptr`.
+  ///
+  /// Corresponds to `cudaIpcGetMemHandle`.
+  pub unsafe fn get_raw(dptr: *mut u8) -> CudaResult<CudaIpcMemHandle> {
+    let mut handle: cudaIpcMemHandle_t = handle_from_bytes(&[0; IPC_HANDLE_SIZE]);
+    match cudaIpcGetMemHandle(&mut handle as *mut cudaIpcMemHandle_t, dptr as *mut c_void) {
+      cudaError_cudaSuccess => Ok(CudaIpcMemHandle{bytes: handle_to_bytes(&handle)}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Map the exported allocation into this process as a buffer of `len`
+  /// elements, enabling peer access to the exporting device if needed. The
+  /// exported allocation must hold at least `len` elements. A handle cannot
+  /// be opened in the process which exported it.
+  ///
+  /// Corresponds to `cudaIpcOpenMemHandle`.
+  pub unsafe fn open<T: Copy + 'static>(&self, len: usize) -> CudaResult<IpcMappedBuffer<T>> {
+    checked_bytes::<T>(len)?;
+    let mut ptr: *mut c_void = null_mut();
+    match cudaIpcOpenMemHandle(&mut ptr as *mut *mut c_void, handle_from_bytes(&self.bytes), IPC_MEM_LAZY_ENABLE_PEER_ACCESS) {
+      cudaError_cudaSuccess => Ok(IpcMappedBuffer{ptr: ptr as *mut T, len, _marker: PhantomData}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
+
+/// Device memory of another process, mapped with `CudaIpcMemHandle::open`.
+/// The mapping is closed on drop.
+pub struct IpcMappedBuffer<T: Copy + 'static> {
+  ptr:      *mut T,
+  len:      usize,
+  _marker:  PhantomData<T>,
+}
+
+unsafe impl<T: Copy + 'static> Send for IpcMappedBuffer<T> {}
+unsafe impl<T: Copy + 'static> Sync for IpcMappedBuffer<T> {}
+
+impl<T: Copy + 'static> Drop for IpcMappedBuffer<T> {
+  fn drop(&mut self) {
+    check_drop("IpcMappedBuffer", unsafe { cudaIpcCloseMemHandle(self.ptr as *mut c_void) });
+  }
+}
+
+impl<T: Copy + 'static> IpcMappedBuffer<T> {
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  pub fn as_ptr(&self) -> *const T {
+    self.ptr
+  }
+
+  pub fn as_mut_ptr(&mut self) -> *mut T {
+    self.ptr
+  }
+
+  pub fn copy_from_host(&mut self, src: &[T]) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, src.len())?;
+    unsafe { cuda_memcpy(self.ptr, src.as_ptr(), self.len, CudaMemcpyKind::HostToDevice) }
+  }
+
+  pub fn copy_to_host(&self, dst: &mut [T]) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, dst.len())?;
+    unsafe { cuda_memcpy(dst.as_mut_ptr(), self.ptr, self.len, CudaMemcpyKind::DeviceToHost) }
+  }
+
+  /// Enqueue a copy from host memory on `stream`. `src` must stay valid
+  /// and unmodified until the copy completes.
+  pub unsafe fn copy_from_host_async(&mut self, src: &[T], stream: &mut CudaStream) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, src.len())?;
+    cuda_memcpy_async(self.ptr, src.as_ptr(), self.len, CudaMemcpyKind::HostToDevice, stream)
+  }
+
+  /// Enqueue a copy into host memory on `stream`. `dst` must stay valid
+  /// and untouched until the copy completes.
+  pub unsafe fn copy_to_host_async(&self, dst: &mut [T], stream: &mut CudaStream) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, dst.len())?;
+    cuda_memcpy_async(dst.as_mut_ptr(), self.ptr, self.len, CudaMemcpyKind::DeviceToHost, stream)
+  }
+}
+
+/// A mapped buffer is passed as a pointer to its first element.
+unsafe impl<T: Copy + 'static> KernelArg for IpcMappedBuffer<T> {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    &self.ptr as *const *mut T as *mut c_void
+  }
+}
+
+/// An interprocess handle to an event.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CudaIpcEventHandle {
+  bytes:    [u8; IPC_HANDLE_SIZE],
+}
+
+impl fmt::Debug for CudaIpcEventHandle {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt_handle("CudaIpcEventHandle", &self.bytes, f)
+  }
+}
+
+impl CudaIpcEventHandle {
+  pub fn from_bytes(bytes: [u8; IPC_HANDLE_SIZE]) -> CudaIpcEventHandle {
+    CudaIpcEventHandle{bytes}
+  }
+
+  pub fn as_bytes(&self) -> &[u8; IPC_HANDLE_SIZE] {
+    &self.bytes
+  }
+
+  /// Export an event, which must have been created with
+  /// `CudaEvent::create_interprocess`.
+  ///
+  /// Corresponds to `cudaIpcGetEventHandle`.
+  pub fn get(event: &mut CudaEvent) -> CudaResult<CudaIpcEventHandle> {
+    let mut handle: cudaIpcEventHandle_t = handle_from_bytes(&[0; IPC_HANDLE_SIZE]);
+    match unsafe { cudaIpcGetEventHandle(&mut handle as *mut cudaIpcEventHandle_t, event.as_mut_ptr()) } {
+      cudaError_cudaSuccess => Ok(CudaIpcEventHandle{bytes: handle_to_bytes(&handle)}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Open the exported event in this process. The exported event must not
+  /// be destroyed while the opened event is in use.
+  ///
+  /// Corresponds to `cudaIpcOpenEventHandle`.
+  pub fn open(&self) -> CudaResult<CudaEvent> {
+    let mut ptr: cudaEvent_t = null_mut();
+    match unsafe { cudaIpcOpenEventHandle(&mut ptr as *mut cudaEvent_t, handle_from_bytes(&self.bytes)) } {
+      cudaError_cudaSuccess => Ok(unsafe { CudaEvent::from_raw(ptr) }),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
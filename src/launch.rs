@@ -0,0 +1,438 @@
+#[cfg(not(feature = "cuda_sys"))]
+use crate::ffi::*;
+#[cfg(all(not(feature = "cuda_sys"), cuda_gte_9_0, not(cuda_gte_12_0)))]
+use crate::ffi::{cudaLaunchParams};
+#[cfg(all(not(feature = "cuda_sys"), cuda_gte_12_0))]
+use crate::ffi::{cudaFuncAttributes};
+use crate::occupancy::{KernelResources};
+use crate::runtime::{CachePreference, CudaDevice, CudaDeviceBuffer, CudaError, CudaResult};
+#[cfg(cuda_gte_7_0)]
+use crate::runtime::{CudaStream};
+#[cfg(cuda_gte_9_0)]
+use crate::runtime::{with_device};
+#[cfg(cuda_gte_7_0)]
+use crate::timeline;
+
+#[cfg(not(feature = "cuda_sys"))]
+use cuda_api_types::driver_types::*;
+#[cfg(feature = "cuda_sys")]
+use cuda_sys::cudart::*;
+
+use std::collections::{HashMap};
+use std::mem::{zeroed};
+#[cfg(cuda_gte_9_0)]
+use std::os::raw::{c_int};
+use std::os::raw::{c_void};
+#[cfg(cuda_gte_7_0)]
+use std::os::raw::{c_uint};
+use std::sync::{Mutex};
+
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaSuccess: cudaError_t = cudaError_t::Success;
+
+/// A device function, identified by the address of its host-side stub
+/// (e.g. a kernel declared in an `extern "C"` block and compiled by nvcc).
+#[derive(Clone, Copy, Debug)]
+pub struct CudaFunction(pub *const c_void);
+
+unsafe impl Send for CudaFunction {}
+unsafe impl Sync for CudaFunction {}
+
+/// Attributes of a device function, see `cudaFuncAttributes`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CudaFuncAttributes {
+  pub shared_size_bytes:            usize,
+  pub const_size_bytes:             usize,
+  pub local_size_bytes:             usize,
+  pub max_threads_per_block:        u32,
+  pub num_regs:                     u32,
+  pub ptx_version:                  i32,
+  pub binary_version:               i32,
+  pub max_dynamic_shared_size_bytes: usize,
+}
+
+impl CudaFuncAttributes {
+  /// The resource usage of the function, for use with `OccupancyModel`.
+  pub fn resources(&self) -> KernelResources {
+    KernelResources{
+      regs_per_thread:          self.num_regs,
+      static_smem_bytes:        self.shared_size_bytes,
+      max_threads_per_block:    self.max_threads_per_block,
+    }
+  }
+}
+
+/// A settable attribute of a device function.
+#[cfg(cuda_gte_9_0)]
+#[derive(Clone, Copy, Debug)]
+pub enum FuncAttribute {
+  /// Maximum dynamic shared memory size in bytes; raising it above the
+  /// default per-block limit opts into the larger shared memory carveout.
+  MaxDynamicSharedMemorySize(usize),
+  /// Preferred shared memory carveout, as a percentage of the maximum.
+  PreferredSharedMemoryCarveout(i32),
+}
+
+impl CudaFunction {
+  /// Query the attributes of the function.
+  ///
+  /// Corresponds to `cudaFuncGetAttributes`.
+  pub fn get_attributes(&self) -> CudaResult<CudaFuncAttributes> {
+    let mut attr: cudaFuncAttributes = unsafe { zeroed() };
+    match unsafe { cudaFuncGetAttributes(&mut attr as *mut cudaFuncAttributes, self.0) } {
+      cudaError_cudaSuccess => {
+        #[cfg(cuda_gte_9_0)]
+        let max_dynamic_shared_size_bytes = attr.maxDynamicSharedSizeBytes as usize;
+        #[cfg(not(cuda_gte_9_0))]
+        let max_dynamic_shared_size_bytes = 0;
+        Ok(CudaFuncAttributes{
+          shared_size_bytes:        attr.sharedSizeBytes,
+          const_size_bytes:         attr.constSizeBytes,
+          local_size_bytes:         attr.localSizeBytes,
+          max_threads_per_block:    attr.maxThreadsPerBlock as u32,
+          num_regs:                 attr.numRegs as u32,
+          ptx_version:              attr.ptxVersion as i32,
+          binary_version:           attr.binaryVersion as i32,
+          max_dynamic_shared_size_bytes,
+        })
+      }
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Set an attribute of the function.
+  ///
+  /// Corresponds to `cudaFuncSetAttribute`.
+  #[cfg(cuda_gte_9_0)]
+  pub fn set_attribute(&self, attr: FuncAttribute) -> CudaResult<()> {
+    let (raw_attr, value) = match attr {
+      FuncAttribute::MaxDynamicSharedMemorySize(size) => {
+        (cudaFuncAttribute_cudaFuncAttributeMaxDynamicSharedMemorySize, size as c_int)
+      }
+      FuncAttribute::PreferredSharedMemoryCarveout(percent) => {
+        (cudaFuncAttribute_cudaFuncAttributePreferredSharedMemoryCarveout, percent as c_int)
+      }
+    };
+    match unsafe { cudaFuncSetAttribute(self.0, raw_attr, value) } {
+      cudaError_cudaSuccess => Ok(()),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Set the preferred L1/shared memory split for the function, overriding
+  /// the device preference.
+  ///
+  /// Corresponds to `cudaFuncSetCacheConfig`.
+  pub fn set_cache_config(&self, pref: CachePreference) -> CudaResult<()> {
+    match unsafe { cudaFuncSetCacheConfig(self.0, pref.to_raw()) } {
+      cudaError_cudaSuccess => Ok(()),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LaunchConfig {
+  pub grid:             [u32; 3],
+  pub block:            [u32; 3],
+  pub shared_mem_bytes: usize,
+}
+
+impl LaunchConfig {
+  /// A 1D launch with enough blocks of `block_x` threads to cover `n`
+  /// threads. `block_x` must be nonzero.
+  pub fn linear(n: u32, block_x: u32) -> CudaResult<LaunchConfig> {
+    check_dim("block.x", block_x as u64, u32::max_value() as u64)?;
+    Ok(LaunchConfig{
+      grid:             [n / block_x + (n % block_x != 0) as u32, 1, 1],
+      block:            [block_x, 1, 1],
+      shared_mem_bytes: 0,
+    })
+  }
+
+  /// Check the launch dimensions against the limits of `device`.
+  pub fn check(&self, device: CudaDevice) -> CudaResult<()> {
+    let limits = LaunchLimits::get(device)?;
+    const GRID: [&str; 3] = ["grid.x", "grid.y", "grid.z"];
+    const BLOCK: [&str; 3] = ["block.x", "block.y", "block.z"];
+    for d in 0 .. 3 {
+      check_dim(GRID[d], self.grid[d] as u64, limits.max_grid[d])?;
+      check_dim(BLOCK[d], self.block[d] as u64, limits.max_block[d])?;
+    }
+    let threads = self.block.iter().map(|&b| b as u64).product();
+    check_dim("block threads", threads, limits.max_threads_per_block)
+  }
+}
+
+fn check_dim(dim: &'static str, value: u64, max: u64) -> CudaResult<()> {
+  if value < 1 || value > max {
+    return Err(CudaError::LaunchOutOfRange{dim, value, max});
+  }
+  Ok(())
+}
+
+#[derive(Clone, Copy, Debug)]
+struct LaunchLimits {
+  max_grid:                 [u64; 3],
+  max_block:                [u64; 3],
+  max_threads_per_block:    u64,
+}
+
+static LAUNCH_LIMITS: Mutex<Option<HashMap<i32, LaunchLimits>>> = Mutex::new(None);
+
+impl LaunchLimits {
+  fn get(device: CudaDevice) -> CudaResult<LaunchLimits> {
+    if let Some(limits) = LAUNCH_LIMITS.lock().unwrap().as_ref().and_then(|m| m.get(&device.0)) {
+      return Ok(*limits);
+    }
+    let attr = |a| device.get_attribute(a).map(|v| v as u64);
+    let limits = LaunchLimits{
+      max_grid: [
+        attr(cudaDeviceAttr_cudaDevAttrMaxGridDimX)?,
+        attr(cudaDeviceAttr_cudaDevAttrMaxGridDimY)?,
+        attr(cudaDeviceAttr_cudaDevAttrMaxGridDimZ)?,
+      ],
+      max_block: [
+        attr(cudaDeviceAttr_cudaDevAttrMaxBlockDimX)?,
+        attr(cudaDeviceAttr_cudaDevAttrMaxBlockDimY)?,
+        attr(cudaDeviceAttr_cudaDevAttrMaxBlockDimZ)?,
+      ],
+      max_threads_per_block: attr(cudaDeviceAttr_cudaDevAttrMaxThreadsPerBlock)?,
+    };
+    LAUNCH_LIMITS.lock().unwrap().get_or_insert_with(HashMap::new).insert(device.0, limits);
+    Ok(limits)
+  }
+}
+
+/// A value which can be passed as a kernel argument.
+///
+/// `as_kernel_arg` returns a pointer to the argument value as the kernel
+/// receives it; the runtime copies `size_of` the kernel parameter type from
+/// it at launch time. Implementations must make sure that the pointee has
+/// the layout of the corresponding kernel parameter.
+pub unsafe trait KernelArg {
+  fn as_kernel_arg(&self) -> *mut c_void;
+}
+
+macro_rules! impl_kernel_arg_scalar {
+  ($($ty:ty),*) => {
+    $(
+      unsafe impl KernelArg for $ty {
+        fn as_kernel_arg(&self) -> *mut c_void {
+          self as *const $ty as *mut c_void
+        }
+      }
+    )*
+  };
+}
+
+impl_kernel_arg_scalar!(bool, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize, f32, f64);
+
+unsafe impl<T> KernelArg for *const T {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    self as *const *const T as *mut c_void
+  }
+}
+
+unsafe impl<T> KernelArg for *mut T {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    self as *const *mut T as *mut c_void
+  }
+}
+
+/// A device buffer is passed as a pointer to its first element.
+unsafe impl<T: Copy + 'static> KernelArg for CudaDeviceBuffer<T> {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    self.ptr_ref() as *const *mut T as *mut c_void
+  }
+}
+
+unsafe impl<'a, A: KernelArg + ?Sized> KernelArg for &'a A {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    (**self).as_kernel_arg()
+  }
+}
+
+unsafe impl<'a, A: KernelArg + ?Sized> KernelArg for &'a mut A {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    (**self).as_kernel_arg()
+  }
+}
+
+#[cfg(cuda_gte_7_0)]
+pub(crate) fn to_dim3(d: [u32; 3]) -> dim3 {
+  dim3{x: d[0] as c_uint, y: d[1] as c_uint, z: d[2] as c_uint}
+}
+
+/// Launch `func` on `stream`, after checking `config` against the limits of
+/// the current device. `args` holds one pointer per kernel parameter, see
+/// `KernelArg`; the `launch!` macro builds it from a list of arguments.
+///
+/// Corresponds to `cudaLaunchKernel` (CUDA 7.0 and later).
+#[cfg(cuda_gte_7_0)]
+pub unsafe fn cuda_launch_kernel(
+    func: CudaFunction,
+    config: &LaunchConfig,
+    stream: &mut CudaStream,
+    args: &mut [*mut c_void]) -> CudaResult<()>
+{
+  config.check(CudaDevice::get_current()?)?;
+  let op = timeline::begin(stream);
+  match cudaLaunchKernel(
+      func.0,
+      to_dim3(config.grid),
+      to_dim3(config.block),
+      args.as_mut_ptr(),
+      config.shared_mem_bytes,
+      stream.as_mut_ptr())
+  {
+    cudaError_cudaSuccess => {
+      timeline::end(op, "cudaLaunchKernel", None, stream);
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Launch a kernel: `launch!(func, config, stream, args...)`.
+///
+/// `func` is a `CudaFunction`, `config` a `LaunchConfig`, `stream` a
+/// `&mut CudaStream`, and each argument implements `KernelArg`. The
+/// arguments are borrowed for the duration of the launch call. Expands to a
+/// call to the unsafe `cuda_launch_kernel`, as the argument types cannot be
+/// checked against the kernel signature.
+#[macro_export]
+macro_rules! launch {
+  ($func:expr, $config:expr, $stream:expr $(, $arg:expr)* $(,)?) => {
+    $crate::launch::cuda_launch_kernel(
+        $func,
+        &$config,
+        $stream,
+        &mut [$($crate::launch::KernelArg::as_kernel_arg(&$arg)),*])
+  };
+}
+
+/// Flag for `cuda_launch_cooperative_kernel_multi_device`: do not wait for
+/// prior work on the other devices' streams before launching.
+#[cfg(all(cuda_gte_9_0, not(cuda_gte_12_0)))]
+pub const COOPERATIVE_LAUNCH_MULTI_DEVICE_NO_PRE_SYNC: u32 = 0x01;
+/// Flag for `cuda_launch_cooperative_kernel_multi_device`: do not make later
+/// work on the other devices' streams wait for the launch.
+#[cfg(all(cuda_gte_9_0, not(cuda_gte_12_0)))]
+pub const COOPERATIVE_LAUNCH_MULTI_DEVICE_NO_POST_SYNC: u32 = 0x02;
+
+/// Check that `device` supports the cooperative launch `attr`, and that all
+/// blocks of the grid can be co-resident on it.
+#[cfg(cuda_gte_9_0)]
+fn check_cooperative(device: CudaDevice, attr: cudaDeviceAttr, func: CudaFunction, config: &LaunchConfig) -> CudaResult<()> {
+  config.check(device)?;
+  if device.get_attribute(attr)? == 0 {
+    return Err(CudaError::CooperativeLaunchUnsupported{device: device.0});
+  }
+  let block_size = config.block.iter().product();
+  let blocks_per_multiprocessor = with_device(device.0, || {
+    func.max_active_blocks_per_multiprocessor(block_size, config.shared_mem_bytes)
+  })?;
+  let multiprocessor_count = device.get_attribute(cudaDeviceAttr_cudaDevAttrMultiProcessorCount)?;
+  let max_blocks = blocks_per_multiprocessor as u64 * multiprocessor_count as u64;
+  let blocks = config.grid.iter().map(|&g| g as u64).product();
+  if blocks > max_blocks {
+    return Err(CudaError::CooperativeGridTooLarge{blocks, max_blocks});
+  }
+  Ok(())
+}
+
+/// Launch `func` on `stream` as a cooperative kernel, whose blocks may
+/// synchronize with each other (e.g. with a cooperative groups grid sync).
+/// Besides the `cuda_launch_kernel` checks, the current device must support
+/// cooperative launches and fit the whole grid at once.
+///
+/// Corresponds to `cudaLaunchCooperativeKernel`.
+#[cfg(cuda_gte_9_0)]
+pub unsafe fn cuda_launch_cooperative_kernel(
+    func: CudaFunction,
+    config: &LaunchConfig,
+    stream: &mut CudaStream,
+    args: &mut [*mut c_void]) -> CudaResult<()>
+{
+  check_cooperative(CudaDevice::get_current()?, cudaDeviceAttr_cudaDevAttrCooperativeLaunch, func, config)?;
+  let op = timeline::begin(stream);
+  match cudaLaunchCooperativeKernel(
+      func.0,
+      to_dim3(config.grid),
+      to_dim3(config.block),
+      args.as_mut_ptr(),
+      config.shared_mem_bytes,
+      stream.as_mut_ptr())
+  {
+    cudaError_cudaSuccess => {
+      timeline::end(op, "cudaLaunchCooperativeKernel", None, stream);
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// The launch on one device of a multi-device cooperative launch. `stream`
+/// must belong to `device`.
+#[cfg(all(cuda_gte_9_0, not(cuda_gte_12_0)))]
+pub struct CooperativeLaunch<'a> {
+  pub device:   CudaDevice,
+  pub func:     CudaFunction,
+  pub config:   LaunchConfig,
+  pub stream:   &'a mut CudaStream,
+  pub args:     &'a mut [*mut c_void],
+}
+
+/// Launch cooperative kernels on several devices at once, one per device,
+/// with the given `COOPERATIVE_LAUNCH_MULTI_DEVICE_*` flags. Each device must
+/// support multi-device cooperative launches and fit its whole grid at once.
+///
+/// Corresponds to `cudaLaunchCooperativeKernelMultiDevice`, which was
+/// removed in CUDA 12.0.
+#[cfg(all(cuda_gte_9_0, not(cuda_gte_12_0)))]
+pub unsafe fn cuda_launch_cooperative_kernel_multi_device(launches: &mut [CooperativeLaunch], flags: u32) -> CudaResult<()> {
+  let mut params = Vec::with_capacity(launches.len());
+  for launch in launches.iter_mut() {
+    check_cooperative(launch.device, cudaDeviceAttr_cudaDevAttrCooperativeMultiDeviceLaunch, launch.func, &launch.config)?;
+    params.push(cudaLaunchParams{
+      func:         launch.func.0 as *mut c_void,
+      gridDim:      to_dim3(launch.config.grid),
+      blockDim:     to_dim3(launch.config.block),
+      args:         launch.args.as_mut_ptr(),
+      sharedMem:    launch.config.shared_mem_bytes,
+      stream:       launch.stream.as_mut_ptr(),
+    });
+  }
+  // Each stream's timeline events belong to its own device.
+  let ops: Vec<_> = launches.iter_mut()
+    .map(|launch| with_device(launch.device.0, || Ok(timeline::begin(&mut *launch.stream))).unwrap_or(None))
+    .collect();
+  match cudaLaunchCooperativeKernelMultiDevice(params.as_mut_ptr(), params.len() as c_uint, flags as c_uint) {
+    cudaError_cudaSuccess => {
+      for (launch, op) in launches.iter_mut().zip(ops) {
+        let _ = with_device(launch.device.0, || {
+          timeline::end(op, "cudaLaunchCooperativeKernelMultiDevice", None, &mut *launch.stream);
+          Ok(())
+        });
+      }
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Launch a cooperative kernel: `launch_cooperative!(func, config, stream,
+/// args...)`. Like `launch!`, but expands to a call to
+/// `cuda_launch_cooperative_kernel`.
+#[cfg(cuda_gte_9_0)]
+#[macro_export]
+macro_rules! launch_cooperative {
+  ($func:expr, $config:expr, $stream:expr $(, $arg:expr)* $(,)?) => {
+    $crate::launch::cuda_launch_cooperative_kernel(
+        $func,
+        &$config,
+        $stream,
+        &mut [$($crate::launch::KernelArg::as_kernel_arg(&$arg)),*])
+  };
+}
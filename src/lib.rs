@@ -1,20 +1,106 @@
 #![allow(non_upper_case_globals)]
 
+#[cfg(all(feature = "dynamic_loading", any(feature = "cuda_sys", not(unix))))]
+compile_error!("the dynamic_loading feature requires unix and the bundled ffi bindings (not cuda_sys)");
+#[cfg(all(feature = "dynamic_loading", feature = "static"))]
+compile_error!("the dynamic_loading and static features are mutually exclusive");
+
 #[cfg(not(feature = "cuda_sys"))]
 extern crate cuda_api_types;
 #[cfg(feature = "cuda_sys")]
 extern crate cuda_sys;
 #[cfg(feature = "cuda_sys")]
 #[macro_use] extern crate static_assertions;
+#[cfg(feature = "alloc_tracking")]
+extern crate backtrace;
+#[cfg(unix)]
+extern crate libc;
+#[cfg(feature = "tracing")]
+extern crate tracing;
 
+pub use crate::array::{
+  ChannelFormat,
+  ChannelFormatDesc,
+  ChannelFormatKind,
+  CudaArray,
+  cuda_memcpy_2d_from_array,
+  cuda_memcpy_2d_from_array_async,
+  cuda_memcpy_2d_to_array,
+  cuda_memcpy_2d_to_array_async,
+};
+pub use crate::caching::{
+  CachingAllocator,
+  CachingStats,
+};
+#[cfg(all(unix, cuda_gte_10_0))]
+pub use crate::external::{
+  ExternalBuffer,
+  ExternalMemory,
+  ExternalSemaphore,
+};
+#[cfg(cuda_gte_10_0)]
+pub use crate::graph::{
+  CaptureMode,
+  CaptureStatus,
+  CudaGraph,
+  CudaGraphExec,
+  GraphNode,
+  GraphNodeKind,
+};
+pub use crate::ipc::{
+  CudaIpcEventHandle,
+  CudaIpcMemHandle,
+  IpcMappedBuffer,
+};
+#[cfg(cuda_gte_9_0)]
+pub use crate::launch::{
+  FuncAttribute,
+  cuda_launch_cooperative_kernel,
+};
+#[cfg(all(cuda_gte_9_0, not(cuda_gte_12_0)))]
+pub use crate::launch::{
+  CooperativeLaunch,
+  cuda_launch_cooperative_kernel_multi_device,
+};
+pub use crate::launch::{
+  CudaFuncAttributes,
+  CudaFunction,
+  KernelArg,
+  LaunchConfig,
+};
+#[cfg(cuda_gte_7_0)]
+pub use crate::launch::{
+  cuda_launch_kernel,
+};
+pub use crate::occupancy::{
+  KernelResources,
+  Occupancy,
+  OccupancyModel,
+};
+pub use crate::pinned::{
+  PinnedBlock,
+  PinnedHostPool,
+};
+pub use crate::profiler::{
+  ProfileAttributes,
+  ProfileRange,
+  ProfileSpan,
+  cuda_profiler_start,
+  cuda_profiler_stop,
+  nvtx_available,
+};
 pub use crate::runtime::{
+  CachePreference,
+  SharedMemBankSize,
   CudaError,
   CudaResult,
   CudaDevice,
+  CudaDeviceProperties,
   CudaStream,
   CudaEvent,
   CudaEventStatus,
   CudaMemcpyKind,
+  CudaDeviceBuffer,
   cuda_alloc_device,
   cuda_alloc_host,
   cuda_free_device,
@@ -28,13 +114,43 @@ pub use crate::runtime::{
   get_driver_version,
   get_runtime_version,
 };
+pub use crate::symbol::{
+  DeviceSymbol,
+  cuda_get_symbol_size,
+};
+pub use crate::texture::{
+  AddressMode,
+  CudaSurfaceObject,
+  CudaTextureObject,
+  FilterMode,
+  ReadMode,
+  TextureBuilder,
+};
 
+pub mod array;
+pub mod caching;
+mod extent;
+#[cfg(all(unix, cuda_gte_10_0))]
+pub mod external;
 #[cfg(not(feature = "cuda_sys"))]
 pub mod ffi;
+#[cfg(cuda_gte_10_0)]
+pub mod graph;
+pub mod ipc;
+pub mod launch;
+pub mod occupancy;
+pub mod pinned;
+pub mod profiler;
 pub mod runtime;
+pub mod symbol;
+pub mod texture;
+pub mod timeline;
+mod trace;
+#[cfg(feature = "alloc_tracking")]
+pub mod tracking;
 
 #[cfg(feature = "cuda_sys")]
 mod version_checks {
-  #[cfg(feature = "cuda_8_0")]  const_assert_eq!(cuda_api_version; cuda_sys::cuda::__CUDA_API_VERSION,  8000);
-  #[cfg(feature = "cuda_8_0")]  const_assert_eq!(cuda_version;     cuda_sys::cuda::CUDA_VERSION,        8000);
+  #[cfg(cuda_8_0)]  const_assert_eq!(cuda_api_version; cuda_sys::cuda::__CUDA_API_VERSION,  8000);
+  #[cfg(cuda_8_0)]  const_assert_eq!(cuda_version;     cuda_sys::cuda::CUDA_VERSION,        8000);
 }
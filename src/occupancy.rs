@@ -0,0 +1,280 @@
+#[cfg(not(feature = "cuda_sys"))]
+use crate::ffi::*;
+use crate::launch::{CudaFunction, LaunchConfig};
+use crate::runtime::{CudaDevice, CudaDeviceProperties, CudaError, CudaResult};
+
+#[cfg(not(feature = "cuda_sys"))]
+use cuda_api_types::driver_types::*;
+#[cfg(feature = "cuda_sys")]
+use cuda_sys::cudart::*;
+
+use std::os::raw::{c_int};
+#[cfg(cuda_gte_7_0)]
+use std::os::raw::{c_uint};
+
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaSuccess: cudaError_t = cudaError_t::Success;
+
+#[cfg(cuda_gte_7_0)]
+pub const OCCUPANCY_DEFAULT: u32 = 0x00;
+#[cfg(cuda_gte_7_0)]
+pub const OCCUPANCY_DISABLE_CACHING_OVERRIDE: u32 = 0x01;
+
+impl CudaFunction {
+  /// The maximum number of active blocks per multiprocessor when launching
+  /// with `block_size` threads and `dynamic_smem_bytes` of dynamic shared
+  /// memory per block.
+  ///
+  /// Corresponds to `cudaOccupancyMaxActiveBlocksPerMultiprocessor`.
+  pub fn max_active_blocks_per_multiprocessor(&self, block_size: u32, dynamic_smem_bytes: usize) -> CudaResult<u32> {
+    let mut num_blocks: c_int = 0;
+    match unsafe { cudaOccupancyMaxActiveBlocksPerMultiprocessor(
+        &mut num_blocks as *mut c_int,
+        self.0,
+        block_size as c_int,
+        dynamic_smem_bytes) }
+    {
+      cudaError_cudaSuccess => Ok(num_blocks as u32),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Like `max_active_blocks_per_multiprocessor`, with the given
+  /// `OCCUPANCY_*` flags.
+  ///
+  /// Corresponds to `cudaOccupancyMaxActiveBlocksPerMultiprocessorWithFlags`
+  /// (CUDA 7.0 and later).
+  #[cfg(cuda_gte_7_0)]
+  pub fn max_active_blocks_per_multiprocessor_with_flags(&self, block_size: u32, dynamic_smem_bytes: usize, flags: u32) -> CudaResult<u32> {
+    let mut num_blocks: c_int = 0;
+    match unsafe { cudaOccupancyMaxActiveBlocksPerMultiprocessorWithFlags(
+        &mut num_blocks as *mut c_int,
+        self.0,
+        block_size as c_int,
+        dynamic_smem_bytes,
+        flags as c_uint) }
+    {
+      cudaError_cudaSuccess => Ok(num_blocks as u32),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// The block size which maximizes occupancy on the current device, along
+  /// with the minimum grid size which achieves it, as `(min_grid_size,
+  /// block_size)`. `dynamic_smem_bytes` maps a block size to the dynamic
+  /// shared memory used per block; a `block_size_limit` of 0 means no limit.
+  ///
+  /// Equivalent to the `cudaOccupancyMaxPotentialBlockSizeVariableSMem`
+  /// template from `cuda_runtime.h`.
+  pub fn max_potential_block_size<F: Fn(u32) -> usize>(&self, dynamic_smem_bytes: F, block_size_limit: u32) -> CudaResult<(u32, u32)> {
+    let props = CudaDevice::get_current()?.get_device_properties()?;
+    let max_block_size = if block_size_limit == 0 {
+      props.max_threads_per_block
+    } else {
+      block_size_limit.min(props.max_threads_per_block)
+    };
+    max_potential_block_size_by(props.warp_size, props.multiprocessor_count, max_block_size, |block_size| {
+      self.max_active_blocks_per_multiprocessor(block_size, dynamic_smem_bytes(block_size))
+    })
+  }
+}
+
+fn max_potential_block_size_by<F>(warp_size: u32, multiprocessor_count: u32, max_block_size: u32, active_blocks: F) -> CudaResult<(u32, u32)>
+where F: Fn(u32) -> CudaResult<u32>
+{
+  let mut best_block_size = 0;
+  let mut best_active_threads = 0;
+  let mut best_active_blocks = 0;
+  let mut block_size = max_block_size;
+  while block_size > 0 {
+    let blocks = active_blocks(block_size)?;
+    let threads = blocks * block_size;
+    if threads > best_active_threads {
+      best_block_size = block_size;
+      best_active_threads = threads;
+      best_active_blocks = blocks;
+    }
+    // Step down to the next smaller multiple of the warp size.
+    block_size = if block_size % warp_size == 0 {
+      block_size - warp_size.min(block_size)
+    } else {
+      block_size / warp_size * warp_size
+    };
+  }
+  Ok((best_active_blocks * multiprocessor_count, best_block_size))
+}
+
+/// Per-kernel resource usage, as reported by `cudaFuncGetAttributes` or by
+/// `ptxas -v`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KernelResources {
+  pub regs_per_thread:          u32,
+  pub static_smem_bytes:        usize,
+  /// Zero means the device limit.
+  pub max_threads_per_block:    u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OccupancyLimiter {
+  Warps,
+  Blocks,
+  Registers,
+  SharedMemory,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Occupancy {
+  pub active_blocks_per_multiprocessor: u32,
+  pub active_warps_per_multiprocessor:  u32,
+  pub max_warps_per_multiprocessor:     u32,
+  pub limiter:                          OccupancyLimiter,
+}
+
+impl Occupancy {
+  /// Active warps as a fraction of the warps a multiprocessor can hold.
+  pub fn ratio(&self) -> f64 {
+    if self.max_warps_per_multiprocessor == 0 {
+      return 0.0;
+    }
+    self.active_warps_per_multiprocessor as f64 / self.max_warps_per_multiprocessor as f64
+  }
+}
+
+fn round_up(x: usize, unit: usize) -> usize {
+  (x + unit - 1) / unit * unit
+}
+
+/// An occupancy model driven by `CudaDeviceProperties`, following the
+/// calculations of `cuda_occupancy.h`. It needs no device, so launch
+/// configurations can be computed and tested offline.
+#[derive(Clone, Debug)]
+pub struct OccupancyModel {
+  pub props:    CudaDeviceProperties,
+}
+
+impl OccupancyModel {
+  pub fn new(props: CudaDeviceProperties) -> OccupancyModel {
+    OccupancyModel{props}
+  }
+
+  /// Resident block limit per multiprocessor of the architecture.
+  pub fn max_blocks_per_multiprocessor(&self) -> u32 {
+    match (self.props.major, self.props.minor) {
+      (m, _) if m < 3 => 8,
+      (3, _) => 16,
+      (7, 5) => 16,
+      (8, 6) | (8, 7) => 16,
+      (8, 9) => 24,
+      _ => 32,
+    }
+  }
+
+  fn reg_alloc_unit(&self) -> usize {
+    if self.props.major < 3 { 64 } else { 256 }
+  }
+
+  fn num_sub_partitions(&self) -> usize {
+    match (self.props.major, self.props.minor) {
+      (m, _) if m < 3 => 1,
+      (6, 0) => 2,
+      _ => 4,
+    }
+  }
+
+  fn smem_alloc_unit(&self) -> usize {
+    if self.props.major < 3 { 128 } else { 256 }
+  }
+
+  fn smem_reserved_per_block(&self) -> usize {
+    if self.props.major >= 8 { 1024 } else { 0 }
+  }
+
+  pub fn occupancy(&self, kernel: &KernelResources, block_size: u32, dynamic_smem_bytes: usize) -> Occupancy {
+    let props = &self.props;
+    let warp_size = props.warp_size.max(1);
+    let max_warps = props.max_threads_per_multiprocessor / warp_size;
+    let zero = |limiter| Occupancy{
+      active_blocks_per_multiprocessor: 0,
+      active_warps_per_multiprocessor:  0,
+      max_warps_per_multiprocessor:     max_warps,
+      limiter,
+    };
+    let max_block_size = match kernel.max_threads_per_block {
+      0 => props.max_threads_per_block,
+      n => n.min(props.max_threads_per_block),
+    };
+    if block_size == 0 || block_size > max_block_size {
+      return zero(OccupancyLimiter::Warps);
+    }
+    let warps_per_block = (block_size + warp_size - 1) / warp_size;
+
+    let mut limiter = OccupancyLimiter::Warps;
+    let mut blocks = max_warps / warps_per_block;
+    let block_limit = self.max_blocks_per_multiprocessor();
+    if block_limit < blocks {
+      blocks = block_limit;
+      limiter = OccupancyLimiter::Blocks;
+    }
+
+    if kernel.regs_per_thread > 0 {
+      let regs_per_warp = round_up(kernel.regs_per_thread as usize * warp_size as usize, self.reg_alloc_unit());
+      let regs_per_block = regs_per_warp * warps_per_block as usize;
+      if regs_per_block > props.regs_per_block as usize {
+        return zero(OccupancyLimiter::Registers);
+      }
+      let sub_partitions = self.num_sub_partitions();
+      let regs_per_sub_partition = props.regs_per_multiprocessor as usize / sub_partitions;
+      let warps_by_regs = regs_per_sub_partition / regs_per_warp * sub_partitions;
+      let reg_limit = (warps_by_regs / warps_per_block as usize) as u32;
+      if reg_limit < blocks {
+        blocks = reg_limit;
+        limiter = OccupancyLimiter::Registers;
+      }
+    }
+
+    let smem_per_block = kernel.static_smem_bytes + dynamic_smem_bytes;
+    if smem_per_block > 0 {
+      if smem_per_block > props.shared_mem_per_block_optin.max(props.shared_mem_per_block) {
+        return zero(OccupancyLimiter::SharedMemory);
+      }
+      let smem_alloc = round_up(smem_per_block + self.smem_reserved_per_block(), self.smem_alloc_unit());
+      let smem_limit = (props.shared_mem_per_multiprocessor / smem_alloc) as u32;
+      if smem_limit < blocks {
+        blocks = smem_limit;
+        limiter = OccupancyLimiter::SharedMemory;
+      }
+    }
+
+    Occupancy{
+      active_blocks_per_multiprocessor: blocks,
+      active_warps_per_multiprocessor:  blocks * warps_per_block,
+      max_warps_per_multiprocessor:     max_warps,
+      limiter,
+    }
+  }
+
+  /// The block size which maximizes occupancy, along with the minimum grid
+  /// size which achieves it, as `(min_grid_size, block_size)`. See
+  /// `CudaFunction::max_potential_block_size`.
+  pub fn max_potential_block_size<F: Fn(u32) -> usize>(&self, kernel: &KernelResources, dynamic_smem_bytes: F, block_size_limit: u32) -> (u32, u32) {
+    let mut max_block_size = match kernel.max_threads_per_block {
+      0 => self.props.max_threads_per_block,
+      n => n.min(self.props.max_threads_per_block),
+    };
+    if block_size_limit > 0 {
+      max_block_size = max_block_size.min(block_size_limit);
+    }
+    max_potential_block_size_by(self.props.warp_size.max(1), self.props.multiprocessor_count, max_block_size, |block_size| {
+      Ok(self.occupancy(kernel, block_size, dynamic_smem_bytes(block_size)).active_blocks_per_multiprocessor)
+    }).unwrap()
+  }
+
+  /// A 1D launch configuration covering `n` threads with the block size
+  /// which maximizes occupancy.
+  pub fn launch_config(&self, kernel: &KernelResources, n: u32, dynamic_smem_bytes: usize) -> LaunchConfig {
+    let (_, block_size) = self.max_potential_block_size(kernel, |_| dynamic_smem_bytes, 0);
+    let mut config = LaunchConfig::linear(n, block_size.max(1)).unwrap();
+    config.shared_mem_bytes = dynamic_smem_bytes;
+    config
+  }
+}
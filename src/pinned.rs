@@ -0,0 +1,274 @@
+use crate::caching::{size_class};
+use crate::runtime::{
+  CudaError,
+  CudaEvent,
+  CudaEventStatus,
+  CudaResult,
+  CudaStream,
+  cuda_alloc_host,
+  cuda_free_host,
+};
+
+use std::collections::{BTreeMap};
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+use std::sync::{Arc, Mutex};
+
+struct IdleBlock {
+  ptr:      *mut u8,
+  event:    Option<CudaEvent>,
+}
+
+impl IdleBlock {
+  fn is_ready(&mut self) -> CudaResult<bool> {
+    match self.event {
+      None => Ok(true),
+      Some(ref mut event) => match event.query()? {
+        CudaEventStatus::Complete => Ok(true),
+        CudaEventStatus::NotReady => Ok(false),
+      },
+    }
+  }
+}
+
+struct PoolInner {
+  max_reserved_bytes:   usize,
+  reserved_bytes:       usize,
+  leased_bytes:         usize,
+  idle:                 BTreeMap<usize, Vec<IdleBlock>>,
+}
+
+unsafe impl Send for PoolInner {}
+
+impl Drop for PoolInner {
+  fn drop(&mut self) {
+    // NB: Errors are suppressed, as this commonly runs while the runtime is
+    // shutting down.
+    for (_, bin) in std::mem::replace(&mut self.idle, BTreeMap::new()) {
+      for mut block in bin {
+        if let Some(ref mut event) = block.event {
+          let _ = event.synchronize();
+        }
+        let _ = unsafe { cuda_free_host(block.ptr) };
+      }
+    }
+  }
+}
+
+impl PoolInner {
+  fn take_idle(&mut self, class: usize) -> CudaResult<Option<*mut u8>> {
+    let bin = match self.idle.get_mut(&class) {
+      None => return Ok(None),
+      Some(bin) => bin,
+    };
+    for idx in 0 .. bin.len() {
+      if bin[idx].is_ready()? {
+        return Ok(Some(bin.swap_remove(idx).ptr));
+      }
+    }
+    Ok(None)
+  }
+
+  /// Free idle blocks which are no longer in flight, until at least
+  /// `target_bytes` have been freed.
+  fn release_idle(&mut self, target_bytes: usize) -> CudaResult<usize> {
+    let mut freed = 0;
+    let classes: Vec<_> = self.idle.keys().cloned().collect();
+    for class in classes {
+      let bin = self.idle.get_mut(&class).unwrap();
+      let mut idx = 0;
+      while idx < bin.len() && freed < target_bytes {
+        if !bin[idx].is_ready()? {
+          idx += 1;
+          continue;
+        }
+        let block = bin.swap_remove(idx);
+        if let Err(e) = unsafe { cuda_free_host(block.ptr) } {
+          bin.push(block);
+          return Err(e);
+        }
+        self.reserved_bytes -= class;
+        freed += class;
+      }
+      if bin.is_empty() {
+        self.idle.remove(&class);
+      }
+      if freed >= target_bytes {
+        break;
+      }
+    }
+    Ok(freed)
+  }
+}
+
+/// A pool of reusable pinned host memory, intended for staging buffers of
+/// async copies.
+///
+/// Blocks are leased out as `PinnedBlock`s and return to the pool when the
+/// lease is dropped. A block which has an event recorded on it is not leased
+/// out again until that event completes.
+#[derive(Clone)]
+pub struct PinnedHostPool {
+  inner:    Arc<Mutex<PoolInner>>,
+}
+
+impl PinnedHostPool {
+  /// Create a pool which holds at most `max_reserved_bytes` of pinned host
+  /// memory, counting both leased and idle blocks.
+  pub fn new(max_reserved_bytes: usize) -> PinnedHostPool {
+    PinnedHostPool{
+      inner:    Arc::new(Mutex::new(PoolInner{
+        max_reserved_bytes,
+        reserved_bytes:     0,
+        leased_bytes:       0,
+        idle:               BTreeMap::new(),
+      })),
+    }
+  }
+
+  pub fn max_reserved_bytes(&self) -> usize {
+    self.inner.lock().unwrap().max_reserved_bytes
+  }
+
+  /// Change the reservation limit. Blocks above the new limit are freed as
+  /// they become idle and ready.
+  pub fn set_max_reserved_bytes(&self, max_reserved_bytes: usize) {
+    self.inner.lock().unwrap().max_reserved_bytes = max_reserved_bytes;
+  }
+
+  /// Bytes of pinned host memory held by the pool, whether leased or idle.
+  pub fn reserved_bytes(&self) -> usize {
+    self.inner.lock().unwrap().reserved_bytes
+  }
+
+  /// Bytes of pinned host memory currently leased out.
+  pub fn leased_bytes(&self) -> usize {
+    self.inner.lock().unwrap().leased_bytes
+  }
+
+  /// Lease a block of `size` bytes.
+  pub fn lease(&self, size: usize) -> CudaResult<PinnedBlock> {
+    let class = size_class(size)?;
+    let mut inner = self.inner.lock().unwrap();
+    let ptr = match inner.take_idle(class)? {
+      Some(ptr) => ptr,
+      None => {
+        let excess = inner.reserved_bytes.saturating_add(class)
+          .saturating_sub(inner.max_reserved_bytes);
+        if excess > 0 && inner.release_idle(excess)? < excess {
+          return Err(CudaError::PoolLimit{
+            size: class,
+            reserved_bytes: inner.reserved_bytes,
+            max_reserved_bytes: inner.max_reserved_bytes,
+          });
+        }
+        // `cudaMallocHost` is slow, so allocate without holding the lock.
+        // The block is counted first so that concurrent leases still see it
+        // against the limit.
+        inner.reserved_bytes += class;
+        drop(inner);
+        let res = cuda_alloc_host(class);
+        inner = self.inner.lock().unwrap();
+        match res {
+          Ok(ptr) => ptr,
+          Err(e) => {
+            inner.reserved_bytes -= class;
+            return Err(e);
+          }
+        }
+      }
+    };
+    inner.leased_bytes += class;
+    Ok(PinnedBlock{
+      ptr,
+      size,
+      class,
+      event:    None,
+      pool:     self.inner.clone(),
+    })
+  }
+
+  /// Free all idle blocks which are no longer in flight.
+  pub fn release_idle(&self) -> CudaResult<()> {
+    self.inner.lock().unwrap().release_idle(usize::max_value()).map(|_| ())
+  }
+}
+
+/// A lease on a block of pinned host memory from a `PinnedHostPool`.
+pub struct PinnedBlock {
+  ptr:      *mut u8,
+  size:     usize,
+  class:    usize,
+  event:    Option<CudaEvent>,
+  pool:     Arc<Mutex<PoolInner>>,
+}
+
+unsafe impl Send for PinnedBlock {}
+unsafe impl Sync for PinnedBlock {}
+
+impl Drop for PinnedBlock {
+  fn drop(&mut self) {
+    let mut inner = match self.pool.lock() {
+      Err(_) => return,
+      Ok(inner) => inner,
+    };
+    inner.leased_bytes -= self.class;
+    inner.idle.entry(self.class).or_default()
+      .push(IdleBlock{ptr: self.ptr, event: self.event.take()});
+    if inner.reserved_bytes > inner.max_reserved_bytes {
+      let excess = inner.reserved_bytes - inner.max_reserved_bytes;
+      let _ = inner.release_idle(excess);
+    }
+  }
+}
+
+impl PinnedBlock {
+  pub fn len(&self) -> usize {
+    self.size
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.size == 0
+  }
+
+  pub fn as_ptr(&self) -> *const u8 {
+    self.ptr
+  }
+
+  pub fn as_mut_ptr(&mut self) -> *mut u8 {
+    self.ptr
+  }
+
+  pub fn as_slice(&self) -> &[u8] {
+    unsafe { from_raw_parts(self.ptr, self.size) }
+  }
+
+  pub fn as_mut_slice(&mut self) -> &mut [u8] {
+    unsafe { from_raw_parts_mut(self.ptr, self.size) }
+  }
+
+  /// Mark the block as in flight on `stream`, e.g. after enqueueing an async
+  /// copy from or into it. The block is held back from reuse until all work
+  /// enqueued on `stream` so far has completed.
+  pub fn record(&mut self, stream: &mut CudaStream) -> CudaResult<()> {
+    if self.event.is_none() {
+      self.event = Some(CudaEvent::create_fastest()?);
+    }
+    self.event.as_mut().unwrap().record(stream)
+  }
+
+  /// Query whether the block is still in flight.
+  pub fn query(&mut self) -> CudaResult<CudaEventStatus> {
+    match self.event {
+      None => Ok(CudaEventStatus::Complete),
+      Some(ref mut event) => event.query(),
+    }
+  }
+
+  /// Wait until the block is no longer in flight.
+  pub fn synchronize(&mut self) -> CudaResult<()> {
+    match self.event {
+      None => Ok(()),
+      Some(ref mut event) => event.synchronize(),
+    }
+  }
+}
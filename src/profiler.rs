@@ -0,0 +1,291 @@
+//! Profiler control and NVTX annotations.
+//!
+//! NVTX is loaded at runtime from `libnvToolsExt` rather than linked, so
+//! there is no link dependency on it; when the library cannot be loaded the
+//! annotations are no-ops.
+
+#[cfg(not(feature = "cuda_sys"))]
+use crate::ffi::*;
+use crate::runtime::{CudaError, CudaResult};
+
+#[cfg(not(feature = "cuda_sys"))]
+use cuda_api_types::driver_types::*;
+#[cfg(feature = "cuda_sys")]
+use cuda_sys::cudart::*;
+
+use std::ffi::{CString};
+use std::marker::{PhantomData};
+
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaSuccess: cudaError_t = cudaError_t::Success;
+
+#[cfg(feature = "cuda_sys")]
+extern "C" {
+  fn cudaProfilerStart() -> cudaError_t;
+  fn cudaProfilerStop() -> cudaError_t;
+}
+
+/// Enable profile collection by the attached profiler.
+///
+/// Corresponds to `cudaProfilerStart`.
+pub fn cuda_profiler_start() -> CudaResult<()> {
+  match unsafe { cudaProfilerStart() } {
+    cudaError_cudaSuccess => Ok(()),
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Disable profile collection by the attached profiler.
+///
+/// Corresponds to `cudaProfilerStop`.
+pub fn cuda_profiler_stop() -> CudaResult<()> {
+  match unsafe { cudaProfilerStop() } {
+    cudaError_cudaSuccess => Ok(()),
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Color and category of an NVTX annotation.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ProfileAttributes {
+  /// Color as `0xAARRGGBB`, or the tool's default.
+  pub color:    Option<u32>,
+  /// Category, named with `name_category`; 0 is uncategorized.
+  pub category: u32,
+}
+
+impl ProfileAttributes {
+  pub fn with_color(argb: u32) -> ProfileAttributes {
+    ProfileAttributes{color: Some(argb), category: 0}
+  }
+}
+
+/// Whether `libnvToolsExt` was loaded. It is loaded on first use.
+pub fn nvtx_available() -> bool {
+  nvtx::get().is_some()
+}
+
+/// Mark an instantaneous event.
+///
+/// Corresponds to `nvtxMarkEx`.
+pub fn mark(message: &str) {
+  mark_with(message, ProfileAttributes::default())
+}
+
+pub fn mark_with(message: &str, attrs: ProfileAttributes) {
+  if let Some(api) = nvtx::get() {
+    let message = c_message(message);
+    let raw = nvtx::EventAttributes::new(&message, attrs);
+    unsafe { (api.mark_ex)(&raw) };
+  }
+}
+
+/// Name the category used in `ProfileAttributes`.
+///
+/// Corresponds to `nvtxNameCategoryA`.
+pub fn name_category(category: u32, name: &str) {
+  if let Some(api) = nvtx::get() {
+    let name = c_message(name);
+    unsafe { (api.name_category_a)(category, name.as_ptr()) };
+  }
+}
+
+/// A range on the calling thread's NVTX range stack, popped on drop.
+/// Ranges must be dropped in reverse order of creation and cannot move to
+/// other threads; use `ProfileSpan` for ranges which do.
+///
+/// Corresponds to `nvtxRangePushEx` and `nvtxRangePop`.
+#[derive(Debug)]
+pub struct ProfileRange {
+  pushed:   bool,
+  _thread:  PhantomData<*const ()>,
+}
+
+impl Drop for ProfileRange {
+  fn drop(&mut self) {
+    if self.pushed {
+      if let Some(api) = nvtx::get() {
+        unsafe { (api.range_pop)() };
+      }
+    }
+  }
+}
+
+impl ProfileRange {
+  pub fn new(name: &str) -> ProfileRange {
+    ProfileRange::with_attributes(name, ProfileAttributes::default())
+  }
+
+  pub fn with_color(name: &str, argb: u32) -> ProfileRange {
+    ProfileRange::with_attributes(name, ProfileAttributes::with_color(argb))
+  }
+
+  pub fn with_attributes(name: &str, attrs: ProfileAttributes) -> ProfileRange {
+    let pushed = match nvtx::get() {
+      None => false,
+      Some(api) => {
+        let name = c_message(name);
+        let raw = nvtx::EventAttributes::new(&name, attrs);
+        unsafe { (api.range_push_ex)(&raw) >= 0 }
+      }
+    };
+    ProfileRange{pushed, _thread: PhantomData}
+  }
+}
+
+/// A process-wide range, ended on drop. Unlike `ProfileRange`, spans may
+/// overlap and end on a different thread than they started on.
+///
+/// Corresponds to `nvtxRangeStartEx` and `nvtxRangeEnd`.
+#[derive(Debug)]
+pub struct ProfileSpan {
+  id:   Option<u64>,
+}
+
+impl Drop for ProfileSpan {
+  fn drop(&mut self) {
+    if let (Some(id), Some(api)) = (self.id, nvtx::get()) {
+      unsafe { (api.range_end)(id) };
+    }
+  }
+}
+
+impl ProfileSpan {
+  pub fn new(name: &str) -> ProfileSpan {
+    ProfileSpan::with_attributes(name, ProfileAttributes::default())
+  }
+
+  pub fn with_attributes(name: &str, attrs: ProfileAttributes) -> ProfileSpan {
+    let id = nvtx::get().map(|api| {
+      let name = c_message(name);
+      let raw = nvtx::EventAttributes::new(&name, attrs);
+      unsafe { (api.range_start_ex)(&raw) }
+    });
+    ProfileSpan{id}
+  }
+}
+
+/// Messages are truncated at the first interior NUL.
+fn c_message(message: &str) -> CString {
+  let bytes = message.as_bytes();
+  let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+  CString::new(&bytes[.. end]).unwrap()
+}
+
+#[cfg(unix)]
+mod nvtx {
+  use super::{ProfileAttributes};
+
+  use std::ffi::{CString};
+  use std::mem::{size_of, transmute};
+  use std::os::raw::{c_char, c_int, c_void};
+  use std::ptr::{null_mut};
+  use std::sync::{OnceLock};
+
+  const NVTX_VERSION: u16 = 2;
+  const NVTX_COLOR_UNKNOWN: i32 = 0;
+  const NVTX_COLOR_ARGB: i32 = 1;
+  const NVTX_MESSAGE_TYPE_ASCII: i32 = 1;
+
+  /// `nvtxEventAttributes_v2`.
+  #[repr(C)]
+  pub struct EventAttributes {
+    version:      u16,
+    size:         u16,
+    category:     u32,
+    color_type:   i32,
+    color:        u32,
+    payload_type: i32,
+    reserved0:    i32,
+    payload:      u64,
+    message_type: i32,
+    message:      *const c_char,
+  }
+
+  impl EventAttributes {
+    pub fn new(message: &CString, attrs: ProfileAttributes) -> EventAttributes {
+      EventAttributes{
+        version:      NVTX_VERSION,
+        size:         size_of::<EventAttributes>() as u16,
+        category:     attrs.category,
+        color_type:   if attrs.color.is_some() { NVTX_COLOR_ARGB } else { NVTX_COLOR_UNKNOWN },
+        color:        attrs.color.unwrap_or(0),
+        payload_type: 0,
+        reserved0:    0,
+        payload:      0,
+        message_type: NVTX_MESSAGE_TYPE_ASCII,
+        message:      message.as_ptr(),
+      }
+    }
+  }
+
+  pub struct Api {
+    pub range_push_ex:    unsafe extern "C" fn (*const EventAttributes) -> c_int,
+    pub range_pop:        unsafe extern "C" fn () -> c_int,
+    pub mark_ex:          unsafe extern "C" fn (*const EventAttributes),
+    pub range_start_ex:   unsafe extern "C" fn (*const EventAttributes) -> u64,
+    pub range_end:        unsafe extern "C" fn (u64),
+    pub name_category_a:  unsafe extern "C" fn (u32, *const c_char),
+  }
+
+  static API: OnceLock<Option<Api>> = OnceLock::new();
+
+  pub fn get() -> Option<&'static Api> {
+    API.get_or_init(|| unsafe { load() }).as_ref()
+  }
+
+  unsafe fn load() -> Option<Api> {
+    let mut lib: *mut c_void = null_mut();
+    for name in &["libnvToolsExt.so.1\0", "libnvToolsExt.so\0"] {
+      lib = libc::dlopen(name.as_ptr() as *const c_char, libc::RTLD_NOW | libc::RTLD_LOCAL);
+      if !lib.is_null() {
+        break;
+      }
+    }
+    if lib.is_null() {
+      return None;
+    }
+    // The library stays loaded for the life of the process.
+    let sym = |name: &str| {
+      let p = libc::dlsym(lib, name.as_ptr() as *const c_char);
+      if p.is_null() { None } else { Some(p) }
+    };
+    Some(Api{
+      range_push_ex:    transmute(sym("nvtxRangePushEx\0")?),
+      range_pop:        transmute(sym("nvtxRangePop\0")?),
+      mark_ex:          transmute(sym("nvtxMarkEx\0")?),
+      range_start_ex:   transmute(sym("nvtxRangeStartEx\0")?),
+      range_end:        transmute(sym("nvtxRangeEnd\0")?),
+      name_category_a:  transmute(sym("nvtxNameCategoryA\0")?),
+    })
+  }
+}
+
+#[cfg(not(unix))]
+mod nvtx {
+  use super::{ProfileAttributes};
+
+  use std::ffi::{CString};
+  use std::os::raw::{c_char, c_int};
+
+  pub struct EventAttributes;
+
+  impl EventAttributes {
+    pub fn new(_message: &CString, _attrs: ProfileAttributes) -> EventAttributes {
+      EventAttributes
+    }
+  }
+
+  pub struct Api {
+    pub range_push_ex:    unsafe extern "C" fn (*const EventAttributes) -> c_int,
+    pub range_pop:        unsafe extern "C" fn () -> c_int,
+    pub mark_ex:          unsafe extern "C" fn (*const EventAttributes),
+    pub range_start_ex:   unsafe extern "C" fn (*const EventAttributes) -> u64,
+    pub range_end:        unsafe extern "C" fn (u64),
+    pub name_category_a:  unsafe extern "C" fn (u32, *const c_char),
+  }
+
+  pub fn get() -> Option<&'static Api> {
+    None
+  }
+}
@@ -1,5 +1,16 @@
+use crate::extent;
+use crate::timeline;
+use crate::trace::{traced};
+#[cfg(feature = "alloc_tracking")]
+use crate::tracking::{self, AllocKind};
 #[cfg(not(feature = "cuda_sys"))]
 use crate::ffi::*;
+#[cfg(all(not(feature = "cuda_sys"), cuda_gte_11_0))]
+use crate::ffi::{cudaDeviceProp};
+// CUDA 12.0 headers likewise redirect this to the `_v2` entry point, which
+// fills in the extended `cudaDeviceProp`.
+#[cfg(all(not(feature = "cuda_sys"), cuda_gte_12_0))]
+use crate::ffi::{cudaGetDeviceProperties_v2 as cudaGetDeviceProperties};
 
 #[cfg(not(feature = "cuda_sys"))]
 use cuda_api_types::driver_types::*;
@@ -21,18 +32,128 @@ const cudaError_cudaErrorPeerAccessNotEnabled: cudaError_t = cudaError_t::PeerAc
 const cudaError_cudaErrorCudartUnloading: cudaError_t = cudaError_t::CudartUnloading;
 #[cfg(feature = "cuda_sys")]
 const cudaError_cudaErrorNotReady: cudaError_t = cudaError_t::NotReady;
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaErrorInvalidValue: cudaError_t = cudaError_t::InvalidValue;
 
+/// An error returned by the runtime, or detected by `cudart` before
+/// calling into it.
+///
+/// This replaces the former `CudaError(pub cudaError_t)` tuple struct, which
+/// is a breaking change: match `CudaError::Runtime(e)` instead of
+/// `CudaError(e)`, or use `raw()`.
 #[derive(Clone, Copy, Debug)]
-pub struct CudaError(pub cudaError_t);
+pub enum CudaError {
+  /// An error code returned by the CUDA runtime.
+  Runtime(cudaError_t),
+  /// The runtime library could not be loaded (`dynamic_loading` feature),
+  /// see `ffi::dynamic::load_error`.
+  RuntimeUnavailable,
+  /// A byte size computation overflowed `usize`.
+  SizeOverflow,
+  /// The row width of a 2D copy exceeds one of its row pitches.
+  PitchTooSmall{width_bytes: usize, pitch_bytes: usize},
+  /// An access of `size` bytes at `offset` bytes into a known allocation of
+  /// `alloc_size` bytes would go out of bounds.
+  OutOfBounds{offset: usize, size: usize, alloc_size: usize},
+  /// A pointer was not allocated by the allocator it was returned to.
+  UnknownPointer{ptr: usize},
+  /// A pointer was freed which had already been freed.
+  DoubleFree{ptr: usize},
+  /// A launch dimension is zero or exceeds the device limit.
+  LaunchOutOfRange{dim: &'static str, value: u64, max: u64},
+  /// The device does not support cooperative (or multi-device cooperative)
+  /// launches.
+  CooperativeLaunchUnsupported{device: i32},
+  /// A cooperative launch of `blocks` blocks cannot be co-resident on the
+  /// device, which fits at most `max_blocks`.
+  CooperativeGridTooLarge{blocks: u64, max_blocks: u64},
+  /// A graph node does not belong to the graph it was used with.
+  UnknownGraphNode{index: usize},
+  /// Adding the dependency of node `to` on node `from` would create a cycle.
+  GraphCycle{from: usize, to: usize},
+  /// A pool allocation of `size` bytes would exceed the pool limit.
+  PoolLimit{size: usize, reserved_bytes: usize, max_reserved_bytes: usize},
+  /// A device symbol of `size` bytes was accessed as a type of
+  /// `expected` bytes.
+  SymbolSizeMismatch{size: usize, expected: usize},
+}
 
 impl CudaError {
+  /// Wrap an error code returned by the runtime.
+  pub fn from_raw(e: cudaError_t) -> CudaError {
+    #[cfg(feature = "dynamic_loading")]
+    {
+      if e == crate::ffi::dynamic::RUNTIME_UNAVAILABLE {
+        return CudaError::RuntimeUnavailable;
+      }
+    }
+    CudaError::Runtime(e)
+  }
+
+  /// The runtime error code, if this error came from the runtime.
+  pub fn raw(&self) -> Option<cudaError_t> {
+    match *self {
+      CudaError::Runtime(e) => Some(e),
+      _ => None,
+    }
+  }
+
+  /// The runtime error code. Errors which are detected by `cudart` before
+  /// calling into the runtime report `cudaErrorInvalidValue`.
   pub fn get_code(&self) -> u32 {
-    let &CudaError(e) = self;
-    e as _
+    match *self {
+      CudaError::Runtime(e) => e as _,
+      _ => cudaError_cudaErrorInvalidValue as _,
+    }
   }
 
   pub fn get_string(&self) -> String {
-    let raw_s = unsafe { cudaGetErrorString(self.0) };
+    let code = match *self {
+      CudaError::Runtime(e) => e,
+      CudaError::RuntimeUnavailable => {
+        return format!("CUDA runtime library unavailable");
+      }
+      CudaError::SizeOverflow => {
+        return format!("byte size overflow");
+      }
+      CudaError::PitchTooSmall{width_bytes, pitch_bytes} => {
+        return format!("width of {} bytes exceeds pitch of {} bytes",
+            width_bytes, pitch_bytes);
+      }
+      CudaError::OutOfBounds{offset, size, alloc_size} => {
+        return format!("access of {} bytes at offset {} exceeds allocation of {} bytes",
+            size, offset, alloc_size);
+      }
+      CudaError::UnknownPointer{ptr} => {
+        return format!("unknown pointer 0x{:x}", ptr);
+      }
+      CudaError::DoubleFree{ptr} => {
+        return format!("double free of pointer 0x{:x}", ptr);
+      }
+      CudaError::LaunchOutOfRange{dim, value, max} => {
+        return format!("launch {} of {} is out of range (max {})", dim, value, max);
+      }
+      CudaError::CooperativeLaunchUnsupported{device} => {
+        return format!("device {} does not support cooperative launch", device);
+      }
+      CudaError::CooperativeGridTooLarge{blocks, max_blocks} => {
+        return format!("cooperative launch of {} blocks exceeds the {} co-resident blocks", blocks, max_blocks);
+      }
+      CudaError::UnknownGraphNode{index} => {
+        return format!("graph node {} does not belong to the graph", index);
+      }
+      CudaError::GraphCycle{from, to} => {
+        return format!("dependency of graph node {} on node {} would create a cycle", to, from);
+      }
+      CudaError::PoolLimit{size, reserved_bytes, max_reserved_bytes} => {
+        return format!("pool allocation of {} bytes exceeds limit ({} of {} bytes reserved)",
+            size, reserved_bytes, max_reserved_bytes);
+      }
+      CudaError::SymbolSizeMismatch{size, expected} => {
+        return format!("device symbol of {} bytes accessed as {} bytes", size, expected);
+      }
+    };
+    let raw_s = unsafe { cudaGetErrorString(code) };
     if raw_s.is_null() {
       return format!("(null)");
     }
@@ -49,26 +170,67 @@ pub type CudaResult<T> = Result<T, CudaError>;
 
 pub fn get_driver_version() -> CudaResult<i32> {
   let mut version: c_int = -1;
-  match unsafe { cudaDriverGetVersion(&mut version as *mut c_int) } {
+  match traced!("cudaDriverGetVersion", unsafe { cudaDriverGetVersion(&mut version as *mut c_int) }) {
     cudaError_cudaSuccess => {
       assert!(version >= 0);
       Ok(version)
     }
-    e => Err(CudaError(e)),
+    e => Err(CudaError::from_raw(e)),
   }
 }
 
+/// The version of the runtime in use. With the `static` feature this is
+/// the runtime linked into the binary, whatever is installed on the host.
+///
+/// Corresponds to `cudaRuntimeGetVersion`.
 pub fn get_runtime_version() -> CudaResult<i32> {
   let mut version: c_int = -1;
-  match unsafe { cudaRuntimeGetVersion(&mut version as *mut c_int) } {
+  match traced!("cudaRuntimeGetVersion", unsafe { cudaRuntimeGetVersion(&mut version as *mut c_int) }) {
     cudaError_cudaSuccess => {
       assert!(version >= 0);
       Ok(version)
     }
-    e => Err(CudaError(e)),
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// Check the result of a runtime call made from `name`'s `drop()`.
+pub(crate) fn check_drop(name: &str, e: cudaError_t) {
+  match e {
+    cudaError_cudaSuccess => {}
+    e => drop_failed(name, CudaError::from_raw(e)),
+  }
+}
+
+/// Panic on an error from `name`'s `drop()`.
+pub(crate) fn drop_failed(name: &str, err: CudaError) {
+  match err {
+    CudaError::Runtime(cudaError_cudaErrorCudartUnloading) => {
+      // NB(20160308): Sometimes drop() is called while the global runtime
+      // is shutting down; suppress these errors.
+    }
+    err => {
+      panic!("FATAL: {}::drop() failed: {:?} ({})",
+          name, err, err.get_string());
+    }
   }
 }
 
+/// Run `f` with `device` as the current device, restoring the previous
+/// current device afterwards.
+pub(crate) fn with_device<T, F: FnOnce() -> CudaResult<T>>(device: i32, f: F) -> CudaResult<T> {
+  let prev_dev = CudaDevice::get_current()?;
+  if prev_dev.0 == device {
+    return f();
+  }
+  CudaDevice(device).set_current()?;
+  let res = f();
+  let restore = prev_dev.set_current();
+  let x = res?;
+  restore?;
+  Ok(x)
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct CudaDevice(pub i32);
 
@@ -78,12 +240,12 @@ impl CudaDevice {
   /// Corresponds to `cudaGetDeviceCount`.
   pub fn count() -> CudaResult<usize> {
     let mut count: c_int = 0;
-    match unsafe { cudaGetDeviceCount(&mut count as *mut c_int) } {
+    match traced!("cudaGetDeviceCount", unsafe { cudaGetDeviceCount(&mut count as *mut c_int) }) {
       cudaError_cudaSuccess => {
         assert!(count >= 0);
         Ok(count as usize)
       }
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -91,9 +253,9 @@ impl CudaDevice {
   ///
   /// Corresponds to `cudaDeviceReset`.
   pub fn reset_current() -> CudaResult<()> {
-    match unsafe { cudaDeviceReset() } {
+    match traced!("cudaDeviceReset", unsafe { cudaDeviceReset() }) {
       cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -101,9 +263,9 @@ impl CudaDevice {
   ///
   /// Corresponds to `cudaDeviceSynchronize`.
   pub fn synchronize_current() -> CudaResult<()> {
-    match unsafe { cudaDeviceSynchronize() } {
+    match traced!("cudaDeviceSynchronize", unsafe { cudaDeviceSynchronize() }) {
       cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -111,9 +273,9 @@ impl CudaDevice {
   ///
   /// Corresponds to `cudaSetDeviceFlags`.
   pub fn set_flags_current(flags: u32) -> CudaResult<()> {
-    match unsafe { cudaSetDeviceFlags(flags as c_uint) } {
+    match traced!("cudaSetDeviceFlags", {flags}, unsafe { cudaSetDeviceFlags(flags as c_uint) }) {
       cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -122,9 +284,9 @@ impl CudaDevice {
   /// Corresponds to `cudaGetDevice`.
   pub fn get_current() -> CudaResult<CudaDevice> {
     let mut curr_dev: c_int = 0;
-    match unsafe { cudaGetDevice(&mut curr_dev as *mut c_int) } {
+    match traced!("cudaGetDevice", unsafe { cudaGetDevice(&mut curr_dev as *mut c_int) }) {
       cudaError_cudaSuccess => Ok(CudaDevice(curr_dev)),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -132,9 +294,9 @@ impl CudaDevice {
   ///
   /// Corresponds to `cudaSetDevice`.
   pub fn set_current(&self) -> CudaResult<()> {
-    match unsafe { cudaSetDevice(self.0 as c_int) } {
+    match traced!("cudaSetDevice", {device = self.0}, unsafe { cudaSetDevice(self.0 as c_int) }) {
       cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -143,20 +305,70 @@ impl CudaDevice {
   /// Corresponds to `cudaGetDeviceProperties`.
   pub fn get_properties(&self) -> CudaResult<cudaDeviceProp> {
     let mut prop: cudaDeviceProp = unsafe { zeroed() };
-    match unsafe { cudaGetDeviceProperties(&mut prop as *mut cudaDeviceProp, self.0 as c_int) } {
+    match traced!("cudaGetDeviceProperties", {device = self.0}, unsafe { cudaGetDeviceProperties(&mut prop as *mut cudaDeviceProp, self.0 as c_int) }) {
       cudaError_cudaSuccess => Ok(prop),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
+  /// Query the properties of the given device which are relevant to launch
+  /// configuration, see `CudaDeviceProperties`.
+  ///
+  /// Corresponds to `cudaGetDeviceProperties`.
+  pub fn get_device_properties(&self) -> CudaResult<CudaDeviceProperties> {
+    self.get_properties().map(|prop| CudaDeviceProperties::from_raw(&prop))
+  }
+
   /// Query the given attribute for the given device.
   ///
   /// Corresponds to `cudaGetDeviceAttribute`.
   pub fn get_attribute(&self, attr: cudaDeviceAttr) -> CudaResult<i32> {
     let mut value: c_int = 0;
-    match unsafe { cudaDeviceGetAttribute(&mut value as *mut c_int, attr, self.0 as c_int) } {
+    match traced!("cudaDeviceGetAttribute", {device = self.0, attr = ?attr}, unsafe { cudaDeviceGetAttribute(&mut value as *mut c_int, attr, self.0 as c_int) }) {
       cudaError_cudaSuccess => Ok(value as i32),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Query the preferred L1/shared memory split of the current device.
+  ///
+  /// Corresponds to `cudaDeviceGetCacheConfig`.
+  pub fn get_cache_config_current() -> CudaResult<CachePreference> {
+    let mut config: cudaFuncCache = unsafe { zeroed() };
+    match traced!("cudaDeviceGetCacheConfig", unsafe { cudaDeviceGetCacheConfig(&mut config as *mut cudaFuncCache) }) {
+      cudaError_cudaSuccess => Ok(CachePreference::from_raw(config)),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Set the preferred L1/shared memory split of the current device.
+  ///
+  /// Corresponds to `cudaDeviceSetCacheConfig`.
+  pub fn set_cache_config_current(pref: CachePreference) -> CudaResult<()> {
+    match traced!("cudaDeviceSetCacheConfig", {pref = ?pref}, unsafe { cudaDeviceSetCacheConfig(pref.to_raw()) }) {
+      cudaError_cudaSuccess => Ok(()),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Query the shared memory bank size of the current device.
+  ///
+  /// Corresponds to `cudaDeviceGetSharedMemConfig`.
+  pub fn get_shared_mem_config_current() -> CudaResult<SharedMemBankSize> {
+    let mut config: cudaSharedMemConfig = unsafe { zeroed() };
+    match traced!("cudaDeviceGetSharedMemConfig", unsafe { cudaDeviceGetSharedMemConfig(&mut config as *mut cudaSharedMemConfig) }) {
+      cudaError_cudaSuccess => Ok(SharedMemBankSize::from_raw(config)),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Set the shared memory bank size of the current device.
+  ///
+  /// Corresponds to `cudaDeviceSetSharedMemConfig`.
+  pub fn set_shared_mem_config_current(bank_size: SharedMemBankSize) -> CudaResult<()> {
+    match traced!("cudaDeviceSetSharedMemConfig", {bank_size = ?bank_size}, unsafe { cudaDeviceSetSharedMemConfig(bank_size.to_raw()) }) {
+      cudaError_cudaSuccess => Ok(()),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -165,9 +377,9 @@ impl CudaDevice {
   /// Corresponds to `cudaDeviceCanAccessPeer`.
   pub fn can_access_peer(&self, peer_dev: i32) -> CudaResult<bool> {
     let mut access: c_int = 0;
-    match unsafe { cudaDeviceCanAccessPeer(&mut access as *mut c_int, self.0 as c_int, peer_dev as c_int) } {
+    match traced!("cudaDeviceCanAccessPeer", {device = self.0, peer_device = peer_dev}, unsafe { cudaDeviceCanAccessPeer(&mut access as *mut c_int, self.0 as c_int, peer_dev as c_int) }) {
       cudaError_cudaSuccess => Ok(access != 0),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -176,10 +388,10 @@ impl CudaDevice {
   ///
   /// Corresponds to `cudaDeviceEnablePeerAccess`.
   pub fn enable_peer_access_current(peer_dev: i32) -> CudaResult<bool> {
-    match unsafe { cudaDeviceEnablePeerAccess(peer_dev as c_int, 0) } {
+    match traced!("cudaDeviceEnablePeerAccess", {peer_device = peer_dev}, unsafe { cudaDeviceEnablePeerAccess(peer_dev as c_int, 0) }) {
       cudaError_cudaSuccess => Ok(false),
       cudaError_cudaErrorPeerAccessAlreadyEnabled => Ok(true),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -188,10 +400,114 @@ impl CudaDevice {
   ///
   /// Corresponds to `cudaDeviceDisablePeerAccess`.
   pub fn disable_peer_access_current(peer_dev: i32) -> CudaResult<bool> {
-    match unsafe { cudaDeviceDisablePeerAccess(peer_dev as c_int) } {
+    match traced!("cudaDeviceDisablePeerAccess", {peer_device = peer_dev}, unsafe { cudaDeviceDisablePeerAccess(peer_dev as c_int) }) {
       cudaError_cudaSuccess => Ok(true),
       cudaError_cudaErrorPeerAccessNotEnabled => Ok(false),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
+
+/// Preferred split between L1 cache and shared memory.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CachePreference {
+  None,
+  Shared,
+  L1,
+  Equal,
+}
+
+impl CachePreference {
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn to_raw(&self) -> cudaFuncCache {
+    match *self {
+      CachePreference::None   => cudaFuncCache_cudaFuncCachePreferNone,
+      CachePreference::Shared => cudaFuncCache_cudaFuncCachePreferShared,
+      CachePreference::L1     => cudaFuncCache_cudaFuncCachePreferL1,
+      CachePreference::Equal  => cudaFuncCache_cudaFuncCachePreferEqual,
+    }
+  }
+
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn from_raw(config: cudaFuncCache) -> CachePreference {
+    match config {
+      cudaFuncCache_cudaFuncCachePreferShared => CachePreference::Shared,
+      cudaFuncCache_cudaFuncCachePreferL1     => CachePreference::L1,
+      cudaFuncCache_cudaFuncCachePreferEqual  => CachePreference::Equal,
+      _ => CachePreference::None,
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SharedMemBankSize {
+  Default,
+  FourByte,
+  EightByte,
+}
+
+impl SharedMemBankSize {
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn to_raw(&self) -> cudaSharedMemConfig {
+    match *self {
+      SharedMemBankSize::Default    => cudaSharedMemConfig_cudaSharedMemBankSizeDefault,
+      SharedMemBankSize::FourByte   => cudaSharedMemConfig_cudaSharedMemBankSizeFourByte,
+      SharedMemBankSize::EightByte  => cudaSharedMemConfig_cudaSharedMemBankSizeEightByte,
+    }
+  }
+
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn from_raw(config: cudaSharedMemConfig) -> SharedMemBankSize {
+    match config {
+      cudaSharedMemConfig_cudaSharedMemBankSizeFourByte   => SharedMemBankSize::FourByte,
+      cudaSharedMemConfig_cudaSharedMemBankSizeEightByte  => SharedMemBankSize::EightByte,
+      _ => SharedMemBankSize::Default,
+    }
+  }
+}
+
+/// A subset of `cudaDeviceProp` in plain Rust types, so that it can also be
+/// filled in by hand, e.g. to compute launch configurations offline.
+#[derive(Clone, Debug, Default)]
+pub struct CudaDeviceProperties {
+  pub name:                             String,
+  pub major:                            i32,
+  pub minor:                            i32,
+  pub total_global_mem:                 usize,
+  pub multiprocessor_count:             u32,
+  pub warp_size:                        u32,
+  pub max_threads_per_block:            u32,
+  pub max_threads_per_multiprocessor:   u32,
+  pub regs_per_block:                   u32,
+  pub regs_per_multiprocessor:          u32,
+  pub shared_mem_per_block:             usize,
+  /// The largest shared memory size a block can opt into; equal to
+  /// `shared_mem_per_block` on devices without opt-in.
+  pub shared_mem_per_block_optin:       usize,
+  pub shared_mem_per_multiprocessor:    usize,
+}
+
+impl CudaDeviceProperties {
+  pub fn from_raw(prop: &cudaDeviceProp) -> CudaDeviceProperties {
+    let name = unsafe { CStr::from_ptr(prop.name.as_ptr()) };
+    #[cfg(cuda_gte_9_0)]
+    let shared_mem_per_block_optin = prop.sharedMemPerBlockOptin.max(prop.sharedMemPerBlock);
+    #[cfg(not(cuda_gte_9_0))]
+    let shared_mem_per_block_optin = prop.sharedMemPerBlock;
+    CudaDeviceProperties{
+      name:                             name.to_string_lossy().into_owned(),
+      major:                            prop.major as i32,
+      minor:                            prop.minor as i32,
+      total_global_mem:                 prop.totalGlobalMem,
+      multiprocessor_count:             prop.multiProcessorCount as u32,
+      warp_size:                        prop.warpSize as u32,
+      max_threads_per_block:            prop.maxThreadsPerBlock as u32,
+      max_threads_per_multiprocessor:   prop.maxThreadsPerMultiProcessor as u32,
+      regs_per_block:                   prop.regsPerBlock as u32,
+      regs_per_multiprocessor:          prop.regsPerMultiprocessor as u32,
+      shared_mem_per_block:             prop.sharedMemPerBlock,
+      shared_mem_per_block_optin,
+      shared_mem_per_multiprocessor:    prop.sharedMemPerMultiprocessor,
     }
   }
 }
@@ -207,18 +523,7 @@ unsafe impl Sync for CudaStream {}
 impl Drop for CudaStream {
   fn drop(&mut self) {
     if !self.ptr.is_null() {
-      match unsafe { cudaStreamDestroy(self.ptr) } {
-        cudaError_cudaSuccess => {}
-        cudaError_cudaErrorCudartUnloading => {
-          // NB(20160308): Sometimes drop() is called while the global runtime
-          // is shutting down; suppress these errors.
-        }
-        e => {
-          let err = CudaError(e);
-          panic!("FATAL: CudaStream::drop() failed: {:?} ({})",
-              err, err.get_string());
-        }
-      }
+      check_drop("CudaStream", traced!("cudaStreamDestroy", {stream = ?self.ptr}, unsafe { cudaStreamDestroy(self.ptr) }));
     }
   }
 }
@@ -230,9 +535,9 @@ impl CudaStream {
 
   pub fn create() -> CudaResult<CudaStream> {
     let mut ptr: cudaStream_t = null_mut();
-    match unsafe { cudaStreamCreate(&mut ptr as *mut cudaStream_t) } {
+    match traced!("cudaStreamCreate", unsafe { cudaStreamCreate(&mut ptr as *mut cudaStream_t) }) {
       cudaError_cudaSuccess => Ok(CudaStream{ptr: ptr}),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -244,24 +549,50 @@ impl CudaStream {
     self.ptr == other.ptr
   }
 
+  /// Enqueue `callback` to be called with the stream status once the work
+  /// enqueued so far has completed. Stream callbacks cannot be captured
+  /// into a graph; see `launch_host_func`.
+  ///
+  /// Corresponds to `cudaStreamAddCallback`.
   pub fn add_callback(&mut self, callback: extern "C" fn (stream: cudaStream_t, status: cudaError_t, user_data: *mut c_void), user_data: *mut c_void) -> CudaResult<()> {
-    match unsafe { cudaStreamAddCallback(self.ptr, Some(callback), user_data, 0) } {
-      cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e)),
+    let op = timeline::begin(self);
+    match traced!("cudaStreamAddCallback", {stream = ?self.ptr}, unsafe { cudaStreamAddCallback(self.ptr, Some(callback), user_data, 0) }) {
+      cudaError_cudaSuccess => {
+        timeline::end(op, "cudaStreamAddCallback", None, self);
+        Ok(())
+      }
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Enqueue `func` to be called once the work enqueued so far has
+  /// completed. Unlike `add_callback`, `func` is not called at all after an
+  /// error, and the call can be captured into a graph.
+  ///
+  /// Corresponds to `cudaLaunchHostFunc` (CUDA 10.0 and later).
+  #[cfg(cuda_gte_10_0)]
+  pub fn launch_host_func(&mut self, func: extern "C" fn (user_data: *mut c_void), user_data: *mut c_void) -> CudaResult<()> {
+    let op = timeline::begin(self);
+    match traced!("cudaLaunchHostFunc", {stream = ?self.ptr}, unsafe { cudaLaunchHostFunc(self.ptr, Some(func), user_data) }) {
+      cudaError_cudaSuccess => {
+        timeline::end(op, "cudaLaunchHostFunc", None, self);
+        Ok(())
+      }
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
   pub fn synchronize(&mut self) -> CudaResult<()> {
-    match unsafe { cudaStreamSynchronize(self.ptr) } {
+    match traced!("cudaStreamSynchronize", {stream = ?self.ptr}, unsafe { cudaStreamSynchronize(self.ptr) }) {
       cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
   pub fn wait_event(&mut self, event: &mut CudaEvent) -> CudaResult<()> {
-    match unsafe { cudaStreamWaitEvent(self.ptr, event.as_mut_ptr(), 0) } {
+    match traced!("cudaStreamWaitEvent", {stream = ?self.ptr, event = ?event.ptr}, unsafe { cudaStreamWaitEvent(self.ptr, event.as_mut_ptr(), 0) }) {
       cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e))
+      e => Err(CudaError::from_raw(e))
     }
   }
 }
@@ -283,18 +614,7 @@ unsafe impl Sync for CudaEvent {}
 impl Drop for CudaEvent {
   fn drop(&mut self) {
     if !self.ptr.is_null() {
-      match unsafe { cudaEventDestroy(self.ptr) } {
-        cudaError_cudaSuccess => {}
-        cudaError_cudaErrorCudartUnloading => {
-          // NB(20160308): Sometimes drop() is called while the global runtime
-          // is shutting down; suppress these errors.
-        }
-        e => {
-          let err = CudaError(e);
-          panic!("FATAL: CudaEvent::drop() failed: {:?} ({})",
-              err, err.get_string());
-        }
-      }
+      check_drop("CudaEvent", traced!("cudaEventDestroy", {event = ?self.ptr}, unsafe { cudaEventDestroy(self.ptr) }));
     }
   }
 }
@@ -302,9 +622,9 @@ impl Drop for CudaEvent {
 impl CudaEvent {
   pub fn create() -> CudaResult<CudaEvent> {
     let mut ptr = null_mut() as cudaEvent_t;
-    match unsafe { cudaEventCreate(&mut ptr as *mut cudaEvent_t) } {
+    match traced!("cudaEventCreate", unsafe { cudaEventCreate(&mut ptr as *mut cudaEvent_t) }) {
       cudaError_cudaSuccess => Ok(CudaEvent{ptr: ptr}),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -316,11 +636,17 @@ impl CudaEvent {
     Self::create_with_flags(0x02)
   }
 
+  /// Create an event which can be shared with other processes through a
+  /// `CudaIpcEventHandle`. Interprocess events do not record timing.
+  pub fn create_interprocess() -> CudaResult<CudaEvent> {
+    Self::create_with_flags(0x02 | 0x04)
+  }
+
   pub fn create_with_flags(flags: u32) -> CudaResult<CudaEvent> {
     let mut ptr = null_mut() as cudaEvent_t;
-    match unsafe { cudaEventCreateWithFlags(&mut ptr as *mut cudaEvent_t, flags) } {
+    match traced!("cudaEventCreateWithFlags", {flags}, unsafe { cudaEventCreateWithFlags(&mut ptr as *mut cudaEvent_t, flags) }) {
       cudaError_cudaSuccess => Ok(CudaEvent{ptr: ptr}),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
@@ -328,74 +654,171 @@ impl CudaEvent {
     self.ptr
   }
 
+  /// Take ownership of an event created by the runtime.
+  pub(crate) unsafe fn from_raw(ptr: cudaEvent_t) -> CudaEvent {
+    CudaEvent{ptr}
+  }
+
   pub fn ptr_eq(&self, other: &CudaEvent) -> bool {
     self.ptr == other.ptr
   }
 
   pub fn query(&mut self) -> CudaResult<CudaEventStatus> {
-    match unsafe { cudaEventQuery(self.ptr) } {
+    match traced!("cudaEventQuery", {event = ?self.ptr}, unsafe { cudaEventQuery(self.ptr) }) {
       cudaError_cudaSuccess => Ok(CudaEventStatus::Complete),
       cudaError_cudaErrorNotReady => Ok(CudaEventStatus::NotReady),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
   pub fn record(&mut self, stream: &mut CudaStream) -> CudaResult<()> {
-    match unsafe { cudaEventRecord(self.ptr, stream.as_mut_ptr()) } {
+    match traced!("cudaEventRecord", {event = ?self.ptr, stream = ?stream.ptr}, unsafe { cudaEventRecord(self.ptr, stream.as_mut_ptr()) }) {
       cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 
   pub fn synchronize(&mut self) -> CudaResult<()> {
-    match unsafe { cudaEventSynchronize(self.ptr) } {
+    match traced!("cudaEventSynchronize", {event = ?self.ptr}, unsafe { cudaEventSynchronize(self.ptr) }) {
       cudaError_cudaSuccess => Ok(()),
-      e => Err(CudaError(e)),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Milliseconds elapsed between the completion of this event and of
+  /// `end`. Both events must have completed and recorded timing.
+  ///
+  /// Corresponds to `cudaEventElapsedTime`.
+  pub fn elapsed_ms(&self, end: &CudaEvent) -> CudaResult<f32> {
+    let mut ms: f32 = 0.0;
+    match traced!("cudaEventElapsedTime", {start = ?self.ptr, end = ?end.ptr}, unsafe { cudaEventElapsedTime(&mut ms as *mut f32, self.ptr, end.ptr) }) {
+      cudaError_cudaSuccess => Ok(ms),
+      e => Err(CudaError::from_raw(e)),
     }
   }
 }
 
 pub fn cuda_alloc_device(size: usize) -> CudaResult<*mut u8> {
   let mut dptr: *mut c_void = null_mut();
-  match unsafe { cudaMalloc(&mut dptr as *mut *mut c_void, size) } {
-    cudaError_cudaSuccess => Ok(dptr as *mut u8),
-    e => Err(CudaError(e)),
+  match traced!("cudaMalloc", {size}, unsafe { cudaMalloc(&mut dptr as *mut *mut c_void, size) }) {
+    cudaError_cudaSuccess => {
+      extent::insert(dptr as *mut u8, size);
+      #[cfg(feature = "alloc_tracking")]
+      tracking::record_alloc(dptr as *mut u8, size, AllocKind::Device);
+      Ok(dptr as *mut u8)
+    }
+    e => Err(CudaError::from_raw(e)),
   }
 }
 
 pub fn cuda_alloc_host(size: usize) -> CudaResult<*mut u8> {
   let mut ptr: *mut c_void = null_mut();
-  match unsafe { cudaMallocHost(&mut ptr as *mut *mut c_void, size) } {
-    cudaError_cudaSuccess => Ok(ptr as *mut u8),
-    e => Err(CudaError(e)),
+  match traced!("cudaMallocHost", {size}, unsafe { cudaMallocHost(&mut ptr as *mut *mut c_void, size) }) {
+    cudaError_cudaSuccess => {
+      extent::insert(ptr as *mut u8, size);
+      #[cfg(feature = "alloc_tracking")]
+      tracking::record_alloc(ptr as *mut u8, size, AllocKind::Host);
+      Ok(ptr as *mut u8)
+    }
+    e => Err(CudaError::from_raw(e)),
   }
 }
 
 pub unsafe fn cuda_free_device(dptr: *mut u8) -> CudaResult<()> {
-  match cudaFree(dptr as *mut c_void) {
+  // Freeing null is a no-op for the runtime, and is not tracked.
+  #[cfg(feature = "alloc_tracking")]
+  let record = if dptr.is_null() { None } else { Some(tracking::remove(dptr, AllocKind::Device)?) };
+  // NB: Forget the extent before freeing, so that a concurrent allocation
+  // reusing the same address is not clobbered.
+  let size = extent::remove(dptr);
+  match traced!("cudaFree", {ptr = ?dptr}, cudaFree(dptr as *mut c_void)) {
     cudaError_cudaSuccess => Ok(()),
-    e => Err(CudaError(e)),
+    e => {
+      if let Some(size) = size {
+        extent::insert(dptr, size);
+      }
+      #[cfg(feature = "alloc_tracking")]
+      {
+        if let Some(record) = record {
+          tracking::restore(record);
+        }
+      }
+      Err(CudaError::from_raw(e))
+    }
   }
 }
 
 pub unsafe fn cuda_free_host(ptr: *mut u8) -> CudaResult<()> {
-  match cudaFreeHost(ptr as *mut c_void) {
+  // Freeing null is a no-op for the runtime, and is not tracked.
+  #[cfg(feature = "alloc_tracking")]
+  let record = if ptr.is_null() { None } else { Some(tracking::remove(ptr, AllocKind::Host)?) };
+  let size = extent::remove(ptr);
+  match traced!("cudaFreeHost", {ptr = ?ptr}, cudaFreeHost(ptr as *mut c_void)) {
     cudaError_cudaSuccess => Ok(()),
-    e => Err(CudaError(e)),
+    e => {
+      if let Some(size) = size {
+        extent::insert(ptr, size);
+      }
+      #[cfg(feature = "alloc_tracking")]
+      {
+        if let Some(record) = record {
+          tracking::restore(record);
+        }
+      }
+      Err(CudaError::from_raw(e))
+    }
+  }
+}
+
+/// Byte size of `len` elements of type `T`.
+pub(crate) fn checked_bytes<T>(len: usize) -> CudaResult<usize> {
+  len.checked_mul(size_of::<T>()).ok_or(CudaError::SizeOverflow)
+}
+
+/// Check that a host slice of `host_len` elements matches a buffer of `len`
+/// elements of type `T`.
+pub(crate) fn check_copy_len<T>(len: usize, host_len: usize) -> CudaResult<()> {
+  if len != host_len {
+    return Err(CudaError::OutOfBounds{
+      offset:     0,
+      size:       checked_bytes::<T>(host_len)?,
+      alloc_size: checked_bytes::<T>(len)?,
+    });
+  }
+  Ok(())
+}
+
+/// Byte extent spanned by `height` rows of `width_bytes` each, spaced
+/// `pitch_bytes` apart.
+pub(crate) fn checked_bytes_2d(width_bytes: usize, pitch_bytes: usize, height: usize) -> CudaResult<usize> {
+  if width_bytes > pitch_bytes {
+    return Err(CudaError::PitchTooSmall{width_bytes, pitch_bytes});
+  }
+  if height == 0 {
+    return Ok(0);
   }
+  (height - 1).checked_mul(pitch_bytes)
+    .and_then(|b| b.checked_add(width_bytes))
+    .ok_or(CudaError::SizeOverflow)
 }
 
 pub unsafe fn cuda_memset(dptr: *mut u8, value: i32, size: usize) -> CudaResult<()> {
-  match cudaMemset(dptr as *mut c_void, value, size) {
+  extent::check(dptr, size)?;
+  match traced!("cudaMemset", {ptr = ?dptr, value, size}, cudaMemset(dptr as *mut c_void, value, size)) {
     cudaError_cudaSuccess => Ok(()),
-    e => Err(CudaError(e)),
+    e => Err(CudaError::from_raw(e)),
   }
 }
 
 pub unsafe fn cuda_memset_async(dptr: *mut u8, value: i32, size: usize, stream: &mut CudaStream) -> CudaResult<()> {
-  match cudaMemsetAsync(dptr as *mut c_void, value, size, stream.as_mut_ptr()) {
-    cudaError_cudaSuccess => Ok(()),
-    e => Err(CudaError(e)),
+  extent::check(dptr, size)?;
+  let op = timeline::begin(stream);
+  match traced!("cudaMemsetAsync", {ptr = ?dptr, value, size, stream = ?stream.ptr}, cudaMemsetAsync(dptr as *mut c_void, value, size, stream.as_mut_ptr())) {
+    cudaError_cudaSuccess => {
+      timeline::end(op, "cudaMemsetAsync", Some(size), stream);
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
   }
 }
 
@@ -428,14 +851,17 @@ pub unsafe fn cuda_memcpy<T>(
     kind: CudaMemcpyKind) -> CudaResult<()>
 where T: Copy + 'static
 {
-  match cudaMemcpy(
+  let size = checked_bytes::<T>(len)?;
+  extent::check(dst as *const u8, size)?;
+  extent::check(src as *const u8, size)?;
+  match traced!("cudaMemcpy", {size, kind = ?kind}, cudaMemcpy(
       dst as *mut c_void,
       src as *const c_void,
-      len * size_of::<T>(),
-      kind.to_raw())
+      size,
+      kind.to_raw()))
   {
     cudaError_cudaSuccess => Ok(()),
-    e => Err(CudaError(e)),
+    e => Err(CudaError::from_raw(e)),
   }
 }
 
@@ -447,15 +873,22 @@ pub unsafe fn cuda_memcpy_async<T>(
     stream: &mut CudaStream) -> CudaResult<()>
 where T: Copy + 'static
 {
-  match cudaMemcpyAsync(
+  let size = checked_bytes::<T>(len)?;
+  extent::check(dst as *const u8, size)?;
+  extent::check(src as *const u8, size)?;
+  let op = timeline::begin(stream);
+  match traced!("cudaMemcpyAsync", {size, kind = ?kind, stream = ?stream.ptr}, cudaMemcpyAsync(
       dst as *mut c_void,
       src as *const c_void,
-      len * size_of::<T>(),
+      size,
       kind.to_raw(),
-      stream.as_mut_ptr())
+      stream.as_mut_ptr()))
   {
-    cudaError_cudaSuccess => Ok(()),
-    e => Err(CudaError(e)),
+    cudaError_cudaSuccess => {
+      timeline::end(op, "cudaMemcpyAsync", Some(size), stream);
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
   }
 }
 
@@ -470,10 +903,13 @@ pub unsafe fn cuda_memcpy_2d_async<T>(
     stream: &mut CudaStream) -> CudaResult<()>
 where T: Copy + 'static
 {
-  let width_bytes = width * size_of::<T>();
-  assert!(width_bytes <= dst_pitch_bytes);
-  assert!(width_bytes <= src_pitch_bytes);
-  match cudaMemcpy2DAsync(
+  let width_bytes = checked_bytes::<T>(width)?;
+  let dst_size = checked_bytes_2d(width_bytes, dst_pitch_bytes, height)?;
+  let src_size = checked_bytes_2d(width_bytes, src_pitch_bytes, height)?;
+  extent::check(dst as *const u8, dst_size)?;
+  extent::check(src as *const u8, src_size)?;
+  let op = timeline::begin(stream);
+  match traced!("cudaMemcpy2DAsync", {width_bytes, height, dst_pitch_bytes, src_pitch_bytes, kind = ?kind, stream = ?stream.ptr}, cudaMemcpy2DAsync(
       dst as *mut c_void,
       dst_pitch_bytes,
       src as *const c_void,
@@ -481,10 +917,13 @@ where T: Copy + 'static
       width_bytes,
       height,
       kind.to_raw(),
-      stream.as_mut_ptr())
+      stream.as_mut_ptr()))
   {
-    cudaError_cudaSuccess => Ok(()),
-    e => Err(CudaError(e)),
+    cudaError_cudaSuccess => {
+      timeline::end(op, "cudaMemcpy2DAsync", Some(width_bytes * height), stream);
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
   }
 }
 
@@ -497,15 +936,93 @@ pub unsafe fn cuda_memcpy_peer_async<T>(
     stream: &mut CudaStream) -> CudaResult<()>
 where T: Copy + 'static
 {
-  match cudaMemcpyPeerAsync(
+  let size = checked_bytes::<T>(len)?;
+  extent::check(dst as *const u8, size)?;
+  extent::check(src as *const u8, size)?;
+  let op = timeline::begin(stream);
+  match traced!("cudaMemcpyPeerAsync", {size, dst_device = dst_device_idx, src_device = src_device_idx, stream = ?stream.ptr}, cudaMemcpyPeerAsync(
       dst as *mut c_void,
       dst_device_idx,
       src as *const c_void,
       src_device_idx,
-      len * size_of::<T>(),
-      stream.as_mut_ptr())
+      size,
+      stream.as_mut_ptr()))
   {
-    cudaError_cudaSuccess => Ok(()),
-    e => Err(CudaError(e)),
+    cudaError_cudaSuccess => {
+      timeline::end(op, "cudaMemcpyPeerAsync", Some(size), stream);
+      Ok(())
+    }
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+/// An owned device allocation of `len` elements of type `T`.
+#[derive(Debug)]
+pub struct CudaDeviceBuffer<T: Copy + 'static> {
+  ptr:  *mut T,
+  len:  usize,
+}
+
+unsafe impl<T: Copy + 'static> Send for CudaDeviceBuffer<T> {}
+unsafe impl<T: Copy + 'static> Sync for CudaDeviceBuffer<T> {}
+
+impl<T: Copy + 'static> Drop for CudaDeviceBuffer<T> {
+  fn drop(&mut self) {
+    if let Err(err) = unsafe { cuda_free_device(self.ptr as *mut u8) } {
+      drop_failed("CudaDeviceBuffer", err);
+    }
+  }
+}
+
+impl<T: Copy + 'static> CudaDeviceBuffer<T> {
+  /// Allocate an uninitialized buffer on the current device.
+  pub unsafe fn alloc(len: usize) -> CudaResult<CudaDeviceBuffer<T>> {
+    let size = checked_bytes::<T>(len)?;
+    let ptr = cuda_alloc_device(size)? as *mut T;
+    Ok(CudaDeviceBuffer{ptr, len})
+  }
+
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  pub fn as_ptr(&self) -> *const T {
+    self.ptr
+  }
+
+  pub fn as_mut_ptr(&mut self) -> *mut T {
+    self.ptr
+  }
+
+  pub(crate) fn ptr_ref(&self) -> &*mut T {
+    &self.ptr
+  }
+
+  pub fn copy_from_host(&mut self, src: &[T]) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, src.len())?;
+    unsafe { cuda_memcpy(self.ptr, src.as_ptr(), self.len, CudaMemcpyKind::HostToDevice) }
+  }
+
+  pub fn copy_to_host(&self, dst: &mut [T]) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, dst.len())?;
+    unsafe { cuda_memcpy(dst.as_mut_ptr(), self.ptr, self.len, CudaMemcpyKind::DeviceToHost) }
+  }
+
+  /// Enqueue a copy from host memory on `stream`. `src` must stay valid
+  /// and unmodified until the copy completes.
+  pub unsafe fn copy_from_host_async(&mut self, src: &[T], stream: &mut CudaStream) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, src.len())?;
+    cuda_memcpy_async(self.ptr, src.as_ptr(), self.len, CudaMemcpyKind::HostToDevice, stream)
+  }
+
+  /// Enqueue a copy into host memory on `stream`. `dst` must stay valid
+  /// and untouched until the copy completes.
+  pub unsafe fn copy_to_host_async(&self, dst: &mut [T], stream: &mut CudaStream) -> CudaResult<()> {
+    check_copy_len::<T>(self.len, dst.len())?;
+    cuda_memcpy_async(dst.as_mut_ptr(), self.ptr, self.len, CudaMemcpyKind::DeviceToHost, stream)
   }
 }
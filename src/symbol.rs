@@ -0,0 +1,174 @@
+//! Reading and writing `__device__` and `__constant__` variables.
+
+#[cfg(not(feature = "cuda_sys"))]
+use crate::ffi::*;
+use crate::runtime::{CudaError, CudaMemcpyKind, CudaResult, CudaStream};
+use crate::timeline;
+
+#[cfg(not(feature = "cuda_sys"))]
+use cuda_api_types::driver_types::*;
+#[cfg(feature = "cuda_sys")]
+use cuda_sys::cudart::*;
+
+use std::marker::{PhantomData};
+use std::mem::{MaybeUninit, size_of};
+use std::os::raw::{c_void};
+use std::ptr::{null_mut};
+
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaSuccess: cudaError_t = cudaError_t::Success;
+
+/// A device variable of type `T`, identified by the address of its
+/// host-side shadow (e.g. a variable declared in an `extern "C"` block and
+/// compiled by nvcc).
+#[derive(Debug)]
+pub struct DeviceSymbol<T: Copy + 'static> {
+  ptr:      *const c_void,
+  _marker:  PhantomData<T>,
+}
+
+impl<T: Copy + 'static> Clone for DeviceSymbol<T> {
+  fn clone(&self) -> DeviceSymbol<T> {
+    *self
+  }
+}
+
+impl<T: Copy + 'static> Copy for DeviceSymbol<T> {}
+
+unsafe impl<T: Copy + 'static> Send for DeviceSymbol<T> {}
+unsafe impl<T: Copy + 'static> Sync for DeviceSymbol<T> {}
+
+/// The size in bytes of the device variable `symbol`.
+///
+/// Corresponds to `cudaGetSymbolSize`.
+pub unsafe fn cuda_get_symbol_size(symbol: *const c_void) -> CudaResult<usize> {
+  let mut size: usize = 0;
+  match cudaGetSymbolSize(&mut size as *mut usize, symbol) {
+    cudaError_cudaSuccess => Ok(size),
+    e => Err(CudaError::from_raw(e)),
+  }
+}
+
+impl<T: Copy + 'static> DeviceSymbol<T> {
+  /// Wrap the device variable `symbol`, checking that its size matches
+  /// `T`. The device type of the variable must have the layout of `T`.
+  pub unsafe fn new(symbol: *const c_void) -> CudaResult<DeviceSymbol<T>> {
+    let size = cuda_get_symbol_size(symbol)?;
+    if size != size_of::<T>() {
+      return Err(CudaError::SymbolSizeMismatch{size, expected: size_of::<T>()});
+    }
+    Ok(DeviceSymbol{ptr: symbol, _marker: PhantomData})
+  }
+
+  pub fn as_ptr(&self) -> *const c_void {
+    self.ptr
+  }
+
+  /// The device address of the variable on the current device.
+  ///
+  /// Corresponds to `cudaGetSymbolAddress`.
+  pub fn address(&self) -> CudaResult<*mut T> {
+    let mut dptr: *mut c_void = null_mut();
+    match unsafe { cudaGetSymbolAddress(&mut dptr as *mut *mut c_void, self.ptr) } {
+      cudaError_cudaSuccess => Ok(dptr as *mut T),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Write `value` to the variable after the work enqueued on `stream`, and
+  /// wait for the write to complete.
+  pub fn write(&self, value: &T, stream: &mut CudaStream) -> CudaResult<()> {
+    unsafe { self.write_async(value, stream)? };
+    stream.synchronize()
+  }
+
+  /// Read the variable after the work enqueued on `stream`.
+  pub fn read(&self, stream: &mut CudaStream) -> CudaResult<T> {
+    let mut value = MaybeUninit::<T>::uninit();
+    unsafe {
+      self.read_raw_async(value.as_mut_ptr(), stream)?;
+      stream.synchronize()?;
+      Ok(value.assume_init())
+    }
+  }
+
+  /// Enqueue a write of `value` on `stream`. `value` must stay valid and
+  /// unmodified until the write completes.
+  ///
+  /// Corresponds to `cudaMemcpyToSymbolAsync`.
+  pub unsafe fn write_async(&self, value: &T, stream: &mut CudaStream) -> CudaResult<()> {
+    let op = timeline::begin(stream);
+    match cudaMemcpyToSymbolAsync(
+        self.ptr,
+        value as *const T as *const c_void,
+        size_of::<T>(),
+        0,
+        CudaMemcpyKind::HostToDevice.to_raw(),
+        stream.as_mut_ptr())
+    {
+      cudaError_cudaSuccess => {
+        timeline::end(op, "cudaMemcpyToSymbolAsync", Some(size_of::<T>()), stream);
+        Ok(())
+      }
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Enqueue a read of the variable into `dst` on `stream`. `dst` must stay
+  /// valid and untouched until the read completes.
+  pub unsafe fn read_async(&self, dst: &mut T, stream: &mut CudaStream) -> CudaResult<()> {
+    self.read_raw_async(dst, stream)
+  }
+
+  /// Corresponds to `cudaMemcpyFromSymbolAsync`.
+  unsafe fn read_raw_async(&self, dst: *mut T, stream: &mut CudaStream) -> CudaResult<()> {
+    let op = timeline::begin(stream);
+    match cudaMemcpyFromSymbolAsync(
+        dst as *mut c_void,
+        self.ptr,
+        size_of::<T>(),
+        0,
+        CudaMemcpyKind::DeviceToHost.to_raw(),
+        stream.as_mut_ptr())
+    {
+      cudaError_cudaSuccess => {
+        timeline::end(op, "cudaMemcpyFromSymbolAsync", Some(size_of::<T>()), stream);
+        Ok(())
+      }
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Write `value` to the variable, synchronizing with the device.
+  ///
+  /// Corresponds to `cudaMemcpyToSymbol`.
+  pub fn write_sync(&self, value: &T) -> CudaResult<()> {
+    match unsafe { cudaMemcpyToSymbol(
+        self.ptr,
+        value as *const T as *const c_void,
+        size_of::<T>(),
+        0,
+        CudaMemcpyKind::HostToDevice.to_raw()) }
+    {
+      cudaError_cudaSuccess => Ok(()),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  /// Read the variable, synchronizing with the device.
+  ///
+  /// Corresponds to `cudaMemcpyFromSymbol`.
+  pub fn read_sync(&self) -> CudaResult<T> {
+    let mut value = MaybeUninit::<T>::uninit();
+    match unsafe { cudaMemcpyFromSymbol(
+        value.as_mut_ptr() as *mut c_void,
+        self.ptr,
+        size_of::<T>(),
+        0,
+        CudaMemcpyKind::DeviceToHost.to_raw()) }
+    {
+      cudaError_cudaSuccess => Ok(unsafe { value.assume_init() }),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+}
@@ -0,0 +1,289 @@
+//! Texture and surface objects over CUDA arrays and device buffers.
+//!
+//! An object borrows the memory it reads or writes, so it cannot outlive
+//! it, and is passed to kernels as its 64 bit handle.
+
+use crate::array::{ChannelFormat, CudaArray};
+#[cfg(not(feature = "cuda_sys"))]
+use crate::ffi::*;
+use crate::launch::{KernelArg};
+use crate::runtime::{CudaDeviceBuffer, CudaError, CudaResult, check_drop, checked_bytes, checked_bytes_2d};
+
+#[cfg(not(feature = "cuda_sys"))]
+use cuda_api_types::driver_types::*;
+#[cfg(feature = "cuda_sys")]
+use cuda_sys::cudart::*;
+
+use std::marker::{PhantomData};
+use std::os::raw::{c_void};
+use std::ptr::{null};
+
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaSuccess: cudaError_t = cudaError_t::Success;
+#[cfg(feature = "cuda_sys")]
+const cudaError_cudaErrorCudartUnloading: cudaError_t = cudaError_t::CudartUnloading;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AddressMode {
+  Wrap,
+  Clamp,
+  Mirror,
+  Border,
+}
+
+impl AddressMode {
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn to_raw(&self) -> cudaTextureAddressMode {
+    match *self {
+      AddressMode::Wrap   => cudaTextureAddressMode_cudaAddressModeWrap,
+      AddressMode::Clamp  => cudaTextureAddressMode_cudaAddressModeClamp,
+      AddressMode::Mirror => cudaTextureAddressMode_cudaAddressModeMirror,
+      AddressMode::Border => cudaTextureAddressMode_cudaAddressModeBorder,
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FilterMode {
+  Point,
+  Linear,
+}
+
+impl FilterMode {
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn to_raw(&self) -> cudaTextureFilterMode {
+    match *self {
+      FilterMode::Point   => cudaTextureFilterMode_cudaFilterModePoint,
+      FilterMode::Linear  => cudaTextureFilterMode_cudaFilterModeLinear,
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReadMode {
+  /// Return elements as stored.
+  ElementType,
+  /// Return 8 and 16 bit integer elements as floats in `[0, 1]`, or
+  /// `[-1, 1]` if signed.
+  NormalizedFloat,
+}
+
+impl ReadMode {
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn to_raw(&self) -> cudaTextureReadMode {
+    match *self {
+      ReadMode::ElementType     => cudaTextureReadMode_cudaReadModeElementType,
+      ReadMode::NormalizedFloat => cudaTextureReadMode_cudaReadModeNormalizedFloat,
+    }
+  }
+}
+
+/// Sampling state for new texture objects. Defaults to clamped, point
+/// filtered element reads with unnormalized coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct TextureBuilder {
+  address_mode:       [AddressMode; 3],
+  filter_mode:        FilterMode,
+  read_mode:          ReadMode,
+  normalized_coords:  bool,
+  border_color:       [f32; 4],
+}
+
+impl Default for TextureBuilder {
+  fn default() -> TextureBuilder {
+    TextureBuilder{
+      address_mode:       [AddressMode::Clamp; 3],
+      filter_mode:        FilterMode::Point,
+      read_mode:          ReadMode::ElementType,
+      normalized_coords:  false,
+      border_color:       [0.0; 4],
+    }
+  }
+}
+
+impl TextureBuilder {
+  pub fn new() -> TextureBuilder {
+    TextureBuilder::default()
+  }
+
+  /// Set the address mode of every dimension.
+  pub fn address_mode(self, mode: AddressMode) -> TextureBuilder {
+    self.address_modes([mode; 3])
+  }
+
+  /// Set the address mode of each dimension. `Wrap` and `Mirror` require
+  /// normalized coordinates.
+  pub fn address_modes(mut self, modes: [AddressMode; 3]) -> TextureBuilder {
+    self.address_mode = modes;
+    self
+  }
+
+  /// `Linear` filtering requires floating point reads, i.e. float elements
+  /// or `ReadMode::NormalizedFloat`.
+  pub fn filter_mode(mut self, mode: FilterMode) -> TextureBuilder {
+    self.filter_mode = mode;
+    self
+  }
+
+  pub fn read_mode(mut self, mode: ReadMode) -> TextureBuilder {
+    self.read_mode = mode;
+    self
+  }
+
+  /// Address the texture with coordinates in `[0, 1)` rather than
+  /// `[0, extent)`.
+  pub fn normalized_coords(mut self, normalized: bool) -> TextureBuilder {
+    self.normalized_coords = normalized;
+    self
+  }
+
+  /// The value read outside the texture with `AddressMode::Border`.
+  pub fn border_color(mut self, color: [f32; 4]) -> TextureBuilder {
+    self.border_color = color;
+    self
+  }
+
+  #[cfg(not(feature = "cuda_sys"))]
+  pub fn to_raw(&self) -> cudaTextureDesc {
+    let mut desc = cudaTextureDesc::default();
+    for d in 0 .. 3 {
+      desc.addressMode[d] = self.address_mode[d].to_raw();
+    }
+    desc.filterMode = self.filter_mode.to_raw();
+    desc.readMode = self.read_mode.to_raw();
+    desc.normalizedCoords = self.normalized_coords as _;
+    #[cfg(cuda_gte_8_0)]
+    {
+      desc.borderColor = self.border_color;
+    }
+    desc
+  }
+
+  /// Create a texture object reading from a CUDA array.
+  pub fn build_array<'a, T: ChannelFormat>(&self, array: &'a CudaArray<T>) -> CudaResult<CudaTextureObject<'a>> {
+    let res = cudaResourceDesc{
+      resType:  cudaResourceType_cudaResourceTypeArray,
+      res:      cudaResourceDescRes{array: cudaResourceDescArray{array: array.as_ptr() as cudaArray_t}},
+    };
+    unsafe { CudaTextureObject::create(&res, &self.to_raw()) }
+  }
+
+  /// Create a texture object reading from a device buffer as 1D linear
+  /// memory. Linear textures support neither filtering nor normalized
+  /// coordinates.
+  pub fn build_linear<'a, T: ChannelFormat>(&self, buf: &'a CudaDeviceBuffer<T>) -> CudaResult<CudaTextureObject<'a>> {
+    let res = cudaResourceDesc{
+      resType:  cudaResourceType_cudaResourceTypeLinear,
+      res:      cudaResourceDescRes{linear: cudaResourceDescLinear{
+        devPtr:       buf.as_ptr() as *mut c_void,
+        desc:         T::DESC.to_raw(),
+        sizeInBytes:  checked_bytes::<T>(buf.len())?,
+      }},
+    };
+    unsafe { CudaTextureObject::create(&res, &self.to_raw()) }
+  }
+
+  /// Create a texture object reading from a device buffer as `height` rows
+  /// of `width` elements, spaced `pitch_bytes` apart. The pitch must meet
+  /// the device's texture pitch alignment.
+  pub fn build_pitch_2d<'a, T: ChannelFormat>(&self, buf: &'a CudaDeviceBuffer<T>, width: usize, height: usize, pitch_bytes: usize) -> CudaResult<CudaTextureObject<'a>> {
+    let size = checked_bytes_2d(checked_bytes::<T>(width)?, pitch_bytes, height)?;
+    let alloc_size = checked_bytes::<T>(buf.len())?;
+    if size > alloc_size {
+      return Err(CudaError::OutOfBounds{offset: 0, size, alloc_size});
+    }
+    let res = cudaResourceDesc{
+      resType:  cudaResourceType_cudaResourceTypePitch2D,
+      res:      cudaResourceDescRes{pitch2D: cudaResourceDescPitch2D{
+        devPtr:       buf.as_ptr() as *mut c_void,
+        desc:         T::DESC.to_raw(),
+        width,
+        height,
+        pitchInBytes: pitch_bytes,
+      }},
+    };
+    unsafe { CudaTextureObject::create(&res, &self.to_raw()) }
+  }
+}
+
+/// A texture object borrowing the array or buffer it reads from.
+#[derive(Debug)]
+pub struct CudaTextureObject<'a> {
+  raw:        cudaTextureObject_t,
+  _resource:  PhantomData<&'a ()>,
+}
+
+impl<'a> Drop for CudaTextureObject<'a> {
+  fn drop(&mut self) {
+    check_drop("CudaTextureObject", unsafe { cudaDestroyTextureObject(self.raw) });
+  }
+}
+
+impl<'a> CudaTextureObject<'a> {
+  /// Corresponds to `cudaCreateTextureObject`.
+  unsafe fn create(res: &cudaResourceDesc, tex: &cudaTextureDesc) -> CudaResult<CudaTextureObject<'a>> {
+    let mut raw: cudaTextureObject_t = 0;
+    match cudaCreateTextureObject(
+        &mut raw as *mut cudaTextureObject_t,
+        res as *const cudaResourceDesc,
+        tex as *const cudaTextureDesc,
+        null())
+    {
+      cudaError_cudaSuccess => Ok(CudaTextureObject{raw, _resource: PhantomData}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  pub fn as_raw(&self) -> cudaTextureObject_t {
+    self.raw
+  }
+}
+
+/// A texture object is passed as its handle.
+unsafe impl<'a> KernelArg for CudaTextureObject<'a> {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    &self.raw as *const cudaTextureObject_t as *mut c_void
+  }
+}
+
+/// A surface object mutably borrowing the CUDA array it reads and writes.
+#[derive(Debug)]
+pub struct CudaSurfaceObject<'a> {
+  raw:        cudaSurfaceObject_t,
+  _resource:  PhantomData<&'a mut ()>,
+}
+
+impl<'a> Drop for CudaSurfaceObject<'a> {
+  fn drop(&mut self) {
+    check_drop("CudaSurfaceObject", unsafe { cudaDestroySurfaceObject(self.raw) });
+  }
+}
+
+impl<'a> CudaSurfaceObject<'a> {
+  /// Create a surface object over `array`, which must have been allocated
+  /// with `ARRAY_SURFACE_LOAD_STORE`.
+  ///
+  /// Corresponds to `cudaCreateSurfaceObject`.
+  pub fn create<T: ChannelFormat>(array: &'a mut CudaArray<T>) -> CudaResult<CudaSurfaceObject<'a>> {
+    let res = cudaResourceDesc{
+      resType:  cudaResourceType_cudaResourceTypeArray,
+      res:      cudaResourceDescRes{array: cudaResourceDescArray{array: array.as_mut_ptr()}},
+    };
+    let mut raw: cudaSurfaceObject_t = 0;
+    match unsafe { cudaCreateSurfaceObject(&mut raw as *mut cudaSurfaceObject_t, &res as *const cudaResourceDesc) } {
+      cudaError_cudaSuccess => Ok(CudaSurfaceObject{raw, _resource: PhantomData}),
+      e => Err(CudaError::from_raw(e)),
+    }
+  }
+
+  pub fn as_raw(&self) -> cudaSurfaceObject_t {
+    self.raw
+  }
+}
+
+/// A surface object is passed as its handle.
+unsafe impl<'a> KernelArg for CudaSurfaceObject<'a> {
+  fn as_kernel_arg(&self) -> *mut c_void {
+    &self.raw as *const cudaSurfaceObject_t as *mut c_void
+  }
+}
@@ -0,0 +1,211 @@
+//! GPU activity timelines in the Chrome trace format.
+//!
+//! While recording is enabled, every asynchronous operation issued through
+//! `cudart` (copies, memsets, kernel and graph launches, stream callbacks)
+//! is bracketed by a pair of events on its stream. Once the work has
+//! completed, `resolve` turns the event pairs into timed operations, which
+//! `write_chrome_trace` writes as JSON for `chrome://tracing` or Perfetto,
+//! with one process per device and one thread per stream.
+//!
+//! Each device's clock is aligned to the host at the first operation
+//! recorded on it, which briefly synchronizes the device's default stream.
+//! Operations which fail to record (e.g. during stream capture) are left out.
+
+use crate::runtime::{CudaDevice, CudaEvent, CudaResult, CudaStream};
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+use std::sync::{Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Instant};
+
+/// An operation resolved from its event pair. Times are in microseconds
+/// since recording was enabled.
+#[derive(Clone, Debug)]
+pub struct TimelineOp {
+  pub name:     &'static str,
+  pub device:   i32,
+  pub stream:   usize,
+  pub start_us: f64,
+  pub dur_us:   f64,
+  pub bytes:    Option<usize>,
+}
+
+/// Host time of a device clock: the device reached `event` at `host_us`.
+struct Epoch {
+  event:    CudaEvent,
+  host_us:  f64,
+}
+
+struct PendingOp {
+  name:     &'static str,
+  device:   i32,
+  stream:   usize,
+  bytes:    Option<usize>,
+  start:    CudaEvent,
+  end:      CudaEvent,
+}
+
+struct Recorder {
+  origin:   Instant,
+  epochs:   HashMap<i32, Epoch>,
+  pending:  Vec<PendingOp>,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+/// Start recording, discarding any operations recorded before.
+pub fn enable() {
+  let mut recorder = RECORDER.lock().unwrap();
+  *recorder = Some(Recorder{
+    origin:   Instant::now(),
+    epochs:   HashMap::new(),
+    pending:  Vec::new(),
+  });
+  ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Stop recording. Operations recorded so far can still be resolved.
+pub fn disable() {
+  ENABLED.store(false, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+  ENABLED.load(Ordering::Relaxed)
+}
+
+/// The start of an operation being recorded.
+pub(crate) struct Bracket {
+  device:   i32,
+  start:    CudaEvent,
+}
+
+/// Record the start of an operation about to be enqueued on `stream`, if
+/// recording is enabled.
+pub(crate) fn begin(stream: &mut CudaStream) -> Option<Bracket> {
+  if !is_enabled() {
+    return None;
+  }
+  let device = CudaDevice::get_current().ok()?.0;
+  {
+    let mut recorder = RECORDER.lock().unwrap();
+    let recorder = recorder.as_mut()?;
+    if !recorder.epochs.contains_key(&device) {
+      let epoch = sync_epoch(recorder.origin).ok()?;
+      recorder.epochs.insert(device, epoch);
+    }
+  }
+  let mut start = CudaEvent::create().ok()?;
+  start.record(stream).ok()?;
+  Some(Bracket{device, start})
+}
+
+/// Record the end of the operation `name` just enqueued on `stream`.
+pub(crate) fn end(bracket: Option<Bracket>, name: &'static str, bytes: Option<usize>, stream: &mut CudaStream) {
+  let bracket = match bracket {
+    None => return,
+    Some(bracket) => bracket,
+  };
+  let mut end = match CudaEvent::create() {
+    Err(_) => return,
+    Ok(end) => end,
+  };
+  if end.record(stream).is_err() {
+    return;
+  }
+  let mut recorder = RECORDER.lock().unwrap();
+  if let Some(recorder) = recorder.as_mut() {
+    recorder.pending.push(PendingOp{
+      name,
+      device:   bracket.device,
+      stream:   unsafe { stream.as_mut_ptr() as usize },
+      bytes,
+      start:    bracket.start,
+      end,
+    });
+  }
+}
+
+fn sync_epoch(origin: Instant) -> CudaResult<Epoch> {
+  let mut event = CudaEvent::create()?;
+  event.record(&mut CudaStream::default())?;
+  event.synchronize()?;
+  let host_us = origin.elapsed().as_secs_f64() * 1.0e6;
+  Ok(Epoch{event, host_us})
+}
+
+/// Wait for the operations recorded so far and resolve their times. The
+/// resolved operations are removed from the recorder.
+///
+/// Corresponds to `cudaEventElapsedTime`.
+pub fn resolve() -> CudaResult<Vec<TimelineOp>> {
+  let mut recorder = RECORDER.lock().unwrap();
+  let recorder = match recorder.as_mut() {
+    None => return Ok(Vec::new()),
+    Some(recorder) => recorder,
+  };
+  let mut ops = Vec::with_capacity(recorder.pending.len());
+  for mut op in recorder.pending.drain(..) {
+    op.end.synchronize()?;
+    let epoch = &recorder.epochs[&op.device];
+    let (offset_ms, dur_ms) = match (epoch.event.elapsed_ms(&op.start), op.start.elapsed_ms(&op.end)) {
+      (Ok(offset_ms), Ok(dur_ms)) => (offset_ms, dur_ms),
+      _ => continue,
+    };
+    ops.push(TimelineOp{
+      name:     op.name,
+      device:   op.device,
+      stream:   op.stream,
+      start_us: epoch.host_us + offset_ms as f64 * 1.0e3,
+      dur_us:   dur_ms as f64 * 1.0e3,
+      bytes:    op.bytes,
+    });
+  }
+  Ok(ops)
+}
+
+/// Write `ops` as a Chrome trace, with one process per device and one
+/// thread per stream.
+pub fn write_chrome_trace<W: Write>(ops: &[TimelineOp], w: &mut W) -> io::Result<()> {
+  let mut tracks: BTreeMap<i32, Vec<usize>> = BTreeMap::new();
+  for op in ops.iter() {
+    let streams = tracks.entry(op.device).or_insert_with(Vec::new);
+    if !streams.contains(&op.stream) {
+      streams.push(op.stream);
+    }
+  }
+  let tid = |op: &TimelineOp| tracks[&op.device].iter().position(|&s| s == op.stream).unwrap();
+  write!(w, "{{\"displayTimeUnit\":\"ns\",\"traceEvents\":[")?;
+  let mut first = true;
+  for (&device, streams) in tracks.iter() {
+    sep(w, &mut first)?;
+    write!(w, "{{\"name\":\"process_name\",\"ph\":\"M\",\"pid\":{},\"args\":{{\"name\":\"GPU {}\"}}}}", device, device)?;
+    for (tid, &stream) in streams.iter().enumerate() {
+      sep(w, &mut first)?;
+      if stream == 0 {
+        write!(w, "{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":{},\"tid\":{},\"args\":{{\"name\":\"default stream\"}}}}", device, tid)?;
+      } else {
+        write!(w, "{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":{},\"tid\":{},\"args\":{{\"name\":\"stream 0x{:x}\"}}}}", device, tid, stream)?;
+      }
+    }
+  }
+  for op in ops.iter() {
+    sep(w, &mut first)?;
+    write!(w, "{{\"name\":\"{}\",\"ph\":\"X\",\"pid\":{},\"tid\":{},\"ts\":{:.3},\"dur\":{:.3}",
+        op.name, op.device, tid(op), op.start_us, op.dur_us)?;
+    match op.bytes {
+      None => write!(w, "}}")?,
+      Some(bytes) => write!(w, ",\"args\":{{\"bytes\":{}}}}}", bytes)?,
+    }
+  }
+  write!(w, "]}}")
+}
+
+fn sep<W: Write>(w: &mut W, first: &mut bool) -> io::Result<()> {
+  if !*first {
+    write!(w, ",")?;
+  }
+  *first = false;
+  Ok(())
+}
@@ -0,0 +1,62 @@
+//! `tracing` instrumentation of runtime calls (with the `tracing` feature).
+//!
+//! Each instrumented call runs inside a DEBUG span named after the runtime
+//! function, carrying the call's fields and, once it returns, the name of
+//! the returned error code in `error`.
+
+#[cfg(all(feature = "tracing", not(feature = "cuda_sys")))]
+use crate::ffi::*;
+
+#[cfg(all(feature = "tracing", not(feature = "cuda_sys")))]
+use cuda_api_types::driver_types::*;
+#[cfg(all(feature = "tracing", feature = "cuda_sys"))]
+use cuda_sys::cudart::*;
+
+#[cfg(feature = "tracing")]
+use std::ffi::{CStr};
+
+/// Evaluate the runtime call `$call`, instrumented as `$name` with the
+/// span fields in braces (in `tracing` field syntax). Without the `tracing`
+/// feature the fields are not evaluated.
+macro_rules! traced {
+  ($name:expr, { $($fields:tt)+ }, $call:expr) => {{
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!($name, $($fields)+, error = tracing::field::Empty);
+    #[cfg(feature = "tracing")]
+    let _enter = span.enter();
+    let ret = $call;
+    #[cfg(feature = "tracing")]
+    crate::trace::record_error(&span, ret);
+    ret
+  }};
+  ($name:expr, $call:expr) => {{
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!($name, error = tracing::field::Empty);
+    #[cfg(feature = "tracing")]
+    let _enter = span.enter();
+    let ret = $call;
+    #[cfg(feature = "tracing")]
+    crate::trace::record_error(&span, ret);
+    ret
+  }};
+}
+
+pub(crate) use traced;
+
+#[cfg(feature = "tracing")]
+pub(crate) fn record_error(span: &tracing::Span, e: cudaError_t) {
+  if !span.is_disabled() {
+    span.record("error", error_name(e));
+  }
+}
+
+/// Corresponds to `cudaGetErrorName`.
+#[cfg(feature = "tracing")]
+fn error_name(e: cudaError_t) -> &'static str {
+  let raw_s = unsafe { cudaGetErrorName(e) };
+  if raw_s.is_null() {
+    return "(null)";
+  }
+  let cs: &'static CStr = unsafe { CStr::from_ptr(raw_s) };
+  cs.to_str().unwrap_or("(invalid utf8)")
+}
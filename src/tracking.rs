@@ -0,0 +1,215 @@
+//! Allocation tracking and leak reports (`alloc_tracking` feature).
+//!
+//! While tracking is enabled, frees of pointers which are not live
+//! allocations are rejected instead of being passed on to the runtime.
+//! Double frees are reported as such for the last `MAX_FREED` freed
+//! addresses, and as unknown pointers beyond that.
+
+use crate::runtime::{CudaDevice, CudaError, CudaResult};
+
+use backtrace::{Backtrace};
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::os::raw::{c_int};
+use std::sync::{Mutex, Once};
+use std::time::{SystemTime};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AllocKind {
+  Device,
+  Host,
+}
+
+#[derive(Clone, Debug)]
+pub struct AllocRecord {
+  pub ptr:          usize,
+  pub size:         usize,
+  pub kind:         AllocKind,
+  pub device:       i32,
+  pub timestamp:    SystemTime,
+  backtrace:        Backtrace,
+}
+
+impl AllocRecord {
+  /// The first resolved frame outside of `cudart`, formatted as a symbol
+  /// name followed by its source location when known.
+  pub fn call_site(&self) -> String {
+    let mut backtrace = self.backtrace.clone();
+    backtrace.resolve();
+    for frame in backtrace.frames() {
+      for symbol in frame.symbols() {
+        let name = match symbol.name() {
+          None => continue,
+          Some(name) => format!("{:#}", name),
+        };
+        if name.starts_with("backtrace::") ||
+           name.starts_with("cudart::") ||
+           name.starts_with("<cudart::")
+        {
+          continue;
+        }
+        return match (symbol.filename(), symbol.lineno()) {
+          (Some(file), Some(line)) => format!("{} ({}:{})", name, file.display(), line),
+          _ => name,
+        };
+      }
+    }
+    "(unknown)".to_owned()
+  }
+}
+
+/// The number of freed addresses remembered for double free detection.
+pub const MAX_FREED: usize = 1 << 16;
+
+#[derive(Default)]
+struct Tracker {
+  live:         HashMap<usize, AllocRecord>,
+  /// Recently freed addresses, each with the sequence number of its entry
+  /// in `freed_order`.
+  freed:        HashMap<usize, u64>,
+  freed_order:  VecDeque<(usize, u64)>,
+  freed_seq:    u64,
+}
+
+impl Tracker {
+  fn insert_freed(&mut self, ptr: usize) {
+    self.freed_seq += 1;
+    self.freed.insert(ptr, self.freed_seq);
+    self.freed_order.push_back((ptr, self.freed_seq));
+    while self.freed_order.len() > MAX_FREED {
+      let (old_ptr, old_seq) = self.freed_order.pop_front().unwrap();
+      // The address may have been freed again since.
+      if self.freed.get(&old_ptr) == Some(&old_seq) {
+        self.freed.remove(&old_ptr);
+      }
+    }
+  }
+}
+
+static TRACKER: Mutex<Option<Tracker>> = Mutex::new(None);
+
+fn with_tracker<T, F: FnOnce(&mut Tracker) -> T>(f: F) -> T {
+  let mut tracker = TRACKER.lock().unwrap();
+  f(tracker.get_or_insert_with(Tracker::default))
+}
+
+pub(crate) fn record_alloc(ptr: *mut u8, size: usize, kind: AllocKind) {
+  if ptr.is_null() {
+    return;
+  }
+  let device = CudaDevice::get_current().map(|dev| dev.0).unwrap_or(-1);
+  let record = AllocRecord{
+    ptr:        ptr as usize,
+    size,
+    kind,
+    device,
+    timestamp:  SystemTime::now(),
+    backtrace:  Backtrace::new_unresolved(),
+  };
+  with_tracker(|tracker| {
+    tracker.freed.remove(&record.ptr);
+    tracker.live.insert(record.ptr, record);
+  });
+}
+
+/// Forget a live allocation which is about to be freed. If the free then
+/// fails, the returned record should be handed back to `restore`.
+pub(crate) fn remove(ptr: *mut u8, kind: AllocKind) -> CudaResult<AllocRecord> {
+  let ptr = ptr as usize;
+  with_tracker(|tracker| {
+    match tracker.live.get(&ptr) {
+      Some(record) if record.kind == kind => {}
+      Some(_) => return Err(CudaError::UnknownPointer{ptr}),
+      None if tracker.freed.contains_key(&ptr) => return Err(CudaError::DoubleFree{ptr}),
+      None => return Err(CudaError::UnknownPointer{ptr}),
+    }
+    tracker.insert_freed(ptr);
+    Ok(tracker.live.remove(&ptr).unwrap())
+  })
+}
+
+pub(crate) fn restore(record: AllocRecord) {
+  with_tracker(|tracker| {
+    tracker.freed.remove(&record.ptr);
+    tracker.live.insert(record.ptr, record);
+  });
+}
+
+/// All live allocations, oldest first.
+pub fn live_allocations() -> Vec<AllocRecord> {
+  let mut records: Vec<_> = with_tracker(|tracker| tracker.live.values().cloned().collect());
+  records.sort_by_key(|record| record.timestamp);
+  records
+}
+
+#[derive(Clone, Debug)]
+pub struct LeakSite {
+  pub call_site:    String,
+  pub kind:         AllocKind,
+  pub count:        usize,
+  pub bytes:        usize,
+}
+
+/// Live allocations grouped by call site, largest first.
+#[derive(Clone, Debug)]
+pub struct LeakReport {
+  pub sites:    Vec<LeakSite>,
+}
+
+impl LeakReport {
+  pub fn is_empty(&self) -> bool {
+    self.sites.is_empty()
+  }
+}
+
+impl fmt::Display for LeakReport {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let count: usize = self.sites.iter().map(|site| site.count).sum();
+    let bytes: usize = self.sites.iter().map(|site| site.bytes).sum();
+    writeln!(f, "cudart: {} leaked allocations ({} bytes)", count, bytes)?;
+    for site in self.sites.iter() {
+      writeln!(f, "  {} {:?} allocations ({} bytes) at {}",
+          site.count, site.kind, site.bytes, site.call_site)?;
+    }
+    Ok(())
+  }
+}
+
+pub fn leak_report() -> LeakReport {
+  let mut groups: BTreeMap<(String, bool), LeakSite> = BTreeMap::new();
+  for record in live_allocations() {
+    let call_site = record.call_site();
+    let site = groups.entry((call_site.clone(), record.kind == AllocKind::Host))
+      .or_insert_with(|| LeakSite{call_site, kind: record.kind, count: 0, bytes: 0});
+    site.count += 1;
+    site.bytes += record.size;
+  }
+  let mut sites: Vec<_> = groups.into_iter().map(|(_, site)| site).collect();
+  sites.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+  LeakReport{sites}
+}
+
+/// Print a leak report to stderr, if there are any live allocations.
+pub fn print_leak_report() {
+  let report = leak_report();
+  if !report.is_empty() {
+    eprint!("{}", report);
+  }
+}
+
+extern "C" {
+  fn atexit(callback: extern "C" fn ()) -> c_int;
+}
+
+extern "C" fn print_leak_report_at_exit_callback() {
+  print_leak_report();
+}
+
+/// Print a leak report to stderr when the process exits normally.
+pub fn print_leak_report_at_exit() {
+  static REGISTER: Once = Once::new();
+  REGISTER.call_once(|| {
+    unsafe { atexit(print_leak_report_at_exit_callback) };
+  });
+}
@@ -0,0 +1,37 @@
+extern crate cudart;
+
+use cudart::*;
+use cudart::array::{f16, f16x2, u8x4};
+
+use std::mem::{size_of};
+
+#[test]
+fn test_channel_format_desc() {
+  assert_eq!(u8x4::DESC, ChannelFormatDesc::new(8, 8, 8, 8, ChannelFormatKind::Unsigned));
+  assert_eq!(f16x2::DESC, ChannelFormatDesc::new(16, 16, 0, 0, ChannelFormatKind::Float));
+  assert_eq!(i16::DESC, ChannelFormatDesc::new(16, 0, 0, 0, ChannelFormatKind::Signed));
+  assert_eq!(u8x4::DESC.element_bytes(), size_of::<u8x4>());
+  assert_eq!(f16::DESC.element_bytes(), size_of::<f16>());
+  assert_eq!(f32::DESC, ChannelFormatDesc::create(32, 0, 0, 0, ChannelFormatKind::Float));
+}
+
+#[test]
+fn test_array_2d_roundtrip() {
+  let src: Vec<u8x4> = (0 .. 12).map(|i| u8x4{x: i, y: i + 1, z: i + 2, w: 255}).collect();
+  let mut array = unsafe { CudaArray::<u8x4>::alloc(4, 3) }.unwrap();
+  assert_eq!(array.len(), 12);
+  array.copy_from_host(&src).unwrap();
+  let mut dst = vec![u8x4::default(); 12];
+  array.copy_to_host(&mut dst).unwrap();
+  assert_eq!(src, dst);
+
+  // Overwrite the 2x2 block at (1, 1) from a host buffer with a pitch of 3
+  // elements, then read the middle row back.
+  let block = [-1.0_f32; 6];
+  let mut array = unsafe { CudaArray::<f32>::alloc(4, 3) }.unwrap();
+  array.copy_from_host(&[0.0; 12]).unwrap();
+  unsafe { cuda_memcpy_2d_to_array(&mut array, 1, 1, block.as_ptr(), 3 * size_of::<f32>(), 2, 2, CudaMemcpyKind::HostToDevice) }.unwrap();
+  let mut row = [0.0_f32; 4];
+  unsafe { cuda_memcpy_2d_from_array(row.as_mut_ptr(), 4 * size_of::<f32>(), &array, 0, 1, 4, 1, CudaMemcpyKind::DeviceToHost) }.unwrap();
+  assert_eq!(row, [0.0, -1.0, -1.0, 0.0]);
+}
@@ -0,0 +1,92 @@
+#[allow(dead_code)]
+#[path = "../build/cuda_search.rs"]
+mod cuda_search;
+mod common;
+
+use crate::cuda_search::*;
+use crate::common::{fake_toolkit};
+
+use std::fs;
+use std::path::{PathBuf};
+
+#[test]
+fn test_search_lib64() {
+  let root = fake_toolkit("lib64", &[("lib64/libcudart.so", ""), ("lib64/stubs/libcuda.so", ""), ("include/cuda_runtime_api.h", "")]);
+  let search = Search{
+    roots:  vec![PathBuf::from("/nonexistent/cuda"), root.clone()],
+    target: "x86_64-unknown-linux-gnu".to_owned(),
+    .. Search::default()
+  };
+  let lib_dir = find(&search.lib_dirs(), &["libcudart.so"]).unwrap();
+  assert_eq!(lib_dir, root.join("lib64"));
+  assert_eq!(stubs_dir(&lib_dir), Some(root.join("lib64").join("stubs")));
+  assert_eq!(find(&search.include_dirs(), &["cuda_runtime_api.h"]).unwrap(), root.join("include"));
+  fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn test_search_targets_cross() {
+  // A Jetson style layout, with host libraries that must not be used when
+  // cross compiling.
+  let root = fake_toolkit("targets", &[
+    ("lib64/libcudart.so", ""),
+    ("targets/aarch64-linux/lib/libcudart.so", ""),
+    ("targets/aarch64-linux/include/cuda_runtime_api.h", ""),
+  ]);
+  let mut search = Search{
+    roots:  vec![root.clone()],
+    target: "aarch64-unknown-linux-gnu".to_owned(),
+    cross:  true,
+    .. Search::default()
+  };
+  assert_eq!(find(&search.lib_dirs(), &["libcudart.so"]).unwrap(), root.join("targets/aarch64-linux/lib"));
+  assert_eq!(find(&search.include_dirs(), &["cuda_runtime_api.h"]).unwrap(), root.join("targets/aarch64-linux/include"));
+  assert!(!search.lib_dirs().contains(&root.join("lib64")));
+
+  search.target = "x86_64-unknown-linux-gnu".to_owned();
+  search.cross = false;
+  assert_eq!(find(&search.lib_dirs(), &["libcudart.so"]).unwrap(), root.join("lib64"));
+  fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn test_search_distro_and_pkg_config() {
+  let root = fake_toolkit("distro", &[("lib/x86_64-linux-gnu/libcudart.so", ""), ("pc/lib/libcudart.so", "")]);
+  let mut search = Search{
+    roots:  vec![root.clone()],
+    target: "x86_64-unknown-linux-gnu".to_owned(),
+    .. Search::default()
+  };
+  assert_eq!(find(&search.lib_dirs(), &["libcudart.so"]).unwrap(), root.join("lib/x86_64-linux-gnu"));
+  search.pkg_lib_dirs = vec![root.join("pc/lib")];
+  assert_eq!(find(&search.lib_dirs(), &["libcudart.so"]).unwrap(), root.join("pc/lib"));
+  fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn test_search_overrides() {
+  let root = fake_toolkit("overrides", &[("lib64/libcudart.so", ""), ("include/cuda_runtime_api.h", "")]);
+  let search = Search{
+    lib_dir:      Some(PathBuf::from("/nonexistent/lib")),
+    include_dir:  Some(root.join("include")),
+    roots:        vec![root.clone()],
+    target:       "x86_64-unknown-linux-gnu".to_owned(),
+    .. Search::default()
+  };
+  assert_eq!(search.lib_dirs(), [PathBuf::from("/nonexistent/lib")]);
+  let tried = find(&search.lib_dirs(), &["libcudart.so", "cudart.lib"]).unwrap_err();
+  assert_eq!(tried.len(), 2);
+  let msg = not_found("libcudart", &tried);
+  assert!(msg.contains("/nonexistent/lib/libcudart.so\n"));
+  assert!(msg.contains("/nonexistent/lib/cudart.lib\n"));
+  assert_eq!(search.include_dirs(), [root.join("include")]);
+  fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn test_target_dirs() {
+  assert_eq!(target_dirs("aarch64-unknown-linux-gnu"), ["aarch64-linux", "sbsa-linux"]);
+  assert_eq!(target_dirs("powerpc64le-unknown-linux-gnu"), ["ppc64le-linux"]);
+  assert!(target_dirs("x86_64-pc-windows-msvc").is_empty());
+  assert_eq!(multiarch_dir("x86_64-unknown-linux-gnu"), Some("x86_64-linux-gnu"));
+}
@@ -0,0 +1,108 @@
+#[allow(dead_code)]
+#[path = "../build/cuda_version.rs"]
+mod cuda_version;
+mod common;
+
+use crate::cuda_version::*;
+use crate::common::{fake_toolkit};
+
+use std::fs;
+
+#[test]
+fn test_detect_runtime_header() {
+  let dir = fake_toolkit("header", &[
+    ("include/cuda_runtime_api.h", "#ifndef __CUDA_RUNTIME_API_H__\n#define CUDART_VERSION 9020\n"),
+    ("include/cuda.h", "#define CUDA_VERSION 10000\n"),
+  ]);
+  let d = detect(&[dir.join("include")], &[dir.clone()]).unwrap();
+  assert_eq!(d.version, CudaVersion::new(9, 2));
+  assert!(d.source.ends_with("include/cuda_runtime_api.h"));
+  fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_detect_driver_header() {
+  let dir = fake_toolkit("driver", &[("include/cuda.h", "#define CUDA_VERSION 8000\n")]);
+  assert_eq!(detect(&[dir.join("include")], &[dir.clone()]).unwrap().version, CudaVersion::new(8, 0));
+  fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_detect_version_files() {
+  let dir = fake_toolkit("txt", &[("version.txt", "CUDA Version 10.0.130\n")]);
+  assert_eq!(detect(&[dir.join("include")], &[dir.clone()]).unwrap().version, CudaVersion::new(10, 0));
+  fs::remove_dir_all(&dir).ok();
+
+  let dir = fake_toolkit("json", &[
+    ("version.json", "{\n  \"cuda\" : {\n    \"name\" : \"CUDA SDK\",\n    \"version\" : \"11.2.0\"\n  }\n}\n"),
+  ]);
+  let detected = detect(&[dir.join("include")], &[dir.clone()]);
+  assert_eq!(detected.as_ref().unwrap().version, CudaVersion::new(11, 2));
+  let selection = select(&[], &detected, false, SUPPORTED).unwrap();
+  assert_eq!(selection.version, CudaVersion::new(11, 2));
+  assert!(selection.warnings.is_empty());
+  fs::remove_dir_all(&dir).ok();
+
+  let dir = fake_toolkit("json_newer", &[
+    ("version.json", "{\n  \"cuda\" : {\n    \"version\" : \"13.0.1\"\n  }\n}\n"),
+  ]);
+  let detected = detect(&[dir.join("include")], &[dir.clone()]);
+  let selection = select(&[], &detected, false, SUPPORTED).unwrap();
+  assert_eq!(selection.version, CudaVersion::new(12, 6));
+  assert_eq!(selection.warnings.len(), 1);
+  fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_select_mismatch() {
+  let dir = fake_toolkit("mismatch", &[("include/cuda.h", "#define CUDA_VERSION 9000\n")]);
+  let detected = detect(&[dir.join("include")], &[dir.clone()]);
+  let selection = select(&[CudaVersion::new(10, 0)], &detected, false, SUPPORTED).unwrap();
+  assert_eq!(selection.version, CudaVersion::new(10, 0));
+  assert!(selection.warnings[0].contains("cuda_10_0"));
+  let err = select(&[CudaVersion::new(10, 0)], &detected, true, SUPPORTED).unwrap_err();
+  assert!(err.contains("CUDA 9.0"));
+  assert!(select(&[CudaVersion::new(9, 0)], &detected, true, SUPPORTED).unwrap().warnings.is_empty());
+  fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_select_errors() {
+  let dir = fake_toolkit("missing", &[]);
+  let detected = detect(&[dir.join("include")], &[dir.clone()]);
+  let err = select(&[], &detected, false, SUPPORTED).unwrap_err();
+  assert!(err.contains("version.txt"));
+  // An explicit feature does not need a toolkit.
+  assert_eq!(select(&[CudaVersion::new(8, 0)], &detected, true, SUPPORTED).unwrap().version, CudaVersion::new(8, 0));
+  let err = select(&[CudaVersion::new(8, 0), CudaVersion::new(9, 0)], &detected, false, SUPPORTED).unwrap_err();
+  assert!(err.contains("cuda_8_0, cuda_9_0"));
+}
+
+#[test]
+fn test_select_unavailable() {
+  let dir = fake_toolkit("unavailable", &[("include/cuda.h", "#define CUDA_VERSION 9010\n")]);
+  let detected = detect(&[dir.join("include")], &[dir.clone()]);
+  let available = [CudaVersion::new(8, 0), CudaVersion::new(9, 0), CudaVersion::new(9, 2)];
+  // A detected toolkit falls back to the newest version with bindings.
+  let selection = select(&[], &detected, false, &available).unwrap();
+  assert_eq!(selection.version, CudaVersion::new(9, 0));
+  assert!(selection.warnings[0].contains("cuda_9_1"));
+  assert_eq!(select(&[], &detected, false, SUPPORTED).unwrap().version, CudaVersion::new(9, 1));
+  // An explicit feature does not.
+  let err = select(&[CudaVersion::new(9, 1)], &detected, false, &available).unwrap_err();
+  assert!(err.contains("fresh"));
+  fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_cfgs() {
+  let v = CudaVersion::new(9, 2);
+  assert_eq!(v.ffi_dir(), "v9_2");
+  assert_eq!(v.cfgs(), [
+    "cuda_9_2", "cuda_gte_7_0", "cuda_gte_7_5", "cuda_gte_8_0", "cuda_gte_9_0", "cuda_gte_9_1", "cuda_gte_9_2",
+  ]);
+  assert_eq!(CudaVersion::new(6, 5).cfgs(), ["cuda_6_5"]);
+  let cfgs = CudaVersion::new(12, 0).cfgs();
+  assert!(cfgs.contains(&"cuda_gte_11_8".to_string()));
+  assert!(!cfgs.contains(&"cuda_gte_12_1".to_string()));
+}
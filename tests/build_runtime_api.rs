@@ -0,0 +1,112 @@
+#[allow(dead_code)]
+#[path = "../build/cuda_version.rs"]
+mod cuda_version;
+#[allow(dead_code)]
+#[path = "../build/runtime_api.rs"]
+mod runtime_api;
+
+use crate::cuda_version::*;
+use crate::runtime_api::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{PathBuf};
+
+fn manifest_dir() -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn manifest() -> Vec<Function> {
+  let src = fs::read_to_string(manifest_dir().join("build").join("runtime_api.txt")).unwrap();
+  parse(&src).unwrap()
+}
+
+/// The functions declared in the checked-in bindings under `src/ffi/<dir>`.
+fn bound_functions(dir: &str) -> BTreeSet<String> {
+  let path = manifest_dir().join("src").join("ffi").join(dir).join("_cuda_runtime_api.rs");
+  let src = fs::read_to_string(&path).unwrap();
+  let mut names = BTreeSet::new();
+  // Older bindings are not rustfmt'd, so `pub fn` may be anywhere in a line,
+  // but never inside a `doc` string.
+  for line in src.lines() {
+    let mut rest = line;
+    while let Some(pos) = rest.find("pub fn ") {
+      let before = &rest[.. pos];
+      rest = &rest[pos + "pub fn ".len() ..];
+      if before.matches('"').count() % 2 == 1 {
+        continue;
+      }
+      let name: String = rest.trim_start().chars().take_while(|&c| c.is_ascii_alphanumeric() || c == '_').collect();
+      names.insert(name);
+    }
+  }
+  names
+}
+
+#[test]
+fn test_checked_in_bindings_match_manifest() {
+  let fns = manifest();
+  let mut dirs: BTreeMap<String, Vec<CudaVersion>> = BTreeMap::new();
+  for &v in SUPPORTED.iter().filter(|v| v.has_checked_in_bindings(&manifest_dir())) {
+    dirs.entry(v.ffi_dir()).or_insert_with(Vec::new).push(v);
+  }
+  for (dir, versions) in dirs.iter() {
+    let bound = bound_functions(dir);
+    for &v in versions.iter() {
+      let expected: BTreeSet<String> = functions_for(&fns, v).into_iter().map(|s| s.to_string()).collect();
+      let missing: Vec<&String> = expected.difference(&bound).collect();
+      let unlisted: Vec<&String> = bound.difference(&expected).collect();
+      assert!(missing.is_empty() && unlisted.is_empty(),
+          "src/ffi/{} for CUDA {}: missing {:?}, not in the manifest {:?}", dir, v, missing, unlisted);
+    }
+  }
+}
+
+/// The features enabled by `feature` in `Cargo.toml`.
+fn cargo_feature(feature: &str) -> Vec<String> {
+  let src = fs::read_to_string(manifest_dir().join("Cargo.toml")).unwrap();
+  let line = src.lines()
+    .find(|line| line.split('=').next().unwrap().trim() == feature)
+    .unwrap_or_else(|| panic!("Cargo.toml has no `{}` feature", feature));
+  let list = line[line.find('[').unwrap() + 1 .. line.rfind(']').unwrap()].trim();
+  list.split(',').filter(|f| !f.trim().is_empty()).map(|f| f.trim().trim_matches('"').to_string()).collect()
+}
+
+#[test]
+fn test_generated_versions_enable_fresh() {
+  for v in SUPPORTED.iter().filter(|v| !v.has_checked_in_bindings(&manifest_dir())) {
+    assert!(cargo_feature(&v.feature()).iter().any(|f| f == "fresh"),
+        "CUDA {} has no bindings under src/ffi, so `{}` must enable `fresh`", v, v.feature());
+  }
+}
+
+#[test]
+fn test_manifest_versions_are_supported() {
+  for f in manifest().iter() {
+    for v in f.since.iter().chain(f.until.iter()) {
+      assert!(SUPPORTED.contains(v), "{}: CUDA {} is not a supported version", f.name, v);
+    }
+  }
+}
+
+#[test]
+fn test_parse() {
+  let fns = parse("# comment\n[Graph management]\ncudaGraphCreate since 10.0  # trailing\ncudaFoo until 12.0\n").unwrap();
+  assert_eq!(fns.len(), 2);
+  assert_eq!(fns[0].category, "Graph management");
+  assert_eq!(fns[0].since, Some(CudaVersion::new(10, 0)));
+  assert_eq!(fns[1].until, Some(CudaVersion::new(12, 0)));
+  assert_eq!(functions_for(&fns, CudaVersion::new(9, 2)), ["cudaFoo"]);
+  assert_eq!(functions_for(&fns, CudaVersion::new(11, 8)), ["cudaGraphCreate", "cudaFoo"]);
+  assert_eq!(functions_for(&fns, CudaVersion::new(12, 0)), ["cudaGraphCreate"]);
+}
+
+#[test]
+fn test_parse_errors() {
+  assert!(parse("cudaMalloc\n").unwrap_err().contains(":1:"));
+  assert!(parse("[Memory]\ncudaMalloc\ncudaMalloc\n").unwrap_err().contains("twice"));
+  assert!(parse("[Memory]\ncudaMalloc since\n").unwrap_err().contains("expected a version"));
+  assert!(parse("[Memory]\ncudaMalloc from 8.0\n").unwrap_err().contains("unknown key"));
+  assert!(parse("[Memory]\ncudaMalloc since 9.0 until 8.0\n").unwrap_err().contains(":2:"));
+  assert!(parse("[Memory\ncudaMalloc\n").is_err());
+}
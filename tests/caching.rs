@@ -0,0 +1,171 @@
+extern crate cudart;
+
+use cudart::*;
+use cudart::caching::{CachingBackend, size_class};
+
+use std::cell::{Cell};
+use std::rc::{Rc};
+
+struct FakeStream(usize);
+
+struct FakeEvent(Rc<Cell<bool>>);
+
+#[derive(Default)]
+struct FakeBackend {
+  device:     i32,
+  allocs:     usize,
+  frees:      usize,
+  fail:       bool,
+  bad_query:  bool,
+  pending:    Vec<Rc<Cell<bool>>>,
+  blocks:     Vec<Vec<u8>>,
+}
+
+impl FakeBackend {
+  fn complete_all(&mut self) {
+    for flag in self.pending.drain( .. ) {
+      flag.set(true);
+    }
+  }
+}
+
+impl CachingBackend for FakeBackend {
+  type Stream = FakeStream;
+  type Event = FakeEvent;
+
+  fn current_device(&mut self) -> CudaResult<i32> {
+    Ok(self.device)
+  }
+
+  fn stream_key(&mut self, stream: &mut FakeStream) -> usize {
+    stream.0
+  }
+
+  fn alloc(&mut self, _device: i32, size: usize) -> CudaResult<*mut u8> {
+    if self.fail && self.allocs > self.frees {
+      return Err(CudaError::SizeOverflow);
+    }
+    self.allocs += 1;
+    self.blocks.push(vec![0; size]);
+    Ok(self.blocks.last_mut().unwrap().as_mut_ptr())
+  }
+
+  unsafe fn free(&mut self, _device: i32, _ptr: *mut u8) -> CudaResult<()> {
+    self.frees += 1;
+    Ok(())
+  }
+
+  fn record(&mut self, _stream: &mut FakeStream) -> CudaResult<FakeEvent> {
+    let flag = Rc::new(Cell::new(false));
+    self.pending.push(flag.clone());
+    Ok(FakeEvent(flag))
+  }
+
+  fn query(&mut self, event: &mut FakeEvent) -> CudaResult<bool> {
+    if self.bad_query {
+      return Err(CudaError::RuntimeUnavailable);
+    }
+    Ok(event.0.get())
+  }
+
+  fn synchronize(&mut self, event: &mut FakeEvent) -> CudaResult<()> {
+    event.0.set(true);
+    Ok(())
+  }
+}
+
+#[test]
+fn test_caching_size_class() {
+  assert_eq!(size_class(0).unwrap(), 512);
+  assert_eq!(size_class(513).unwrap(), 1024);
+  assert_eq!(size_class(1 << 20).unwrap(), 1 << 20);
+  assert_eq!(size_class((1 << 20) + 1).unwrap(), 2 << 20);
+  assert_eq!(size_class((2 << 20) + 1).unwrap(), 4 << 20);
+  assert!(size_class(usize::max_value()).is_err());
+}
+
+#[test]
+fn test_caching_same_stream_reuse() {
+  let mut alloc = CachingAllocator::with_backend(FakeBackend::default());
+  let mut s0 = FakeStream(0);
+  let p = alloc.alloc(1000, &mut s0).unwrap();
+  unsafe { alloc.free(p, &mut s0) }.unwrap();
+  let q = alloc.alloc(700, &mut s0).unwrap();
+  assert_eq!(p, q);
+  let stats = alloc.stats(0);
+  assert_eq!(stats.reserved_bytes, 1024);
+  assert_eq!(stats.allocated_bytes, 1024);
+  assert_eq!(stats.requested_bytes, 700);
+  assert_eq!(stats.num_device_allocs, 1);
+  assert_eq!(stats.num_cache_hits, 1);
+}
+
+#[test]
+fn test_caching_cross_stream_waits_for_event() {
+  let mut alloc = CachingAllocator::with_backend(FakeBackend::default());
+  let mut s0 = FakeStream(0);
+  let mut s1 = FakeStream(1);
+  let p = alloc.alloc(1000, &mut s0).unwrap();
+  unsafe { alloc.free(p, &mut s0) }.unwrap();
+  let q = alloc.alloc(1000, &mut s1).unwrap();
+  assert!(p != q);
+  unsafe { alloc.free(q, &mut s1) }.unwrap();
+  alloc.backend().complete_all();
+  let mut s2 = FakeStream(2);
+  let r = alloc.alloc(1000, &mut s2).unwrap();
+  assert!(r == p || r == q);
+  assert_eq!(alloc.stats(0).num_device_allocs, 2);
+}
+
+#[test]
+fn test_caching_stats_and_empty_cache() {
+  let mut alloc = CachingAllocator::with_backend(FakeBackend::default());
+  let mut s0 = FakeStream(0);
+  let p = alloc.alloc(3 << 20, &mut s0).unwrap();
+  let q = alloc.alloc(256, &mut s0).unwrap();
+  let stats = alloc.stats(0);
+  assert_eq!(stats.reserved_bytes, (4 << 20) + 512);
+  assert_eq!(stats.peak_allocated_bytes, (4 << 20) + 512);
+  assert!(stats.fragmentation() > 0.0);
+  unsafe { alloc.free(p, &mut s0) }.unwrap();
+  let stats = alloc.stats(0);
+  assert_eq!(stats.allocated_bytes, 512);
+  assert_eq!(stats.cached_bytes(), 4 << 20);
+  alloc.empty_cache().unwrap();
+  let stats = alloc.stats(0);
+  assert_eq!(stats.reserved_bytes, 512);
+  assert_eq!(stats.peak_reserved_bytes, (4 << 20) + 512);
+  assert_eq!(stats.num_device_frees, 1);
+  unsafe { alloc.free(q, &mut s0) }.unwrap();
+  match unsafe { alloc.free(q, &mut s0) } {
+    Err(CudaError::UnknownPointer{..}) => {}
+    e => panic!("expected unknown pointer: {:?}", e),
+  }
+}
+
+#[test]
+fn test_caching_retry_after_releasing_cache() {
+  let mut alloc = CachingAllocator::with_backend(FakeBackend::default());
+  let mut s0 = FakeStream(0);
+  let mut s1 = FakeStream(1);
+  let p = alloc.alloc(4096, &mut s0).unwrap();
+  unsafe { alloc.free(p, &mut s0) }.unwrap();
+  alloc.backend().complete_all();
+  alloc.backend().fail = true;
+  alloc.alloc(8192, &mut s1).unwrap();
+  assert_eq!(alloc.stats(0).num_device_frees, 1);
+}
+
+#[test]
+fn test_caching_alloc_error_survives_release_error() {
+  let mut alloc = CachingAllocator::with_backend(FakeBackend::default());
+  let mut s0 = FakeStream(0);
+  let p = alloc.alloc(4096, &mut s0).unwrap();
+  unsafe { alloc.free(p, &mut s0) }.unwrap();
+  alloc.backend().fail = true;
+  alloc.backend().bad_query = true;
+  match alloc.alloc(8192, &mut s0) {
+    Err(CudaError::SizeOverflow) => {}
+    res => panic!("expected the alloc error, got {:?}", res),
+  }
+}
@@ -0,0 +1,18 @@
+//! Fixtures shared by the `build_*` tests.
+
+use std::env;
+use std::fs;
+use std::path::{PathBuf};
+
+/// A fake toolkit tree under the temp dir, holding the given files and
+/// their contents.
+pub fn fake_toolkit(name: &str, files: &[(&str, &str)]) -> PathBuf {
+  let dir = env::temp_dir().join(format!("cudart-test-{}-{}", name, std::process::id()));
+  fs::remove_dir_all(&dir).ok();
+  for &(path, contents) in files {
+    let path = dir.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+  }
+  dir
+}
@@ -11,3 +11,12 @@ fn device_count() {
     Ok(ct) => assert!(ct >= 1),
   }
 }
+
+/// The declared `cudaDeviceProp` has the size it has in the CUDA 11 and 12
+/// headers.
+#[cfg(cuda_gte_11_0)]
+#[test]
+fn device_prop_size() {
+  let expected = if cfg!(cuda_gte_12_0) { 1032 } else { 728 };
+  assert_eq!(std::mem::size_of::<cudart::ffi::cudaDeviceProp>(), expected);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "dynamic_loading")]
+
+extern crate cudart;
+
+use cudart::*;
+use cudart::ffi::dynamic;
+
+use std::env;
+use std::process::{Command};
+
+#[test]
+fn test_dynamic_loaded() {
+  if env::var_os("CUDART_TEST_UNAVAILABLE").is_some() {
+    return;
+  }
+  match CudaDevice::count() {
+    Err(CudaError::RuntimeUnavailable) => panic!("runtime unavailable: {:?}", dynamic::load_error()),
+    _ => {}
+  }
+  assert!(dynamic::is_available());
+  println!("DEBUG: loaded {:?}", dynamic::library_path());
+  assert!(dynamic::library_path().unwrap().contains("libcudart.so"));
+}
+
+/// Run in a child process, which cannot load the runtime.
+#[test]
+fn test_dynamic_unavailable() {
+  if env::var_os("CUDART_TEST_UNAVAILABLE").is_none() {
+    let status = Command::new(env::current_exe().unwrap())
+      .args(&["--exact", "test_dynamic_unavailable", "--nocapture"])
+      .env("CUDART_TEST_UNAVAILABLE", "1")
+      .env("CUDART_LIBRARY", "/nonexistent/libcudart.so")
+      .status()
+      .unwrap();
+    assert!(status.success());
+    return;
+  }
+  match CudaDevice::count() {
+    Err(CudaError::RuntimeUnavailable) => {}
+    e => panic!("expected runtime unavailable: {:?}", e),
+  }
+  match cuda_alloc_device(64) {
+    Err(e @ CudaError::RuntimeUnavailable) => println!("DEBUG: {}", e.get_string()),
+    e => panic!("expected runtime unavailable: {:?}", e),
+  }
+  assert!(!dynamic::is_available());
+  assert!(dynamic::load_error().unwrap().contains("/nonexistent/libcudart.so"));
+}
@@ -0,0 +1,41 @@
+#![cfg(all(unix, cuda_gte_10_0))]
+
+extern crate cudart;
+
+use cudart::*;
+
+use std::io::{ErrorKind, Read};
+use std::os::unix::io::{OwnedFd};
+use std::os::unix::net::{UnixStream};
+
+/// A descriptor which the runtime cannot import, as it was not exported by
+/// a driver, and its peer.
+fn unexported_fd() -> (OwnedFd, UnixStream) {
+  let (fd, peer) = UnixStream::pair().unwrap();
+  peer.set_nonblocking(true).unwrap();
+  (OwnedFd::from(fd), peer)
+}
+
+/// Whether the other end of `peer` has been closed.
+fn is_closed(peer: &mut UnixStream) -> bool {
+  match peer.read(&mut [0; 1]) {
+    Ok(0) => true,
+    Err(ref e) if e.kind() == ErrorKind::WouldBlock => false,
+    r => panic!("unexpected read from peer: {:?}", r),
+  }
+}
+
+#[test]
+fn test_external_memory_import_fd_closed_on_failure() {
+  let (fd, mut peer) = unexported_fd();
+  assert!(!is_closed(&mut peer));
+  assert!(ExternalMemory::import_fd(fd, 4096, false).is_err());
+  assert!(is_closed(&mut peer));
+}
+
+#[test]
+fn test_external_semaphore_import_fd_closed_on_failure() {
+  let (fd, mut peer) = unexported_fd();
+  assert!(ExternalSemaphore::import_fd(fd).is_err());
+  assert!(is_closed(&mut peer));
+}
@@ -0,0 +1,45 @@
+#[allow(dead_code)]
+#[path = "../build/cuda_version.rs"]
+mod cuda_version;
+
+use crate::cuda_version::{SUPPORTED};
+
+use std::fs;
+use std::path::{PathBuf};
+
+fn manifest_dir() -> PathBuf {
+  PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn ci_yml() -> String {
+  fs::read_to_string(manifest_dir().join(".github").join("workflows").join("ci.yml")).unwrap()
+}
+
+/// The `cuda` feature matrix of `.github/workflows/ci.yml`, which checks
+/// the crate and its tests against the bindings of each version.
+fn ci_matrix() -> Vec<String> {
+  let src = ci_yml();
+  let line = src.lines()
+    .map(|line| line.trim())
+    .find(|line| line.starts_with("cuda: ["))
+    .expect("ci.yml has no `cuda` matrix");
+  line["cuda: [".len() .. line.len() - 1].split(',').map(|f| f.trim().to_string()).collect()
+}
+
+#[test]
+fn test_ci_checks_every_supported_version() {
+  let features: Vec<String> = SUPPORTED.iter().map(|v| v.feature()).collect();
+  assert_eq!(ci_matrix(), features);
+}
+
+#[test]
+fn test_ci_installs_toolkit_for_generated_versions() {
+  let src = ci_yml();
+  let lines: Vec<&str> = src.lines().map(|line| line.trim()).collect();
+  for v in SUPPORTED.iter().filter(|v| !v.has_checked_in_bindings(&manifest_dir())) {
+    let entry = format!("- cuda: {}", v.feature());
+    let i = lines.iter().position(|&line| line == entry)
+      .unwrap_or_else(|| panic!("ci.yml has no `include` entry for {}, which has no checked-in bindings", v.feature()));
+    assert!(lines[i + 1].starts_with("toolkit: "), "the ci.yml entry for {} names no toolkit", v.feature());
+  }
+}
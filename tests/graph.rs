@@ -0,0 +1,126 @@
+#![cfg(cuda_gte_10_0)]
+
+extern crate cudart;
+
+use cudart::*;
+
+use std::sync::{Arc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn test_graph_capture_and_launch() {
+  let mut stream = CudaStream::create().unwrap();
+  assert_eq!(stream.capture_status().unwrap(), CaptureStatus::None);
+  stream.begin_capture(CaptureMode::Global).unwrap();
+  assert_eq!(stream.capture_status().unwrap(), CaptureStatus::Active);
+  assert!(stream.begin_capture(CaptureMode::Global).is_err());
+  let graph = stream.end_capture().unwrap();
+  assert!(!stream.is_capturing().unwrap());
+  let mut exec = graph.instantiate().unwrap();
+  drop(graph);
+  for _ in 0 .. 3 {
+    exec.launch(&mut stream).unwrap();
+  }
+  stream.synchronize().unwrap();
+}
+
+#[test]
+fn test_graph_end_capture_without_begin() {
+  let mut stream = CudaStream::create().unwrap();
+  assert!(stream.end_capture().is_err());
+}
+
+#[test]
+fn test_graph_builder_launch() {
+  let counter = Arc::new(AtomicUsize::new(0));
+  let mut graph = CudaGraph::new().unwrap();
+  let mut buf = unsafe { CudaDeviceBuffer::<u8>::alloc(256) }.unwrap();
+  let mut host = vec![0_u8; 256];
+  let memset = unsafe { graph.add_memset_node(&[], buf.as_mut_ptr(), 7, 256) }.unwrap();
+  let memcpy = unsafe { graph.add_memcpy_node(&[memset], host.as_mut_ptr(), buf.as_ptr(), 256, CudaMemcpyKind::DeviceToHost) }.unwrap();
+  let c = counter.clone();
+  let callback = graph.add_host_node(&[memcpy], move || { c.fetch_add(1, Ordering::SeqCst); }).unwrap();
+  assert_eq!(graph.num_nodes(), 3);
+  assert_eq!(graph.root_nodes(), vec![memset]);
+  assert_eq!(graph.dependencies(callback).unwrap(), vec![memcpy]);
+  assert_eq!(graph.node_kind(callback).unwrap(), GraphNodeKind::Host);
+  let mut exec = graph.instantiate().unwrap();
+  drop(graph);
+  let mut stream = CudaStream::create().unwrap();
+  exec.launch(&mut stream).unwrap();
+  exec.launch(&mut stream).unwrap();
+  stream.synchronize().unwrap();
+  assert!(host.iter().all(|&x| x == 7));
+  assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_graph_builder_rejects_cycles() {
+  let mut graph = CudaGraph::new().unwrap();
+  let a = graph.add_empty_node(&[]).unwrap();
+  let b = graph.add_empty_node(&[a]).unwrap();
+  let c = graph.add_empty_node(&[]).unwrap();
+  graph.add_dependencies(&[(b, c)]).unwrap();
+  match graph.add_dependencies(&[(c, a)]) {
+    Err(CudaError::GraphCycle{from: 2, to: 0}) => {}
+    e => panic!("expected cycle: {:?}", e),
+  }
+  match graph.add_dependencies(&[(a, a)]) {
+    Err(CudaError::GraphCycle{..}) => {}
+    e => panic!("expected cycle: {:?}", e),
+  }
+  assert_eq!(graph.edges().len(), 2);
+  let mut other = CudaGraph::new().unwrap();
+  let d = other.add_empty_node(&[]).unwrap();
+  match graph.add_empty_node(&[d]) {
+    Err(CudaError::UnknownGraphNode{..}) => {}
+    e => panic!("expected unknown node: {:?}", e),
+  }
+}
+
+#[test]
+fn test_graph_clone_child_and_dot() {
+  let mut child = CudaGraph::new().unwrap();
+  child.add_host_node(&[], || {}).unwrap();
+  let mut graph = CudaGraph::new().unwrap();
+  let a = graph.add_empty_node(&[]).unwrap();
+  let b = graph.add_child_graph(&[a], &child).unwrap();
+  drop(child);
+  let clone = graph.try_clone().unwrap();
+  assert_eq!(clone.num_nodes(), 2);
+  let b2 = clone.node(b.index()).unwrap();
+  assert!(b2 != b);
+  assert_eq!(clone.node_kind(b2).unwrap(), GraphNodeKind::ChildGraph);
+  assert!(clone.node_kind(b).is_err());
+  let dot = clone.to_dot();
+  assert!(dot.starts_with("digraph CudaGraph {"));
+  assert!(dot.contains("n1 [label=\"1: graph\"];"));
+  assert!(dot.contains("n0 -> n1;"));
+}
+
+extern "C" fn bump(user_data: *mut std::os::raw::c_void) {
+  let counter = unsafe { &*(user_data as *const AtomicUsize) };
+  counter.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn test_stream_launch_host_func() {
+  let counter = AtomicUsize::new(0);
+  let mut stream = CudaStream::create().unwrap();
+  stream.launch_host_func(bump, &counter as *const AtomicUsize as *mut _).unwrap();
+  stream.synchronize().unwrap();
+  assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_graph_kernel_node_shared_mem_too_large() {
+  let mut graph = CudaGraph::new().unwrap();
+  let mut config = LaunchConfig::linear(1024, 128).unwrap();
+  config.shared_mem_bytes = u32::max_value() as usize + 1;
+  match unsafe { graph.add_kernel_node(&[], CudaFunction(std::ptr::null()), &config, &mut []) } {
+    // `cudaErrorInvalidValue`.
+    Err(e @ CudaError::Runtime(_)) => assert_eq!(e.get_code(), 1),
+    e => panic!("expected invalid value: {:?}", e),
+  }
+}
@@ -0,0 +1,25 @@
+extern crate cudart;
+
+use cudart::*;
+
+#[test]
+fn test_ipc_mem_handle_bytes() {
+  let buf = unsafe { CudaDeviceBuffer::<u32>::alloc(64) }.unwrap();
+  let handle = CudaIpcMemHandle::get(&buf).unwrap();
+  let bytes = *handle.as_bytes();
+  assert_eq!(CudaIpcMemHandle::from_bytes(bytes), handle);
+  assert_eq!(CudaIpcMemHandle::get(&buf).unwrap(), handle);
+}
+
+#[test]
+fn test_ipc_event_handle() {
+  let mut event = CudaEvent::create_fastest().unwrap();
+  assert!(CudaIpcEventHandle::get(&mut event).is_err());
+  let mut event = CudaEvent::create_interprocess().unwrap();
+  let handle = CudaIpcEventHandle::get(&mut event).unwrap();
+  let handle = CudaIpcEventHandle::from_bytes(*handle.as_bytes());
+  // The runtime only opens handles exported by other processes.
+  if let Ok(mut opened) = handle.open() {
+    opened.synchronize().unwrap();
+  }
+}
@@ -0,0 +1,67 @@
+extern crate cudart;
+
+use cudart::*;
+
+#[test]
+fn test_launch_config_linear() {
+  assert_eq!(LaunchConfig::linear(1000, 256).unwrap().grid, [4, 1, 1]);
+  assert_eq!(LaunchConfig::linear(1024, 256).unwrap().grid, [4, 1, 1]);
+  assert_eq!(LaunchConfig::linear(u32::max_value(), 256).unwrap().grid, [1 << 24, 1, 1]);
+  match LaunchConfig::linear(1024, 0) {
+    Err(CudaError::LaunchOutOfRange{dim: "block.x", value: 0, ..}) => {}
+    e => panic!("expected out of range: {:?}", e),
+  }
+}
+
+#[test]
+fn test_launch_config_out_of_range() {
+  let dev = CudaDevice::get_current().unwrap();
+  LaunchConfig::linear(1 << 20, 256).unwrap().check(dev).unwrap();
+  let mut config = LaunchConfig::linear(1 << 20, 256).unwrap();
+  config.grid[1] = 0;
+  match config.check(dev) {
+    Err(CudaError::LaunchOutOfRange{dim: "grid.y", value: 0, ..}) => {}
+    e => panic!("expected out of range: {:?}", e),
+  }
+  let config = LaunchConfig{grid: [1, 1, 1], block: [64, 64, 1], shared_mem_bytes: 0};
+  match config.check(dev) {
+    Err(CudaError::LaunchOutOfRange{dim: "block threads", value: 4096, ..}) => {}
+    e => panic!("expected out of range: {:?}", e),
+  }
+}
+
+#[cfg(not(cuda_6_5))]
+#[test]
+fn test_launch_invalid_function() {
+  let mut stream = CudaStream::create().unwrap();
+  let buf = unsafe { CudaDeviceBuffer::<f32>::alloc(1024) }.unwrap();
+  let func = CudaFunction(std::ptr::null());
+  let config = LaunchConfig::linear(1024, 128).unwrap();
+  let n: u32 = 1024;
+  assert!(unsafe { launch!(func, config, &mut stream, buf, n, 2.0_f32) }.is_err());
+  assert!(unsafe { launch!(func, config, &mut stream) }.is_err());
+}
+
+#[test]
+fn test_cache_config() {
+  CudaDevice::set_cache_config_current(CachePreference::L1).unwrap();
+  match CudaDevice::get_cache_config_current().unwrap() {
+    CachePreference::None | CachePreference::L1 => {}
+    pref => panic!("unexpected cache preference: {:?}", pref),
+  }
+  CudaDevice::set_cache_config_current(CachePreference::None).unwrap();
+}
+
+#[cfg(cuda_gte_9_0)]
+#[test]
+fn test_launch_cooperative_invalid_function() {
+  let dev = CudaDevice::get_current().unwrap();
+  let mut stream = CudaStream::create().unwrap();
+  let func = CudaFunction(std::ptr::null());
+  let config = LaunchConfig::linear(1024, 128).unwrap();
+  match unsafe { cudart::launch_cooperative!(func, config, &mut stream) } {
+    Err(CudaError::CooperativeLaunchUnsupported{device}) => assert_eq!(device, dev.0),
+    Err(CudaError::Runtime(_)) => {}
+    e => panic!("expected error: {:?}", e),
+  }
+}
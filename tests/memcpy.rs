@@ -0,0 +1,54 @@
+extern crate cudart;
+
+use cudart::*;
+
+#[test]
+fn test_memcpy_out_of_bounds() {
+  let dptr = cuda_alloc_device(16).unwrap();
+  let host = [0_u8; 32];
+  match unsafe { cuda_memcpy(dptr, host.as_ptr(), 32, CudaMemcpyKind::HostToDevice) } {
+    Err(CudaError::OutOfBounds{offset: 0, size: 32, alloc_size: 16}) => {}
+    e => panic!("expected out of bounds: {:?}", e),
+  }
+  match unsafe { cuda_memset(dptr.offset(8), 0, 16) } {
+    Err(CudaError::OutOfBounds{offset: 8, size: 16, alloc_size: 16}) => {}
+    e => panic!("expected out of bounds: {:?}", e),
+  }
+  unsafe { cuda_memcpy(dptr, host.as_ptr(), 16, CudaMemcpyKind::HostToDevice) }.unwrap();
+  unsafe { cuda_free_device(dptr) }.unwrap();
+}
+
+#[test]
+fn test_memcpy_size_overflow() {
+  let dst: *mut u64 = std::ptr::null_mut();
+  let src: *const u64 = std::ptr::null();
+  match unsafe { cuda_memcpy(dst, src, usize::max_value(), CudaMemcpyKind::HostToHost) } {
+    Err(e @ CudaError::SizeOverflow) => assert!(e.raw().is_none()),
+    e => panic!("expected size overflow: {:?}", e),
+  }
+}
+
+#[test]
+fn test_memcpy_2d_pitch_too_small() {
+  let mut stream = CudaStream::default();
+  let dst: *mut f32 = std::ptr::null_mut();
+  let src: *const f32 = std::ptr::null();
+  match unsafe { cuda_memcpy_2d_async(dst, 16, src, 64, 8, 4, CudaMemcpyKind::DeviceToDevice, &mut stream) } {
+    Err(CudaError::PitchTooSmall{width_bytes: 32, pitch_bytes: 16}) => {}
+    e => panic!("expected pitch too small: {:?}", e),
+  }
+}
+
+#[test]
+fn test_buffer_copy_len_mismatch() {
+  let mut buf = unsafe { CudaDeviceBuffer::<u32>::alloc(16) }.unwrap();
+  match buf.copy_from_host(&[0; 32]) {
+    Err(CudaError::OutOfBounds{offset: 0, size: 128, alloc_size: 64}) => {}
+    e => panic!("expected out of bounds: {:?}", e),
+  }
+  let mut dst = [0; 8];
+  match buf.copy_to_host(&mut dst) {
+    Err(CudaError::OutOfBounds{offset: 0, size: 32, alloc_size: 64}) => {}
+    e => panic!("expected out of bounds: {:?}", e),
+  }
+}
@@ -0,0 +1,72 @@
+extern crate cudart;
+
+use cudart::*;
+use cudart::occupancy::{OccupancyLimiter};
+
+fn v100() -> CudaDeviceProperties {
+  CudaDeviceProperties{
+    name:                           "Tesla V100".to_owned(),
+    major:                          7,
+    minor:                          0,
+    total_global_mem:               16 << 30,
+    multiprocessor_count:           80,
+    warp_size:                      32,
+    max_threads_per_block:          1024,
+    max_threads_per_multiprocessor: 2048,
+    regs_per_block:                 65536,
+    regs_per_multiprocessor:        65536,
+    shared_mem_per_block:           48 << 10,
+    shared_mem_per_block_optin:     96 << 10,
+    shared_mem_per_multiprocessor:  96 << 10,
+  }
+}
+
+#[test]
+fn test_occupancy_model_limiters() {
+  let model = OccupancyModel::new(v100());
+  let kernel = KernelResources{regs_per_thread: 32, ..Default::default()};
+  let occ = model.occupancy(&kernel, 256, 0);
+  assert_eq!(occ.active_blocks_per_multiprocessor, 8);
+  assert_eq!(occ.ratio(), 1.0);
+
+  let kernel = KernelResources{regs_per_thread: 64, ..Default::default()};
+  let occ = model.occupancy(&kernel, 256, 0);
+  assert_eq!(occ.active_blocks_per_multiprocessor, 4);
+  assert_eq!(occ.limiter, OccupancyLimiter::Registers);
+  assert_eq!(occ.ratio(), 0.5);
+
+  let kernel = KernelResources{regs_per_thread: 32, static_smem_bytes: 16 << 10, ..Default::default()};
+  let occ = model.occupancy(&kernel, 256, 32 << 10);
+  assert_eq!(occ.active_blocks_per_multiprocessor, 2);
+  assert_eq!(occ.limiter, OccupancyLimiter::SharedMemory);
+  assert_eq!(occ.ratio(), 0.25);
+
+  let kernel = KernelResources{regs_per_thread: 16, ..Default::default()};
+  let occ = model.occupancy(&kernel, 32, 0);
+  assert_eq!(occ.active_blocks_per_multiprocessor, 32);
+  assert_eq!(occ.limiter, OccupancyLimiter::Blocks);
+
+  let kernel = KernelResources{regs_per_thread: 255, ..Default::default()};
+  assert_eq!(model.occupancy(&kernel, 1024, 0).active_blocks_per_multiprocessor, 0);
+  assert_eq!(model.occupancy(&kernel, 2048, 0).active_blocks_per_multiprocessor, 0);
+}
+
+#[test]
+fn test_occupancy_model_max_potential_block_size() {
+  let model = OccupancyModel::new(v100());
+  let kernel = KernelResources{regs_per_thread: 32, ..Default::default()};
+  assert_eq!(model.max_potential_block_size(&kernel, |_| 0, 0), (160, 1024));
+  assert_eq!(model.max_potential_block_size(&kernel, |_| 0, 200), (1280, 128));
+
+  let kernel = KernelResources{regs_per_thread: 96, ..Default::default()};
+  let (min_grid, block_size) = model.max_potential_block_size(&kernel, |_| 0, 0);
+  let occ = model.occupancy(&kernel, block_size, 0);
+  assert_eq!(min_grid, occ.active_blocks_per_multiprocessor * 80);
+  for b in 1 ..= 1024 {
+    assert!(model.occupancy(&kernel, b, 0).active_warps_per_multiprocessor * 32 <= occ.active_warps_per_multiprocessor * 32);
+  }
+
+  let config = model.launch_config(&KernelResources{regs_per_thread: 32, ..Default::default()}, 1 << 20, 0);
+  assert_eq!(config.block, [1024, 1, 1]);
+  assert_eq!(config.grid, [1024, 1, 1]);
+}
@@ -0,0 +1,54 @@
+extern crate cudart;
+
+use cudart::*;
+
+#[test]
+fn test_pinned_pool_reuse() {
+  let pool = PinnedHostPool::new(1 << 20);
+  let mut stream = CudaStream::create().unwrap();
+  let ptr = {
+    let mut block = pool.lease(1000).unwrap();
+    assert_eq!(block.len(), 1000);
+    block.as_mut_slice()[999] = 1;
+    block.record(&mut stream).unwrap();
+    block.as_ptr()
+  };
+  assert_eq!(pool.leased_bytes(), 0);
+  assert_eq!(pool.reserved_bytes(), 1024);
+  stream.synchronize().unwrap();
+  let block = pool.lease(700).unwrap();
+  assert_eq!(block.as_ptr(), ptr);
+  assert_eq!(pool.leased_bytes(), 1024);
+  assert_eq!(pool.reserved_bytes(), 1024);
+}
+
+#[test]
+fn test_pinned_pool_limit() {
+  let pool = PinnedHostPool::new(4096);
+  let a = pool.lease(4096).unwrap();
+  match pool.lease(1) {
+    Err(CudaError::PoolLimit{size: 512, reserved_bytes: 4096, max_reserved_bytes: 4096}) => {}
+    Err(e) => panic!("expected pool limit: {:?}", e),
+    Ok(_) => panic!("expected pool limit"),
+  }
+  drop(a);
+  let _b = pool.lease(1).unwrap();
+  assert_eq!(pool.reserved_bytes(), 512);
+}
+
+#[test]
+fn test_pinned_pool_limit_concurrent() {
+  let pool = PinnedHostPool::new(4096);
+  let barrier = std::sync::Arc::new(std::sync::Barrier::new(8));
+  let threads: Vec<_> = (0 .. 8).map(|_| {
+    let pool = pool.clone();
+    let barrier = barrier.clone();
+    std::thread::spawn(move || {
+      barrier.wait();
+      pool.lease(4096)
+    })
+  }).collect();
+  let leases: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+  assert_eq!(leases.iter().filter(|res| res.is_ok()).count(), 1);
+  assert_eq!(pool.reserved_bytes(), 4096);
+}
@@ -0,0 +1,22 @@
+extern crate cudart;
+
+use cudart::*;
+use cudart::profiler::{mark, mark_with, name_category};
+
+#[test]
+fn test_profiler_ranges() {
+  cuda_profiler_start().unwrap();
+  // Annotations are no-ops without libnvToolsExt, so only check that they
+  // nest and unwind cleanly either way.
+  name_category(1, "copies");
+  {
+    let _outer = ProfileRange::new("outer");
+    let _inner = ProfileRange::with_attributes("inner", ProfileAttributes{color: Some(0xff00ff00), category: 1});
+    mark("inside\0truncated");
+    mark_with("colored", ProfileAttributes::with_color(0xffff0000));
+  }
+  let span = ProfileSpan::new("span");
+  let handle = std::thread::spawn(move || drop(span));
+  handle.join().unwrap();
+  cuda_profiler_stop().unwrap();
+}
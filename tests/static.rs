@@ -0,0 +1,17 @@
+#![cfg(feature = "static")]
+
+extern crate cudart;
+
+use cudart::*;
+
+use std::fs;
+
+#[test]
+fn test_static_runtime() {
+  let version = get_runtime_version().unwrap();
+  println!("DEBUG: runtime version: {}", version);
+  assert!(version > 0);
+  // The runtime is linked in, so no shared libcudart is mapped.
+  let maps = fs::read_to_string("/proc/self/maps").unwrap();
+  assert!(!maps.contains("libcudart"));
+}
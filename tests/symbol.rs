@@ -0,0 +1,20 @@
+extern crate cudart;
+
+use cudart::*;
+
+use std::os::raw::{c_void};
+
+#[test]
+fn test_symbol_invalid() {
+  // A host variable is not a registered device symbol.
+  static NOT_A_SYMBOL: [f32; 4] = [0.0; 4];
+  let symbol = &NOT_A_SYMBOL as *const [f32; 4] as *const c_void;
+  match unsafe { DeviceSymbol::<[f32; 4]>::new(symbol) } {
+    Err(CudaError::Runtime(_)) => {}
+    e => panic!("expected a runtime error: {:?}", e),
+  }
+  match unsafe { cuda_get_symbol_size(symbol) } {
+    Err(CudaError::Runtime(_)) => {}
+    e => panic!("expected a runtime error: {:?}", e),
+  }
+}
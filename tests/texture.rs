@@ -0,0 +1,35 @@
+extern crate cudart;
+
+use cudart::*;
+use cudart::array::{ARRAY_SURFACE_LOAD_STORE, u8x4};
+
+#[test]
+fn test_texture_objects() {
+  let array = unsafe { CudaArray::<u8x4>::alloc(64, 64) }.unwrap();
+  let builder = TextureBuilder::new()
+    .address_mode(AddressMode::Border)
+    .filter_mode(FilterMode::Linear)
+    .read_mode(ReadMode::NormalizedFloat)
+    .normalized_coords(true);
+  let a = builder.build_array(&array).unwrap();
+  let b = builder.build_array(&array).unwrap();
+  assert_ne!(a.as_raw(), b.as_raw());
+
+  let buf = unsafe { CudaDeviceBuffer::<f32>::alloc(32 * 16) }.unwrap();
+  let _linear = TextureBuilder::new().build_linear(&buf).unwrap();
+  let _pitched = TextureBuilder::new().build_pitch_2d(&buf, 30, 16, 32 * 4).unwrap();
+  match TextureBuilder::new().build_pitch_2d(&buf, 32, 17, 32 * 4) {
+    Err(CudaError::OutOfBounds{offset: 0, size, alloc_size: 2048}) => assert_eq!(size, 16 * 128 + 128),
+    e => panic!("expected out of bounds: {:?}", e.map(|t| t.as_raw())),
+  };
+}
+
+#[test]
+fn test_surface_object() {
+  let mut array = unsafe { CudaArray::<f32>::alloc_with_flags(16, 16, ARRAY_SURFACE_LOAD_STORE) }.unwrap();
+  {
+    let surface = CudaSurfaceObject::create(&mut array).unwrap();
+    assert!(surface.as_raw() != 0);
+  }
+  array.copy_from_host(&[0.0; 256]).unwrap();
+}
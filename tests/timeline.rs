@@ -0,0 +1,36 @@
+extern crate cudart;
+
+use cudart::*;
+use cudart::timeline;
+
+#[test]
+fn test_timeline_chrome_trace() {
+  let mut stream = CudaStream::create().unwrap();
+  let mut buf = unsafe { CudaDeviceBuffer::<f32>::alloc(256) }.unwrap();
+  let host = vec![1.0_f32; 256];
+  timeline::enable();
+  unsafe { buf.copy_from_host_async(&host, &mut stream) }.unwrap();
+  unsafe { cuda_memset_async(buf.as_mut_ptr() as *mut u8, 0, 1024, &mut stream) }.unwrap();
+  unsafe { buf.copy_from_host_async(&host, &mut CudaStream::default()) }.unwrap();
+  timeline::disable();
+  // Not recorded once disabled.
+  unsafe { buf.copy_from_host_async(&host, &mut stream) }.unwrap();
+  stream.synchronize().unwrap();
+
+  let ops = timeline::resolve().unwrap();
+  let names: Vec<_> = ops.iter().map(|op| op.name).collect();
+  assert_eq!(names, ["cudaMemcpyAsync", "cudaMemsetAsync", "cudaMemcpyAsync"]);
+  assert!(ops.iter().all(|op| op.bytes == Some(1024) && op.dur_us >= 0.0));
+  assert_eq!(ops[2].stream, 0);
+  assert!(timeline::resolve().unwrap().is_empty());
+
+  let mut trace = Vec::new();
+  timeline::write_chrome_trace(&ops, &mut trace).unwrap();
+  let trace = String::from_utf8(trace).unwrap();
+  println!("DEBUG: trace: {}", trace);
+  assert!(trace.starts_with("{\"displayTimeUnit\":\"ns\",\"traceEvents\":["));
+  assert!(trace.ends_with("]}"));
+  assert_eq!(trace.matches("\"ph\":\"X\"").count(), 3);
+  assert_eq!(trace.matches("\"name\":\"thread_name\"").count(), 2);
+  assert!(trace.contains("\"args\":{\"name\":\"default stream\"}"));
+}
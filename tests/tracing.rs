@@ -0,0 +1,82 @@
+#![cfg(feature = "tracing")]
+
+extern crate cudart;
+extern crate tracing;
+
+use cudart::*;
+use tracing::{Event, Metadata, Subscriber};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+
+use std::fmt::{Debug};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default, Debug)]
+struct CallRecord {
+  name:   &'static str,
+  fields: Vec<(&'static str, String)>,
+}
+
+impl Visit for CallRecord {
+  fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+    self.fields.push((field.name(), format!("{:?}", value)));
+  }
+
+  fn record_str(&mut self, field: &Field, value: &str) {
+    self.fields.push((field.name(), value.to_owned()));
+  }
+}
+
+impl CallRecord {
+  fn field(&self, name: &str) -> Option<&str> {
+    self.fields.iter().rev().find(|&&(k, _)| k == name).map(|&(_, ref v)| v.as_str())
+  }
+}
+
+#[derive(Clone, Default)]
+struct Recorder {
+  spans:  Arc<Mutex<Vec<CallRecord>>>,
+}
+
+impl Subscriber for Recorder {
+  fn enabled(&self, _: &Metadata) -> bool {
+    true
+  }
+
+  fn new_span(&self, attrs: &Attributes) -> Id {
+    let mut rec = CallRecord{name: attrs.metadata().name(), fields: Vec::new()};
+    attrs.record(&mut rec);
+    let mut spans = self.spans.lock().unwrap();
+    spans.push(rec);
+    Id::from_u64(spans.len() as u64)
+  }
+
+  fn record(&self, id: &Id, values: &Record) {
+    let mut spans = self.spans.lock().unwrap();
+    values.record(&mut spans[id.into_u64() as usize - 1]);
+  }
+
+  fn record_follows_from(&self, _: &Id, _: &Id) {}
+  fn event(&self, _: &Event) {}
+  fn enter(&self, _: &Id) {}
+  fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn test_tracing_runtime_calls() {
+  let recorder = Recorder::default();
+  tracing::subscriber::with_default(recorder.clone(), || {
+    let dptr = cuda_alloc_device(64).unwrap();
+    unsafe { cuda_free_device(dptr) }.unwrap();
+    assert!(CudaDevice(-1).set_current().is_err());
+  });
+  let spans = recorder.spans.lock().unwrap();
+  println!("DEBUG: spans: {:?}", *spans);
+  let malloc = spans.iter().find(|s| s.name == "cudaMalloc").unwrap();
+  assert_eq!(malloc.field("size"), Some("64"));
+  assert_eq!(malloc.field("error"), Some("cudaSuccess"));
+  assert!(spans.iter().any(|s| s.name == "cudaFree" && s.field("error") == Some("cudaSuccess")));
+  let set_device = spans.iter().find(|s| s.name == "cudaSetDevice").unwrap();
+  assert_eq!(set_device.field("device"), Some("-1"));
+  assert!(set_device.field("error").map_or(false, |e| e != "cudaSuccess"));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "alloc_tracking")]
+
+extern crate cudart;
+
+use cudart::*;
+use cudart::tracking::*;
+
+#[test]
+fn test_tracking_double_free() {
+  let dptr = cuda_alloc_device(96).unwrap();
+  unsafe { cuda_free_device(dptr) }.unwrap();
+  match unsafe { cuda_free_device(dptr) } {
+    Err(CudaError::DoubleFree{ptr}) => assert_eq!(ptr, dptr as usize),
+    e => panic!("expected double free: {:?}", e),
+  }
+}
+
+#[test]
+fn test_tracking_unknown_pointer() {
+  let mut x = 0_u8;
+  match unsafe { cuda_free_device(&mut x as *mut u8) } {
+    Err(CudaError::UnknownPointer{..}) => {}
+    e => panic!("expected unknown pointer: {:?}", e),
+  }
+  let ptr = cuda_alloc_host(64).unwrap();
+  match unsafe { cuda_free_device(ptr) } {
+    Err(CudaError::UnknownPointer{..}) => {}
+    e => panic!("expected unknown pointer: {:?}", e),
+  }
+  unsafe { cuda_free_host(ptr) }.unwrap();
+}
+
+#[test]
+fn test_tracking_leak_report() {
+  let dptr = cuda_alloc_device(4096).unwrap();
+  assert!(live_allocations().iter().any(|r| r.ptr == dptr as usize && r.size == 4096));
+  let report = leak_report();
+  println!("{}", report);
+  assert!(report.sites.iter().any(|site| site.call_site.contains("test_tracking_leak_report")));
+  unsafe { cuda_free_device(dptr) }.unwrap();
+  assert!(!live_allocations().iter().any(|r| r.ptr == dptr as usize));
+}
+
+#[test]
+fn test_tracking_free_null() {
+  unsafe { cuda_free_device(std::ptr::null_mut()) }.unwrap();
+  unsafe { cuda_free_host(std::ptr::null_mut()) }.unwrap();
+}
@@ -1 +1,2 @@
 #include <cuda_runtime_api.h>
+#include <cuda_profiler_api.h>